@@ -1,19 +1,41 @@
 #![cfg_attr(target_arch = "wasm32", no_main)]
 
+mod migration;
 mod state;
 
 use agent_hub::{
-    AgentHubAbi, AgentHubError, AgentHubEvent, AgentHubResponse, AgentStrategy, Direction, 
-    Follower, FollowerKey, InstantiationArgument, Message, Operation, Signal, SignalResult, 
-    SignalStatus, StrategyStats, Subscription, SubscriptionOffer,
+    day_of, merkle_append, merkle_root, normalize_name, normalize_tags, signal_expiry,
+    strategist_stream, subscription_id, time_weighted_average, AgentHubAbi, AgentHubError,
+    AgentHubEvent, AgentHubParameters, AgentHubResponse, AgentStrategy, Attestation,
+    AttestationInput, Badge, BadgeKind, CalibrationBucket, ChangeEntity, ChangeKind, ChangeRecord,
+    ChurnStats, CommittedCall, CopierStats, CopySettings, CopySkip, CopySkipReason,
+    DEFAULT_PRICE_DECIMALS, DailyPnl, DeadLetter, DeadLetterStatus, Direction, Follower, FollowerExposure,
+    FollowerKey, FollowerSnapshot, FollowerStats, GlobalId, HubLeaderboard, InstantiationArgument,
+    LeaderboardEntry, MAX_SUBSCRIPTION_DAYS, MAX_TRIAL_DAYS, Market, MarketKind, MarketStats,
+    Message, MirroredStrategy, ModelInfo, Operation, OutboundRequest,
+    ParameterChange, Position, PositionStatus, PredictionAppAbi, PredictionAppRequest, Price,
+    PriceObservation, PriceOracleAbi, PriceOracleRequest, PriceQuote, PriceRange, Proposal,
+    ProposalStatus, RateLimitConfig, ReceivedMessage, RegistrationDeposit, RemoteFollow,
+    RequestKind, RequestStatus, ResolutionProof, ResolutionProofInput, ResolutionProvenance,
+    ResolutionRules, ResolutionSource, Role, Signal, SignalCommitment, SignalLeaf, SignalResult,
+    SignalStatus, SignalVisibility, StrategyStats, StrategySummary, StrategyVersion, Subscription,
+    SubscriptionEscrow, SubscriptionOffer, SyncedStrategy, Tip, Tournament, TournamentEntry,
+    TrackRecordBlob, TrackRecordExport, UnfollowReason, VerifiedStats, WireMessage,
 };
 use linera_sdk::{
-    linera_base_types::{AccountOwner, ChainId, StreamName, WithContractAbi},
-    views::{RootView, View},
+    abis::fungible::{self, FungibleOperation, FungibleTokenAbi},
+    linera_base_types::{
+        Account, AccountOwner, Amount, ApplicationId, ChainId, CryptoHash, StreamName, StreamUpdate, TimeDelta,
+        Timestamp, WithContractAbi,
+    },
+    views::{RootView, View, ViewError},
     Contract, ContractRuntime,
 };
 
-use self::state::AgentHubState;
+use self::state::{
+    AgentHubState, CopierKey, CreationKey, FollowOrderKey, LeaderboardKey, ResolutionKey, ReverseId,
+    TournamentScoreKey,
+};
 
 /// The AgentHub contract.
 pub struct AgentHubContract {
@@ -28,8 +50,8 @@ impl WithContractAbi for AgentHubContract {
 }
 
 impl Contract for AgentHubContract {
-    type Message = Message;
-    type Parameters = ();
+    type Message = WireMessage;
+    type Parameters = AgentHubParameters;
     type InstantiationArgument = InstantiationArgument;
     type EventValue = AgentHubEvent;
 
@@ -41,26 +63,114 @@ impl Contract for AgentHubContract {
     }
 
     async fn instantiate(&mut self, argument: InstantiationArgument) {
+        self.state.schema_version.set(migration::CURRENT_SCHEMA_VERSION);
         // Parse and store hub chain ID
         if let Ok(chain_id) = argument.hub_chain_id.parse::<ChainId>() {
             self.state.hub_chain_id.set(Some(chain_id));
         }
+        // Seed roles
+        let admin = self.runtime.application_parameters().admin;
+        self.state.roles.insert(&admin, Role::Admin).expect("Failed to seed admin role");
+        for assignment in argument.roles {
+            self.state.roles.insert(&assignment.owner, assignment.role)
+                .expect("Failed to seed role");
+        }
         // Initialize counters
         self.state.next_strategy_id.set(1);
         self.state.next_signal_id.set(1);
-        self.state.next_subscription_id.set(1);
     }
 
     async fn execute_operation(&mut self, operation: Operation) -> AgentHubResponse {
-        // Get authenticated signer
-        let owner = match self.runtime.authenticated_signer() {
-            Some(signer) => AccountOwner::from(signer),
-            None => return AgentHubResponse::Error { message: "Not authenticated".to_string() },
+        // While the first release's state is being upgraded, operations only move it along
+        if let Err(error) = self.migrate_batch().await {
+            return error.into();
+        }
+        let result = match operation.upgrade() {
+            // Reads from other applications need no signer
+            Operation::GetVerifiedStats { strategy_id } => self.verified_stats(strategy_id).await,
+            Operation::Unknown { tag } => Err(AgentHubError::UnknownOperation(tag)),
+            operation => match self.runtime.authenticated_signer() {
+                Some(owner) => self.execute_signed_operation(owner, operation).await,
+                None => return AgentHubResponse::Error { message: "Not authenticated".to_string() },
+            },
+        };
+        result.unwrap_or_else(Into::into)
+    }
+
+    async fn execute_message(&mut self, message: WireMessage) {
+        let origin = self.runtime.message_origin_chain_id();
+        let signer = self.runtime.authenticated_signer();
+        // Messages from newer builds that this one cannot read are skipped, not fatal
+        let ReceivedMessage::Known(message) = message.open(origin, signer, self.now()) else {
+            return;
+        };
+        // A message the target chain rejected comes back to its sender
+        let bounced = self.runtime.message_is_bouncing() == Some(true);
+        let message_tag = message.tag();
+        let payload = bcs::to_bytes(&message).expect("Failed to serialize message");
+        if bounced {
+            if let Err(error) = self.apply_bounced_message(*message, origin).await {
+                self.record_dead_letter(origin, Some(message_tag), None, signer, payload, true, error);
+            }
+            return;
+        }
+        // Messages that arrive while the state is being upgraded are kept for replay
+        let result = match self.migrate_batch().await {
+            Err(error) => Err(error),
+            Ok(()) => self.apply_message(*message, origin, signer).await,
         };
+        if let Err(error) = result {
+            self.record_dead_letter(origin, Some(message_tag), None, signer, payload, false, error);
+        }
+    }
+
+    async fn process_streams(&mut self, updates: Vec<StreamUpdate>) {
+        let application_id = self.runtime.application_id().forget_abi();
+        let migrated = self.migrate_batch().await;
+
+        for update in updates {
+            if update.stream_id.application_id != application_id.into() {
+                continue;
+            }
+
+            for index in update.new_indices() {
+                let event = self.runtime.read_event(
+                    update.chain_id,
+                    update.stream_id.stream_name.clone(),
+                    index,
+                );
+                let payload = bcs::to_bytes(&event).expect("Failed to serialize event");
+                let result = match &migrated {
+                    Err(error) => Err(error.clone()),
+                    Ok(()) => self.apply_event(event).await,
+                };
+                if let Err(error) = result {
+                    self.record_dead_letter(Some(update.chain_id), None, Some(index), None, payload, false, error);
+                }
+            }
+        }
+    }
+
+    async fn store(mut self) {
+        self.state.save().await.expect("Failed to save state");
+    }
+}
+
+impl AgentHubContract {
+    /// Apply an operation signed by `owner`. Storage failures surface as `Internal` errors
+    /// rather than panics.
+    async fn execute_signed_operation(
+        &mut self,
+        owner: AccountOwner,
+        operation: Operation,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        // Apply governance outcomes and signal resolutions that have come due
+        self.process_proposals().await?;
+        self.run_due_sweeps().await?;
 
         match operation {
-            Operation::RegisterStrategist { display_name } => {
-                self.register_strategist(owner, display_name).await
+            Operation::RegisterStrategist { display_name, referrer, deposit } => {
+                self.register_strategist(owner, display_name, referrer, deposit.unwrap_or_default()).await
             }
             Operation::CreateAgentStrategy {
                 name,
@@ -69,8 +179,26 @@ impl Contract for AgentHubContract {
                 base_market,
                 is_public,
                 is_ai_controlled,
+                push_band_bps,
+                model,
             } => {
-                self.create_strategy(owner, name, description, market_kind, base_market, is_public, is_ai_controlled).await
+                self.create_strategy(
+                    owner,
+                    name,
+                    description,
+                    market_kind,
+                    base_market,
+                    is_public,
+                    is_ai_controlled,
+                    push_band_bps.unwrap_or(0),
+                    model,
+                ).await
+            }
+            Operation::BumpStrategyVersion { strategy_id, change_note, model } => {
+                self.bump_strategy_version(owner, strategy_id, change_note, model).await
+            }
+            Operation::ForkStrategy { source_strategy_id, name } => {
+                self.fork_strategy(owner, source_strategy_id, name).await
             }
             Operation::PublishSignal {
                 strategy_id,
@@ -78,14 +206,73 @@ impl Contract for AgentHubContract {
                 horizon_secs,
                 confidence_bps,
                 entry_value,
+                target_value,
+                stop_value,
+                range,
+                attestation,
+                visibility,
             } => {
-                self.publish_signal(owner, strategy_id, direction, horizon_secs, confidence_bps, entry_value).await
+                self.publish_signal(
+                    owner,
+                    strategy_id,
+                    direction,
+                    horizon_secs,
+                    confidence_bps,
+                    entry_value,
+                    target_value,
+                    stop_value,
+                    range,
+                    attestation,
+                    visibility.unwrap_or_default(),
+                ).await
+            }
+            Operation::CommitSignal { strategy_id, commitment_hash, horizon_secs, entry_value } => {
+                self.commit_signal(owner, strategy_id, commitment_hash, horizon_secs, entry_value).await
+            }
+            Operation::RevealSignal { signal_id, direction, confidence_bps, salt } => {
+                self.reveal_signal(owner, signal_id, direction, confidence_bps, salt).await
+            }
+            Operation::SetAgentKey { strategy_id, agent_key } => {
+                match self.state.strategies.get(&strategy_id).await? {
+                    Some(mut strategy) if strategy.owner == owner => {
+                        strategy.agent_key = Some(agent_key);
+                        self.state.strategies.insert(&strategy_id, strategy)?;
+                        self.record_change(ChangeEntity::Strategy, strategy_id, ChangeKind::Updated);
+                        Ok(AgentHubResponse::AgentKeySet { strategy_id })
+                    }
+                    Some(_) => Err(AgentHubError::NotAuthorized),
+                    _ => Err(AgentHubError::StrategyNotFound),
+                }
+            }
+            Operation::BackfillEntryValue { signal_id, entry_value } => {
+                if !self.has_role(owner, Role::Oracle).await? {
+                    return Err(AgentHubError::NotAuthorized);
+                }
+                self.backfill_entry_value(signal_id, entry_value).await
+            }
+            Operation::CloseSignalEarly { signal_id, current_value } => {
+                self.close_signal_early(owner, signal_id, current_value).await
             }
             Operation::ResolveSignal {
                 signal_id,
                 resolved_value,
+                proof,
             } => {
-                self.resolve_signal(signal_id, resolved_value).await
+                if !self.has_role(owner, Role::Oracle).await? {
+                    return Err(AgentHubError::NotAuthorized);
+                }
+                let proof = match self.check_resolution_proof(owner, signal_id, resolved_value, proof).await {
+                    Ok(proof) => proof,
+                    Err(error) => return Err(error),
+                };
+                let provenance = ResolutionProvenance {
+                    source: ResolutionSource::Resolver,
+                    resolver: Some(owner),
+                    oracle: None,
+                    observed_at: proof.map_or_else(|| self.now(), |proof| proof.observed_at),
+                    proof,
+                };
+                self.resolve_signal(signal_id, resolved_value, provenance).await
             }
             Operation::CancelSignal { signal_id } => {
                 self.cancel_signal(owner, signal_id).await
@@ -95,30 +282,257 @@ impl Contract for AgentHubContract {
                 auto_copy,
                 max_exposure_units,
             } => {
-                self.follow_strategy(owner, strategy_id, auto_copy, max_exposure_units).await
+                let chain_id = self.runtime.chain_id();
+                self.follow_strategy(owner, chain_id, strategy_id, auto_copy, max_exposure_units).await
+            }
+            Operation::UnfollowStrategy { strategy_id, reason } => {
+                self.unfollow_with_reason(owner, strategy_id, reason).await
+            }
+            Operation::FollowRemoteStrategy {
+                strategy_id,
+                strategy_chain_id,
+                auto_copy,
+                max_exposure_units,
+            } => {
+                self.follow_remote_strategy(owner, strategy_id, strategy_chain_id, auto_copy, max_exposure_units).await
+            }
+            Operation::UnfollowRemoteStrategy { strategy_id, strategy_chain_id, reason } => {
+                self.unfollow_remote_strategy(owner, strategy_id, strategy_chain_id, reason).await
             }
-            Operation::UnfollowStrategy { strategy_id } => {
-                self.unfollow_strategy(owner, strategy_id).await
+            Operation::MirrorStrategy { strategy_id, strategy_chain_id } => {
+                self.mirror_strategy(owner, strategy_id, strategy_chain_id)
             }
             Operation::UpdateStats { strategy_id } => {
-                self.update_strategy_stats(strategy_id).await
+                self.update_strategy_stats(strategy_id).await?;
+                Ok(AgentHubResponse::Ok)
             }
-            Operation::EnableSubscription { description } => {
-                self.enable_subscription(owner, description).await
+            Operation::EnableSubscription { description, price } => {
+                self.enable_subscription(owner, description, price.unwrap_or_default()).await
             }
             Operation::DisableSubscription => {
                 self.disable_subscription(owner).await
             }
-            Operation::SubscribeToStrategist { strategist, strategist_chain_id } => {
-                self.subscribe_to_strategist(owner, strategist, strategist_chain_id).await
+            Operation::SubscribeToStrategist {
+                strategist,
+                strategist_chain_id,
+                payment,
+                token,
+            } => {
+                self.subscribe_to_strategist(owner, strategist, strategist_chain_id, payment.unwrap_or_default(), token)
+                    .await
             }
             Operation::UnsubscribeFromStrategist { strategist } => {
                 self.unsubscribe_from_strategist(owner, strategist).await
             }
+            Operation::CancelSubscriptionEarly { strategist } => {
+                self.cancel_subscription_early(owner, strategist).await
+            }
+            Operation::ReleaseEscrow { subscription_id } => {
+                self.release_escrow(subscription_id).await
+            }
+            Operation::WithdrawTreasury { amount, to } => {
+                self.withdraw_treasury(owner, amount, to).await
+            }
+            Operation::CreateProposal { description, change } => {
+                self.create_proposal(owner, description, change).await
+            }
+            Operation::Vote { proposal_id, support } => {
+                self.vote(owner, proposal_id, support).await
+            }
+            Operation::PushParameterChange { proposal_id, target_chain_id } => {
+                self.push_parameter_change(owner, proposal_id, target_chain_id).await
+            }
+            Operation::CreateTournament {
+                name,
+                market_kind,
+                base_market,
+                start_timestamp,
+                end_timestamp,
+                prize_pool,
+            } => {
+                self.create_tournament(
+                    owner,
+                    name,
+                    market_kind,
+                    base_market,
+                    start_timestamp,
+                    end_timestamp,
+                    prize_pool,
+                ).await
+            }
+            Operation::SettleTournament { tournament_id } => {
+                self.settle_tournament(owner, tournament_id).await
+            }
+            Operation::GrantRole { owner: grantee, role, target_chain_id } => {
+                self.set_role(owner, grantee, Some(role), target_chain_id).await
+            }
+            Operation::RegisterMarket { market, target_chain_id } => {
+                self.register_market(owner, market, target_chain_id).await
+            }
+            Operation::SetMarketActive { symbol, is_active, target_chain_id } => {
+                self.set_market_active(owner, symbol, is_active, target_chain_id).await
+            }
+            Operation::SetResolutionRules { market_kind, rules, target_chain_id } => {
+                self.set_resolution_rules(owner, market_kind, rules, target_chain_id).await
+            }
+            Operation::RevokeRole { owner: grantee, target_chain_id } => {
+                self.set_role(owner, grantee, None, target_chain_id).await
+            }
+            Operation::SetRateLimits {
+                max_signals_per_hour,
+                max_signals_per_day,
+            } => {
+                self.set_rate_limits(owner, max_signals_per_hour, max_signals_per_day).await
+            }
+            Operation::ExportTrackRecord { strategy_id, start } => {
+                self.export_track_record(owner, strategy_id, start.unwrap_or(0)).await
+            }
+            Operation::GetVerifiedStats { strategy_id } => self.verified_stats(strategy_id).await,
+            // `execute_operation` upgrades first-release forms before they get here
+            Operation::ReplayDeadLetter { sequence } => self.replay_dead_letter(owner, sequence).await,
+            Operation::RefundDeadLetter { sequence } => self.refund_dead_letter(owner, sequence).await,
+            operation @ (Operation::LegacyRegisterStrategist { .. }
+            | Operation::LegacyCreateAgentStrategy { .. }
+            | Operation::LegacyPublishSignal { .. }
+            | Operation::LegacyResolveSignal { .. }
+            | Operation::LegacyUnfollowStrategy { .. }
+            | Operation::LegacyEnableSubscription { .. }
+            | Operation::LegacySubscribeToStrategist { .. }
+            | Operation::Unknown { .. }) => Err(AgentHubError::UnknownOperation(operation.tag())),
+            Operation::TipStrategist { strategist, strategist_chain_id, amount } => {
+                self.tip_strategist(owner, strategist, strategist_chain_id, amount).await
+            }
+            Operation::SetPerformanceFee { strategy_id, fee_bps } => {
+                self.set_performance_fee(owner, strategy_id, fee_bps).await
+            }
+            Operation::FundPerformanceFees { strategy_id, strategy_chain_id, amount } => {
+                self.fund_performance_fees(owner, strategy_id, strategy_chain_id, amount).await
+            }
+            Operation::ClaimPerformanceFees => {
+                self.claim_performance_fees(owner).await
+            }
+            Operation::SetSubscriptionPrice { amount, token, duration_days } => {
+                self.set_subscription_price(owner, amount, token, duration_days).await
+            }
+            Operation::SetSubscriptionTrial { trial_days } => {
+                self.set_subscription_trial(owner, trial_days).await
+            }
+            Operation::GiftSubscription {
+                recipient,
+                recipient_chain_id,
+                strategist,
+                strategist_chain_id,
+                payment,
+                token,
+            } => {
+                self.gift_subscription(
+                    owner,
+                    recipient,
+                    recipient_chain_id,
+                    strategist,
+                    strategist_chain_id,
+                    payment,
+                    token,
+                )
+                .await
+            }
+            Operation::PauseStrategy { strategy_id, reason } => {
+                self.set_strategy_paused(owner, strategy_id, true, reason).await
+            }
+            Operation::ResumeStrategy { strategy_id } => {
+                self.set_strategy_paused(owner, strategy_id, false, None).await
+            }
+            Operation::SetMaxFollowers { strategy_id, max_followers } => {
+                self.set_max_followers(owner, strategy_id, max_followers).await
+            }
+            Operation::InviteFollower { strategy_id, invitee } => {
+                self.set_invited(owner, strategy_id, invitee, true).await
+            }
+            Operation::RevokeInvite { strategy_id, invitee } => {
+                self.set_invited(owner, strategy_id, invitee, false).await
+            }
+            Operation::BlockFollower { strategy_id, follower } => {
+                self.block_follower(owner, strategy_id, follower).await
+            }
+            Operation::UnblockFollower { strategy_id, follower } => {
+                self.unblock_follower(owner, strategy_id, follower).await
+            }
+            Operation::UpdateDisplayName { display_name } => {
+                self.update_display_name(owner, display_name).await
+            }
+            Operation::SubmitPriceObservation { signal_id, value, observed_at } => {
+                if !self.has_role(owner, Role::Oracle).await? {
+                    return Err(AgentHubError::NotAuthorized);
+                }
+                self.submit_price_observation(owner, signal_id, value, observed_at).await
+            }
+            Operation::ResolveSignalWithTwap { signal_id } => {
+                if !self.has_role(owner, Role::Oracle).await? {
+                    return Err(AgentHubError::NotAuthorized);
+                }
+                self.resolve_signal_with_twap(owner, signal_id).await
+            }
+            Operation::SetResolverKey { resolver_key } => {
+                if !self.has_role(owner, Role::Oracle).await? {
+                    return Err(AgentHubError::NotAuthorized);
+                }
+                match resolver_key {
+                    Some(key) => self.state.resolver_keys.insert(&owner, key)?,
+                    None => self.state.resolver_keys.remove(&owner)?,
+                }
+                Ok(AgentHubResponse::ResolverKeySet { resolver: owner })
+            }
+            Operation::UpdateNotificationPreferences { preferences } => {
+                if preferences.min_confidence_bps > 10000 {
+                    return Err(AgentHubError::InvalidConfidence);
+                }
+                self.state.notification_preferences.insert(&owner, preferences)?;
+                Ok(AgentHubResponse::NotificationPreferencesUpdated { owner })
+            }
+            Operation::UpdateCopySettings { strategy_id, strategy_chain_id, settings } => {
+                self.update_copy_settings(owner, strategy_id, strategy_chain_id, settings).await
+            }
+            Operation::OpenPosition { signal_id, size_units } => {
+                self.open_direct_position(owner, signal_id, size_units).await
+            }
+            Operation::SetExposureCap { strategy_chain_id, cap_units } => {
+                self.set_exposure_cap(owner, strategy_chain_id, cap_units)
+            }
+            Operation::SetStrategyTags { strategy_id, tags } => {
+                self.set_strategy_tags(owner, strategy_id, tags).await
+            }
+            Operation::WithdrawFeeAllowance { strategy_id, strategy_chain_id } => {
+                self.withdraw_fee_allowance(owner, strategy_id, strategy_chain_id).await
+            }
+            Operation::SetStrategistVerified { strategist, verified } => {
+                if !self.has_role(owner, Role::Moderator).await? {
+                    return Err(AgentHubError::NotAuthorized);
+                }
+                self.set_strategist_verified(strategist, verified).await
+            }
         }
     }
 
-    async fn execute_message(&mut self, message: Message) {
+    /// Resolve, expire, disclose and sync whatever has come due since the last block
+    async fn run_due_sweeps(&mut self) -> Result<(), AgentHubError> {
+        self.resolve_due_signals().await?;
+        self.expire_unresolved_signals().await?;
+        self.lapse_commitments().await?;
+        self.disclose_delayed_signals().await?;
+        self.flush_hub_sync().await?;
+        self.time_out_requests().await?;
+        Ok(())
+    }
+
+    /// Apply an incoming message sent from `origin` and signed by `signer`. Storage failures are
+    /// returned rather than panicking, so the caller can roll back and record the message as a
+    /// dead letter.
+    async fn handle_message(
+        &mut self,
+        message: Message,
+        origin: Option<ChainId>,
+        signer: Option<AccountOwner>,
+    ) -> Result<(), AgentHubError> {
         match message {
             Message::SignalResolved {
                 signal_id: _,
@@ -127,148 +541,396 @@ impl Contract for AgentHubContract {
                 pnl_bps: _,
             } => {
                 // Update stats on message receive (for cross-chain sync)
-                let _ = self.update_strategy_stats(strategy_id).await;
+                self.update_strategy_stats(strategy_id).await?;
             }
             Message::SubscriptionRequest {
                 subscriber,
                 subscriber_chain_id,
                 strategist,
                 timestamp,
+                payment,
+                token,
             } => {
-                // Handle incoming subscription request on strategist's chain
-                // The strategist is now passed in the message (not derived from signer)
-                
-                // Generate subscription ID
-                let sub_id = *self.state.next_subscription_id.get();
-                self.state.next_subscription_id.set(sub_id + 1);
-                
-                let subscription_id = format!("sub-{}-{}", sub_id, timestamp);
-                
-                // 30 days subscription duration
-                const THIRTY_DAYS_MICROS: u64 = 30 * 24 * 60 * 60 * 1_000_000;
-                let end_timestamp = timestamp + THIRTY_DAYS_MICROS;
-                
-                let chain_id = self.runtime.chain_id();
-                
-                let subscription = Subscription {
-                    id: subscription_id.clone(),
-                    subscriber: subscriber.clone(),
-                    subscriber_chain_id: subscriber_chain_id.clone(),
-                    strategist: strategist.clone(),
-                    strategist_chain_id: chain_id.to_string(),
-                    start_timestamp: timestamp,
-                    end_timestamp,
-                    is_active: true,
+                let refund_account = Account { chain_id: subscriber_chain_id, owner: subscriber };
+                self.handle_subscription_request(subscriber, subscriber_chain_id, strategist, timestamp, payment, token, refund_account)
+                    .await?;
+            }
+            Message::SetRole { owner, role } => {
+                // Only admins (as known on this chain) may change roles remotely
+                let Some(signer) = signer else {
+                    return Ok(());
                 };
-                
-                // Store subscription
-                self.state.subscriptions.insert(&subscription_id, subscription)
-                    .expect("Failed to store subscription");
-                
-                // Add to strategist's subscribers list
-                let mut subs = self.state.subscribers_by_strategist.get(&strategist).await
-                    .ok().flatten().unwrap_or_default();
-                subs.push(subscription_id.clone());
-                self.state.subscribers_by_strategist.insert(&strategist, subs)
-                    .expect("Failed to update subscribers list");
-                
-                // Emit event for subscription created
-                let stream = StreamName::from(b"subscriptions");
-                self.runtime.emit(stream, &AgentHubEvent::SubscriptionCreated {
-                    subscription_id: subscription_id.clone(),
-                    subscriber: subscriber.clone(),
-                    strategist: strategist.clone(),
-                });
-
-                // Send confirmation back to subscriber's chain
-                if let Ok(sub_chain) = subscriber_chain_id.parse::<ChainId>() {
-                    self.runtime.prepare_message(Message::SubscriptionConfirmed {
-                        subscription_id,
-                        strategist: strategist.clone(),
-                        strategist_chain_id: chain_id.to_string(),
-                        end_timestamp,
-                    }).send_to(sub_chain);
+                if !self.is_admin(signer).await? {
+                    return Ok(());
+                }
+                self.apply_role(owner, role)?;
+            }
+            Message::ParameterChangeApplied { proposal_id: _, change } => {
+                // Only an admin acting on the hub chain, where governance runs, may apply one
+                if origin != *self.state.hub_chain_id.get() {
+                    return Ok(());
+                }
+                let Some(signer) = signer else {
+                    return Ok(());
+                };
+                if !self.is_admin(signer).await? {
+                    return Ok(());
+                }
+                self.apply_parameter_change(&change);
+            }
+            Message::SetMarket { market } => {
+                let Some(signer) = signer else {
+                    return Ok(());
+                };
+                if !self.is_admin(signer).await? {
+                    return Ok(());
+                }
+                self.apply_market(market)?;
+            }
+            Message::SetMarketActive { symbol, is_active } => {
+                let Some(signer) = signer else {
+                    return Ok(());
+                };
+                if !self.is_admin(signer).await? {
+                    return Ok(());
+                }
+                self.apply_market_active(&symbol, is_active).await?;
+            }
+            Message::SetResolutionRules { market_kind, rules } => {
+                let Some(signer) = signer else {
+                    return Ok(());
+                };
+                if !self.is_admin(signer).await? {
+                    return Ok(());
+                }
+                self.apply_resolution_rules(&market_kind, rules)?;
+            }
+            Message::CancelSubscriptionRequest { subscriber, strategist } => {
+                if signer != Some(subscriber) {
+                    return Ok(());
                 }
+                self.handle_cancel_subscription(subscriber, strategist).await?;
             }
             Message::SubscriptionConfirmed {
                 subscription_id,
+                subscriber,
                 strategist,
                 strategist_chain_id,
+                start_timestamp,
                 end_timestamp,
+                is_trial,
             } => {
-                // Handle subscription confirmation on subscriber's chain
-                let subscriber = self.runtime.authenticated_signer()
-                    .map(AccountOwner::from)
-                    .unwrap_or(strategist.clone());
-                
-                let chain_id = self.runtime.chain_id();
-                let timestamp = self.now().micros();
-                
                 let subscription = Subscription {
-                    id: subscription_id.clone(),
-                    subscriber: subscriber.clone(),
-                    subscriber_chain_id: chain_id.to_string(),
-                    strategist: strategist.clone(),
+                    id: subscription_id,
+                    subscriber,
+                    subscriber_chain_id: self.runtime.chain_id(),
+                    strategist,
                     strategist_chain_id,
-                    start_timestamp: timestamp,
+                    start_timestamp,
                     end_timestamp,
                     is_active: true,
+                    is_trial,
                 };
-                
-                // Store subscription locally
-                self.state.subscriptions.insert(&subscription_id, subscription)
-                    .expect("Failed to store subscription");
-                
-                // Add to subscriber's subscriptions list
-                let mut subs = self.state.subscriptions_by_subscriber.get(&subscriber).await
-                    .ok().flatten().unwrap_or_default();
-                subs.push(subscription_id);
-                self.state.subscriptions_by_subscriber.insert(&subscriber, subs)
-                    .expect("Failed to update subscriptions list");
-            }
-            Message::SignalBroadcast {
-                signal,
-                strategy_name: _,
-                strategist: _,
+                self.settle_request(OutboundRequest::subscribe_key(&subscriber, &strategist), RequestStatus::Confirmed)
+                    .await?;
+                self.handle_subscription_confirmed(subscription).await?;
+            }
+            Message::FollowRequest {
+                strategy_id,
+                follower,
+                follower_chain_id,
+                auto_copy,
+                max_exposure_units,
             } => {
-                // Store received signal from subscribed strategist
-                let signal_id = signal.id;
-                self.state.signals.insert(&signal_id, signal)
-                    .expect("Failed to store broadcast signal");
+                // Handle incoming follow request on the strategy's chain
+                if signer != Some(follower) {
+                    return Ok(());
+                }
+
+                match self
+                    .follow_strategy(follower, follower_chain_id, strategy_id, auto_copy, max_exposure_units)
+                    .await
+                {
+                    Ok(AgentHubResponse::Followed { .. }) => {}
+                    Err(error @ AgentHubError::Internal(_)) => return Err(error),
+                    _ => return Ok(()),
+                }
+                self.confirm_remote_follow(strategy_id, follower, follower_chain_id, auto_copy, max_exposure_units)
+                    .await?;
             }
-        }
-    }
+            Message::FollowConfirmed {
+                strategy,
+                strategy_name,
+                strategist,
+                follower,
+                auto_copy,
+                max_exposure_units,
+            } => {
+                // Handle follow confirmation on follower's chain
+                self.settle_request(OutboundRequest::follow_key(&follower, strategy), RequestStatus::Confirmed)
+                    .await?;
+                let mut follows = self.state.remote_follows.get(&follower).await?.unwrap_or_default();
+                if follows.iter().any(|f| f.strategy == strategy) {
+                    return Ok(());
+                }
 
-    async fn store(mut self) {
-        self.state.save().await.expect("Failed to save state");
+                follows.push(RemoteFollow {
+                    strategy,
+                    strategy_name,
+                    strategist,
+                    follower,
+                    auto_copy,
+                    max_exposure_units,
+                    created_at: self.now(),
+                });
+                self.state.remote_follows.insert(&follower, follows)?;
+                self.state.remote_follows_by_strategy.load_entry_mut(&strategy).await?.insert(&follower)?;
+
+                // Start receiving the strategist's signals
+                self.listen_to_strategist(strategy.chain_id, strategist).await?;
+            }
+            Message::UnfollowRequest { strategy_id, follower, reason } => {
+                // Handle incoming unfollow request on the strategy's chain
+                if signer != Some(follower) {
+                    return Ok(());
+                }
+
+                match self.unfollow_with_reason(follower, strategy_id, reason).await {
+                    Ok(AgentHubResponse::Unfollowed { .. }) => {}
+                    Err(error @ AgentHubError::Internal(_)) => return Err(error),
+                    _ => return Ok(()),
+                }
+
+                let mut remote = self.state.remote_followers.get(&strategy_id).await?.unwrap_or_default();
+                remote.retain(|owner| *owner != follower);
+                self.state.remote_followers.insert(&strategy_id, remote)?;
+            }
+            Message::RegisterStrategyOnHub { summary } => {
+                // Only the hub chain maintains the global catalog
+                if *self.state.hub_chain_id.get() != Some(self.runtime.chain_id()) {
+                    return Ok(());
+                }
+                // A chain may only register its own strategies
+                if origin != Some(summary.global_id.chain_id) {
+                    return Ok(());
+                }
+                self.store_hub_strategy(summary)?;
+            }
+            Message::StatsSync { strategy_summaries } => {
+                if *self.state.hub_chain_id.get() != Some(self.runtime.chain_id()) {
+                    return Ok(());
+                }
+                // A chain may only sync its own strategies
+                for synced in strategy_summaries {
+                    if origin != Some(synced.summary.global_id.chain_id) {
+                        continue;
+                    }
+                    let global_id = synced.summary.global_id;
+                    self.store_hub_strategy(synced.summary)?;
+                    self.store_hub_stats(global_id, synced.stats).await?;
+                }
+            }
+            Message::MirrorRequest { strategy_id } => {
+                let Some(origin) = origin else {
+                    return Ok(());
+                };
+                self.send_mirror_snapshot(strategy_id, origin).await?;
+            }
+            Message::MirrorSnapshot { strategy, stats, signals } => {
+                // Only the host chain may describe its own strategies
+                if origin != Some(strategy.global_id.chain_id) {
+                    return Ok(());
+                }
+                let id = strategy.global_id;
+                self.settle_request(OutboundRequest::mirror_key(id), RequestStatus::Confirmed).await?;
+                let mirror = MirroredStrategy {
+                    strategy: *strategy,
+                    stats,
+                    signals,
+                    mirrored_at: self.now(),
+                };
+                self.state.mirrored_strategies.insert(&id, mirror)?;
+            }
+            Message::TipSent { tipper, strategist, amount } => {
+                if signer != Some(tipper) {
+                    return Ok(());
+                }
+                let Some(tipper_chain_id) = origin else {
+                    return Ok(());
+                };
+                self.record_tip(tipper, tipper_chain_id, strategist, amount).await?;
+            }
+            Message::PerformanceFeesFunded { strategy_id, follower, amount } => {
+                if signer != Some(follower) {
+                    return Ok(());
+                }
+                self.credit_fee_allowance(strategy_id, follower, amount).await?;
+            }
+            Message::ExposureCapUpdate { follower, cap_units } => {
+                if signer != Some(follower) {
+                    return Ok(());
+                }
+                self.apply_exposure_cap(follower, cap_units)?;
+            }
+            Message::RestrictedSignal { signal, strategist, base_market } => {
+                // Only the signal's host chain may send it, and only subscribers keep it
+                if origin != Some(signal.global_id.chain_id) {
+                    return Ok(());
+                }
+                if !self.has_active_subscription_to(strategist).await? {
+                    return Ok(());
+                }
+                self.receive_signal(*signal, strategist, &base_market).await?;
+            }
+            Message::FeeAllowanceWithdrawal { strategy_id, follower } => {
+                if signer != Some(follower) {
+                    return Ok(());
+                }
+                let Some(origin) = origin else {
+                    return Ok(());
+                };
+                let destination = Account { chain_id: origin, owner: follower };
+                // Requests from current followers or with nothing left are dropped
+                if let Err(error @ AgentHubError::Internal(_)) =
+                    self.refund_fee_allowance(strategy_id, follower, destination).await
+                {
+                    return Err(error);
+                }
+            }
+            Message::CopySettingsUpdate { strategy_id, follower, settings } => {
+                if signer != Some(follower) {
+                    return Ok(());
+                }
+                // A follower unknown here is not a failure worth keeping; storage errors are
+                if let Err(error @ AgentHubError::Internal(_)) =
+                    self.apply_copy_settings(strategy_id, follower, settings).await
+                {
+                    return Err(error);
+                }
+            }
+            Message::FollowRevoked { strategy, follower } => {
+                // Only the strategy's own chain can revoke a follow of it
+                if origin != Some(strategy.chain_id) {
+                    return Ok(());
+                }
+                let mut follows = self.state.remote_follows.get(&follower).await?.unwrap_or_default();
+                let Some(position) = follows.iter().position(|f| f.strategy == strategy) else {
+                    return Ok(());
+                };
+                let removed = follows.remove(position);
+                self.stop_listening_to_strategist(strategy.chain_id, removed.strategist).await?;
+                self.state.remote_follows.insert(&follower, follows)?;
+                self.state.remote_follows_by_strategy.load_entry_mut(&strategy).await?.remove(&follower)?;
+            }
+            Message::GiftSubscriptionRequest {
+                gifter,
+                gifter_chain_id,
+                recipient,
+                recipient_chain_id,
+                strategist,
+                timestamp,
+                payment,
+                token,
+            } => {
+                let refund_account = Account { chain_id: gifter_chain_id, owner: gifter };
+                self.handle_subscription_request(recipient, recipient_chain_id, strategist, timestamp, payment, token, refund_account)
+                    .await?;
+            }
+        }
+        Ok(())
     }
-}
 
-impl AgentHubContract {
     /// Get current timestamp
-    fn now(&mut self) -> linera_sdk::linera_base_types::Timestamp {
+    fn now(&mut self) -> Timestamp {
         self.runtime.system_time()
     }
 
+    /// Append a record to the change feed
+    fn record_change(&mut self, entity: ChangeEntity, entity_id: impl ToString, change: ChangeKind) {
+        let record = ChangeRecord {
+            sequence: self.state.change_log.count() as u64 + 1,
+            entity,
+            entity_id: entity_id.to_string(),
+            change,
+            timestamp: self.now(),
+        };
+        self.state.change_log.push(record);
+    }
+
     /// Register a new strategist
-    async fn register_strategist(&mut self, owner: AccountOwner, display_name: String) -> AgentHubResponse {
+    async fn register_strategist(
+        &mut self,
+        owner: AccountOwner,
+        display_name: String,
+        referrer: Option<AccountOwner>,
+        deposit: Amount,
+    ) -> Result<AgentHubResponse, AgentHubError> {
         // Check if already registered
-        if self.state.strategists.contains_key(&owner).await.unwrap_or(false) {
-            return AgentHubError::StrategistAlreadyRegistered.into();
+        if self.state.strategists.contains_key(&owner).await? {
+            return Err(AgentHubError::StrategistAlreadyRegistered);
+        }
+
+        let name_key = normalize_name(&display_name);
+        if name_key.is_empty() {
+            return Err(AgentHubError::InvalidDisplayName);
+        }
+        if self.state.name_index.contains_key(&name_key).await? {
+            return Err(AgentHubError::NameTaken);
+        }
+
+        // Anti-spam deposit, refunded once the strategist has resolved enough signals
+        let parameters = self.runtime.application_parameters();
+        let required_deposit = parameters.deposit_token
+            .filter(|_| parameters.registration_deposit > Amount::ZERO)
+            .map(|token| (token, parameters.registration_deposit));
+        if required_deposit.is_some_and(|(_, required)| deposit < required) {
+            return Err(AgentHubError::InsufficientDeposit);
+        }
+
+        // Referrer must be an existing strategist other than the caller
+        if let Some(referrer) = referrer {
+            if referrer == owner || !self.state.strategists.contains_key(&referrer).await? {
+                return Err(AgentHubError::InvalidReferrer);
+            }
+            let mut referees = self.state.referrals.get(&referrer).await?
+                .unwrap_or_default();
+            referees.push(owner);
+            self.state.referrals.insert(&referrer, referees)?;
+        }
+
+        if let Some((token, amount)) = required_deposit {
+            let chain_id = self.runtime.chain_id();
+            let escrow_account = self.escrow_account(chain_id);
+            self.transfer_tokens(Some(token), owner, escrow_account, amount);
+            let record = RegistrationDeposit {
+                owner,
+                token,
+                amount,
+                deposited_at: self.now(),
+                resolved_signals: 0,
+                refunded: false,
+            };
+            self.state.registration_deposits.insert(&owner, record)?;
         }
 
         let strategist = agent_hub::Strategist {
-            owner: owner.clone(),
+            owner,
             display_name,
+            referrer,
             created_at: self.now(),
+            verified: false,
         };
 
-        self.state.strategists.insert(&owner, strategist).expect("Failed to insert strategist");
+        self.state.strategists.insert(&owner, strategist)?;
+        self.state.name_index.insert(&name_key, owner)?;
+        self.record_change(ChangeEntity::Strategist, owner, ChangeKind::Created);
+        self.state.global_counters.get_mut().total_strategists += 1;
         
-        AgentHubResponse::StrategistRegistered { owner }
+        Ok(AgentHubResponse::StrategistRegistered { owner })
     }
 
     /// Create a new agent strategy
+    #[allow(clippy::too_many_arguments)]
     async fn create_strategy(
         &mut self,
         owner: AccountOwner,
@@ -278,18 +940,38 @@ impl AgentHubContract {
         base_market: String,
         is_public: bool,
         is_ai_controlled: bool,
-    ) -> AgentHubResponse {
+        push_band_bps: u32,
+        model: Option<ModelInfo>,
+    ) -> Result<AgentHubResponse, AgentHubError> {
         // Check if strategist is registered
-        if !self.state.strategists.contains_key(&owner).await.unwrap_or(false) {
-            return AgentHubError::StrategistNotRegistered.into();
+        if !self.state.strategists.contains_key(&owner).await? {
+            return Err(AgentHubError::StrategistNotRegistered);
+        }
+
+        if push_band_bps > 10000 {
+            return Err(AgentHubError::InvalidPushBand);
         }
 
+        // Once the chain has a market registry, strategies must target an active listed
+        // market of the same kind; the registry's symbol becomes the canonical base market
+        let base_market = if self.state.markets.count().await? > 0 {
+            match self.market(&base_market).await? {
+                Some(market) if market.is_active && market.market_kind == market_kind => {
+                    market.symbol
+                }
+                _ => return Err(AgentHubError::MarketNotListed),
+            }
+        } else {
+            base_market
+        };
+
         // Get next strategy ID
         let id = *self.state.next_strategy_id.get();
         self.state.next_strategy_id.set(id + 1);
 
         let strategy = AgentStrategy {
             id,
+            global_id: GlobalId::new(self.runtime.chain_id(), id),
             owner,
             name,
             description,
@@ -297,442 +979,4484 @@ impl AgentHubContract {
             base_market,
             is_public,
             is_ai_controlled,
+            push_band_bps,
+            forked_from: None,
+            agent_key: None,
+            model,
+            version: 1,
+            version_started_at: self.now(),
+            performance_fee_bps: 0,
+            is_paused: false,
+            pause_reason: None,
+            max_followers: None,
+            tags: Vec::new(),
+            last_signal_at: None,
             created_at: self.now(),
         };
 
-        self.state.strategies.insert(&id, strategy).expect("Failed to insert strategy");
-        
-        // Initialize empty signal list
-        self.state.signals_by_strategy.insert(&id, Vec::new()).expect("Failed to init signals list");
+        self.state.strategies.insert(&id, strategy.clone())?;
+        self.record_change(ChangeEntity::Strategy, id, ChangeKind::Created);
+        self.state.global_counters.get_mut().total_strategies += 1;
         
         // Initialize stats
         let stats = StrategyStats {
             strategy_id: id,
             ..Default::default()
         };
-        self.state.strategy_stats.insert(&id, stats).expect("Failed to init stats");
+        self.state.strategy_stats.insert(&id, stats)?;
 
         // Initialize follower count
-        self.state.follower_count.insert(&id, 0).expect("Failed to init follower count");
+        self.state.follower_count.insert(&id, 0)?;
+
+        // Publish public strategies to the hub's global catalog
+        if strategy.is_public {
+            self.register_on_hub(&strategy)?;
+        }
 
-        AgentHubResponse::StrategyCreated { id }
+        Ok(AgentHubResponse::StrategyCreated { id })
     }
 
-    /// Publish a new trading signal
-    async fn publish_signal(
+    /// Freeze a strategy's current track record under its version and start a new version
+    async fn bump_strategy_version(
         &mut self,
         owner: AccountOwner,
         strategy_id: u64,
-        direction: Direction,
-        horizon_secs: u64,
-        confidence_bps: u16,
-        entry_value: Option<u64>,
-    ) -> AgentHubResponse {
-        // Validate confidence
-        if confidence_bps > 10000 {
-            return AgentHubError::InvalidConfidence.into();
+        change_note: String,
+        model: Option<ModelInfo>,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        let mut strategy = match self.state.strategies.get(&strategy_id).await? {
+            Some(s) => s,
+            _ => return Err(AgentHubError::StrategyNotFound),
+        };
+        if strategy.owner != owner {
+            return Err(AgentHubError::NotAuthorized);
         }
 
-        // Check strategy exists and owned by caller
-        let strategy = match self.state.strategies.get(&strategy_id).await {
-            Ok(Some(s)) => s,
-            _ => return AgentHubError::StrategyNotFound.into(),
+        let now = self.now();
+        let (stats, _) = self.compute_stats(strategy_id, strategy.version).await?;
+        let retired = StrategyVersion {
+            strategy_id,
+            version: strategy.version,
+            change_note,
+            model: strategy.model.clone(),
+            started_at: strategy.version_started_at,
+            ended_at: now,
+            stats: StrategyStats {
+                followers: self.state.follower_count.get(&strategy_id).await?
+                    .unwrap_or(0),
+                ..stats
+            },
         };
+        self.state.strategy_versions.insert(&(strategy_id, strategy.version), retired)?;
 
-        if strategy.owner != owner {
-            return AgentHubError::NotAuthorized.into();
+        strategy.version += 1;
+        strategy.version_started_at = now;
+        if model.is_some() {
+            strategy.model = model;
         }
+        let version = strategy.version;
+        self.state.strategies.insert(&strategy_id, strategy)?;
+        self.record_change(ChangeEntity::Strategy, strategy_id, ChangeKind::Updated);
 
-        // Get next signal ID
-        let id = *self.state.next_signal_id.get();
-        self.state.next_signal_id.set(id + 1);
+        // Restart the live stats (and leaderboard position) from the new version's signals
+        self.update_strategy_stats(strategy_id).await?;
 
-        let now = self.now();
-        let expires_at = linera_sdk::linera_base_types::Timestamp::from(
-            now.micros() + (horizon_secs * 1_000_000)
-        );
+        Ok(AgentHubResponse::StrategyVersionBumped { strategy_id, version })
+    }
 
-        let signal = Signal {
-            id,
-            strategy_id,
-            created_at: now,
-            expires_at,
+    /// Refresh a retired version's frozen stats after one of its open signals settles
+    async fn refresh_version_stats(&mut self, strategy_id: u64, version: u32) -> Result<(), ViewError> {
+        let key = (strategy_id, version);
+        let Some(mut retired) = self.state.strategy_versions.get(&key).await? else {
+            return Ok(());
+        };
+        let (stats, _) = self.compute_stats(strategy_id, version).await?;
+        retired.stats = StrategyStats { followers: retired.stats.followers, ..stats };
+        self.state.strategy_versions.insert(&key, retired)?;
+        Ok(())
+    }
+
+    /// Create a strategy copying the settings of a public or owned strategy, with attribution
+    async fn fork_strategy(
+        &mut self,
+        owner: AccountOwner,
+        source_strategy_id: u64,
+        name: String,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        let source = match self.state.strategies.get(&source_strategy_id).await? {
+            Some(s) => s,
+            _ => return Err(AgentHubError::StrategyNotFound),
+        };
+        if !source.is_public && source.owner != owner {
+            return Err(AgentHubError::NotAuthorized);
+        }
+
+        let response = self.create_strategy(
+            owner,
+            name,
+            source.description,
+            source.market_kind,
+            source.base_market,
+            source.is_public,
+            source.is_ai_controlled,
+            source.push_band_bps,
+            source.model,
+        ).await;
+        let Ok(AgentHubResponse::StrategyCreated { id }) = response else {
+            return response;
+        };
+
+        let mut strategy = self.state.strategies.get(&id).await?
+            .ok_or_else(|| AgentHubError::Internal("Forked strategy was not stored".to_string()))?;
+        strategy.forked_from = Some(source.global_id);
+        self.state.strategies.insert(&id, strategy)?;
+        self.record_change(ChangeEntity::Strategy, id, ChangeKind::Updated);
+
+        let mut forks = self.state.forks_by_strategy.get(&source_strategy_id).await?
+            .unwrap_or_default();
+        forks.push(id);
+        self.state.forks_by_strategy.insert(&source_strategy_id, forks)?;
+
+        response
+    }
+
+    /// Stats, reputation and Merkle root of a public strategy, for cross-application reads
+    async fn verified_stats(&mut self, strategy_id: u64) -> Result<AgentHubResponse, AgentHubError> {
+        let strategy = match self.state.strategies.get(&strategy_id).await? {
+            Some(s) if s.is_public => s,
+            _ => return Err(AgentHubError::StrategyNotFound),
+        };
+        let stats = self.state.strategy_stats.get(&strategy_id).await?
+            .unwrap_or_default();
+        let reputation = self.state.reputation.get(&strategy.owner).await?
+            .unwrap_or(0);
+        let peaks = self.state.merkle_peaks.get(&strategy_id).await?
+            .unwrap_or_default();
+
+        Ok(AgentHubResponse::VerifiedStats(Box::new(VerifiedStats {
+            strategy: strategy.global_id,
+            owner: strategy.owner,
+            stats,
+            reputation,
+            merkle_root: merkle_root(&peaks),
+        })))
+    }
+
+    /// Publish a strategy's resolved signals as a canonical data blob and record its hash
+    async fn export_track_record(
+        &mut self,
+        owner: AccountOwner,
+        strategy_id: u64,
+        start: u64,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        const MAX_SIGNALS_PER_EXPORT: u64 = 500;
+
+        let strategy = match self.state.strategies.get(&strategy_id).await? {
+            Some(s) => s,
+            _ => return Err(AgentHubError::StrategyNotFound),
+        };
+        if strategy.owner != owner {
+            return Err(AgentHubError::NotAuthorized);
+        }
+
+        let count = self.state.strategy_signal_count(strategy_id).await? as u64;
+        let start = start.min(count);
+        let end = start.saturating_add(MAX_SIGNALS_PER_EXPORT).min(count);
+        let mut signals = Vec::new();
+        for signal_id in self.state.strategy_signal_ids(strategy_id, start as usize..end as usize).await? {
+            if let Some(signal) = self.state.signals.get(&signal_id).await? {
+                if signal.status == SignalStatus::Resolved {
+                    signals.push(signal);
+                }
+            }
+        }
+
+        let exported_at = self.now();
+        let signal_count = signals.len() as u64;
+        let blob = TrackRecordBlob {
+            strategy: strategy.global_id,
+            owner,
+            exported_at,
+            stats: self.state.strategy_stats.get(&strategy_id).await?.unwrap_or_default(),
+            start,
+            end,
+            signals,
+        };
+        let bytes = bcs::to_bytes(&blob).map_err(|error| AgentHubError::Internal(error.to_string()))?;
+        let blob_hash = self.runtime.create_data_blob(bytes);
+
+        let mut exports = self.state.track_record_exports.get(&strategy_id).await?.unwrap_or_default();
+        exports.push(TrackRecordExport { strategy_id, blob_hash, signal_count, exported_at, start, end });
+        self.state.track_record_exports.insert(&strategy_id, exports)?;
+
+        Ok(AgentHubResponse::TrackRecordExported { strategy_id, blob_hash })
+    }
+
+    /// Send a strategy summary to the hub chain (or store it directly on the hub)
+    fn register_on_hub(&mut self, strategy: &AgentStrategy) -> Result<(), ViewError> {
+        let Some(hub_chain_id) = *self.state.hub_chain_id.get() else {
+            return Ok(());
+        };
+        let chain_id = self.runtime.chain_id();
+        let summary = Self::strategy_summary(strategy);
+
+        if hub_chain_id == chain_id {
+            self.store_hub_strategy(summary)?;
+        } else {
+            self.runtime.prepare_message(Message::RegisterStrategyOnHub { summary }.into())
+                .send_to(hub_chain_id);
+        }
+        Ok(())
+    }
+
+    /// The hub catalog's view of a strategy
+    fn strategy_summary(strategy: &AgentStrategy) -> StrategySummary {
+        StrategySummary {
+            global_id: strategy.global_id,
+            owner: strategy.owner,
+            name: strategy.name.clone(),
+            description: strategy.description.clone(),
+            market_kind: strategy.market_kind.clone(),
+            base_market: strategy.base_market.clone(),
+            is_ai_controlled: strategy.is_ai_controlled,
+            created_at: strategy.created_at,
+        }
+    }
+
+    /// Store a strategy summary in the hub's global catalog
+    fn store_hub_strategy(&mut self, summary: StrategySummary) -> Result<(), ViewError> {
+        let global_id = summary.global_id;
+        self.state.strategist_chains.insert(&summary.owner, global_id.chain_id)?;
+        self.state.hub_strategies.insert(&global_id, summary)?;
+        Ok(())
+    }
+
+    /// Move a strategy to its new position in the sorted leaderboard index
+    async fn update_leaderboard(&mut self, strategy_id: u64, stats: &StrategyStats) -> Result<(), ViewError> {
+        if let Some(old_key) = self.state.leaderboard_keys.get(&strategy_id).await? {
+            self.state.leaderboard.remove(&old_key)?;
+            self.state.leaderboard_keys.remove(&strategy_id)?;
+        }
+
+        let is_public = matches!(
+            self.state.strategies.get(&strategy_id).await?,
+            Some(strategy) if strategy.is_public
+        );
+        if !is_public || stats.total_signals == 0 {
+            return Ok(());
+        }
+
+        let key = LeaderboardKey {
+            win_rate_bps: stats.win_rate_bps,
+            total_pnl_bps: stats.total_pnl_bps,
+            strategy_id,
+        };
+        self.state.leaderboard.insert(&key)?;
+        self.state.leaderboard_keys.insert(&strategy_id, key)?;
+        Ok(())
+    }
+
+    /// Pass a public strategy's new stats on to the hub: directly on the hub itself, otherwise
+    /// queued for the next `StatsSync` batch
+    async fn sync_stats_to_hub(&mut self, strategy_id: u64, stats: StrategyStats) -> Result<(), ViewError> {
+        let Some(hub_chain_id) = *self.state.hub_chain_id.get() else {
+            return Ok(());
+        };
+        let Some(strategy) = self.state.strategies.get(&strategy_id).await? else {
+            return Ok(());
+        };
+        if !strategy.is_public {
+            return Ok(());
+        }
+
+        if hub_chain_id == self.runtime.chain_id() {
+            self.store_hub_stats(strategy.global_id, stats).await?;
+        } else {
+            self.state.hub_sync_pending.insert(&strategy_id)?;
+        }
+        Ok(())
+    }
+
+    /// Send the queued stats to the hub in one `StatsSync` batch, at most once per interval
+    /// unless the queue has filled a batch. Runs with every block, so a quiet chain sends its
+    /// last changes with its next block.
+    async fn flush_hub_sync(&mut self) -> Result<(), ViewError> {
+        const SYNC_INTERVAL_MICROS: u64 = 5 * 60 * 1_000_000;
+        const MAX_SYNC_BATCH: usize = 50;
+
+        let Some(hub_chain_id) = *self.state.hub_chain_id.get() else {
+            return Ok(());
+        };
+        let pending = self.state.hub_sync_pending.indices().await?;
+        if pending.is_empty() {
+            return Ok(());
+        }
+        let now = self.now();
+        let synced_at = *self.state.hub_synced_at.get();
+        if pending.len() < MAX_SYNC_BATCH && now.delta_since(synced_at).as_micros() < SYNC_INTERVAL_MICROS {
+            return Ok(());
+        }
+
+        let mut strategy_summaries = Vec::new();
+        for strategy_id in pending.into_iter().take(MAX_SYNC_BATCH) {
+            self.state.hub_sync_pending.remove(&strategy_id)?;
+            let Some(strategy) = self.state.strategies.get(&strategy_id).await? else {
+                continue;
+            };
+            let stats = self.state.strategy_stats.get(&strategy_id).await?
+                .unwrap_or_default();
+            strategy_summaries.push(SyncedStrategy { summary: Self::strategy_summary(&strategy), stats });
+        }
+        self.state.hub_synced_at.set(now);
+        if !strategy_summaries.is_empty() {
+            self.runtime.prepare_message(Message::StatsSync { strategy_summaries }.into())
+                .send_to(hub_chain_id);
+        }
+        Ok(())
+    }
+
+    /// Store synced stats on the hub and refresh the leaderboards if the last snapshot is stale
+    async fn store_hub_stats(&mut self, strategy: GlobalId, stats: StrategyStats) -> Result<(), ViewError> {
+        const SNAPSHOT_INTERVAL_MICROS: u64 = 60 * 60 * 1_000_000;
+
+        self.state.hub_stats.insert(&strategy, stats)?;
+
+        let now = self.now();
+        let taken_at = *self.state.hub_leaderboard_taken_at.get();
+        if *self.state.hub_leaderboard_epoch.get() == 0
+            || now.delta_since(taken_at).as_micros() >= SNAPSHOT_INTERVAL_MICROS
+        {
+            self.snapshot_hub_leaderboards(now).await?;
+        }
+        Ok(())
+    }
+
+    /// Materialize a new epoch of top-N leaderboards, one per market kind
+    async fn snapshot_hub_leaderboards(&mut self, now: Timestamp) -> Result<(), ViewError> {
+        const LEADERBOARD_SIZE: usize = 50;
+
+        let mut ranked = Vec::new();
+        for (global_id, stats) in self.state.hub_stats.index_values().await? {
+            if stats.total_signals == 0 {
+                continue;
+            }
+            if let Some(summary) = self.state.hub_strategies.get(&global_id).await? {
+                ranked.push((summary, stats));
+            }
+        }
+
+        // Sort by win rate DESC, then by total PnL DESC
+        ranked.sort_by(|(_, a), (_, b)| {
+            b.win_rate_bps.cmp(&a.win_rate_bps)
+                .then_with(|| b.total_pnl_bps.cmp(&a.total_pnl_bps))
+        });
+
+        let epoch = *self.state.hub_leaderboard_epoch.get() + 1;
+        // Every built-in kind gets a board, plus each custom kind in use
+        let mut market_kinds = MarketKind::BUILT_IN.to_vec();
+        for (summary, _) in &ranked {
+            if !market_kinds.contains(&summary.market_kind) {
+                market_kinds.push(summary.market_kind.clone());
+            }
+        }
+
+        for market_kind in market_kinds {
+            let entries = ranked.iter()
+                .filter(|(summary, _)| summary.market_kind == market_kind)
+                .take(LEADERBOARD_SIZE)
+                .enumerate()
+                .map(|(index, (summary, stats))| LeaderboardEntry {
+                    rank: index as u32 + 1,
+                    strategy: summary.clone(),
+                    stats: stats.clone(),
+                })
+                .collect();
+            let key = (epoch, market_kind.clone());
+            let leaderboard = HubLeaderboard { epoch, market_kind, taken_at: now, entries };
+            self.state.hub_leaderboards.insert(&key, leaderboard)?;
+        }
+
+        self.state.hub_leaderboard_epoch.set(epoch);
+        self.state.hub_leaderboard_taken_at.set(now);
+        Ok(())
+    }
+
+    /// Publish a new trading signal
+    #[allow(clippy::too_many_arguments)]
+    async fn publish_signal(
+        &mut self,
+        owner: AccountOwner,
+        strategy_id: u64,
+        direction: Direction,
+        horizon_secs: u64,
+        confidence_bps: u16,
+        entry_value: Option<Price>,
+        target_value: Option<Price>,
+        stop_value: Option<Price>,
+        range: Option<PriceRange>,
+        attestation: Option<AttestationInput>,
+        visibility: SignalVisibility,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        // Validate confidence
+        if confidence_bps > 10000 {
+            return Err(AgentHubError::InvalidConfidence);
+        }
+
+        if range.is_some_and(|range| range.low >= range.high) {
+            return Err(AgentHubError::InvalidRange);
+        }
+
+        // Validate horizon against the application parameters
+        let parameters = self.runtime.application_parameters();
+        if horizon_secs < parameters.min_horizon_secs || horizon_secs > parameters.max_horizon_secs {
+            return Err(AgentHubError::InvalidHorizon);
+        }
+
+        // Reject horizons whose expiry does not fit in a timestamp
+        let now = self.now();
+        let Some(expires_at) = signal_expiry(now, horizon_secs) else {
+            return Err(AgentHubError::InvalidHorizon);
+        };
+
+        // Check strategy exists and owned by caller
+        let strategy = match self.state.strategies.get(&strategy_id).await? {
+            Some(s) => s,
+            _ => return Err(AgentHubError::StrategyNotFound),
+        };
+
+        if strategy.owner != owner {
+            return Err(AgentHubError::NotAuthorized);
+        }
+        if strategy.is_paused {
+            return Err(AgentHubError::StrategyPaused);
+        }
+
+        // An attestation must be signed by the strategy's registered agent key
+        let attestation = match attestation {
+            Some(input) => {
+                let Some(agent_key) = strategy.agent_key else {
+                    return Err(AgentHubError::InvalidAttestation);
+                };
+                let attestation = Attestation {
+                    payload_hash: input.payload_hash,
+                    signature: input.signature,
+                    agent_key,
+                };
+                if !attestation.verify_call(strategy.global_id, strategy.version, direction, confidence_bps) {
+                    return Err(AgentHubError::InvalidAttestation);
+                }
+                Some(attestation)
+            }
+            None => None,
+        };
+
+        // The oracle's price is canonical when one is configured
+        let (entry_value, entry_observed_at) = match self.oracle_quote(&strategy).await? {
+            Some((_, quote)) => (Some(quote.price), Some(quote.observed_at)),
+            None => (entry_value, None),
+        };
+
+        let price_decimals = self.market(&strategy.base_market).await?
+            .map_or(DEFAULT_PRICE_DECIMALS, |market| market.decimals);
+
+        // A signal without an entry value can never be scored
+        let Some(entry_value) = entry_value.filter(|value| !value.is_zero()) else {
+            return Err(AgentHubError::InvalidEntryValue);
+        };
+
+        // Exit levels must sit on the correct side of the entry
+        let bullish = direction.is_bullish();
+        let target_ok = target_value.is_none_or(|target| {
+            if bullish { target > entry_value } else { target < entry_value }
+        });
+        let stop_ok = stop_value.is_none_or(|stop| {
+            if bullish { stop < entry_value } else { stop > entry_value }
+        });
+        if !target_ok || !stop_ok {
+            return Err(AgentHubError::InvalidExitLevels);
+        }
+
+        // Enforce the open signal cap
+        let open_signals = self.state.open_signal_count.get(&strategy_id).await?
+            .unwrap_or(0);
+        if parameters.max_open_signals_per_strategy > 0
+            && open_signals >= parameters.max_open_signals_per_strategy
+        {
+            return Err(AgentHubError::TooManyOpenSignals);
+        }
+
+        // Enforce publishing rate limits
+        if !self.record_publish(owner).await? {
+            return Err(AgentHubError::RateLimited);
+        }
+
+        // Get next signal ID
+        let id = *self.state.next_signal_id.get();
+        self.state.next_signal_id.set(id + 1);
+
+        let signal = Signal {
+            id,
+            global_id: GlobalId::new(self.runtime.chain_id(), id),
+            strategy_id,
+            created_at: now,
+            expires_at,
             direction,
-            entry_value,
+            entry_value: Some(entry_value),
+            price_decimals,
+            entry_observed_at,
+            target_value,
+            stop_value,
             confidence_bps,
             status: SignalStatus::Open,
             result: None,
             pnl_bps: None,
             resolved_value: None,
+            resolution: None,
+            strategy_version: strategy.version,
+            visibility,
+            attestation,
+            closed_early: false,
+            unresolved: false,
+            range,
         };
 
-        self.state.signals.insert(&id, signal).expect("Failed to insert signal");
+        self.record_new_signal(&strategy, signal).await
+    }
+
+    /// Store a newly published signal, index it and deliver it to listeners
+    async fn record_new_signal(&mut self, strategy: &AgentStrategy, signal: Signal) -> Result<AgentHubResponse, AgentHubError> {
+        let id = signal.id;
+        let strategy_id = strategy.id;
+        let open_signals = self.state.open_signal_count.get(&strategy_id).await?
+            .unwrap_or(0);
+
+        self.state.signals.insert(&id, signal.clone())?;
+        self.record_change(ChangeEntity::Signal, id, ChangeKind::Created);
+        let resolution_key = ResolutionKey { expires_at: signal.expires_at, signal_id: id };
+        self.state.pending_resolution.insert(&resolution_key)?;
+        self.state.resolution_schedule.insert(&resolution_key)?;
+        self.state.expiry_sweep.insert(&ResolutionKey { expires_at: signal.expires_at, signal_id: id })?;
+        let creation_key = CreationKey { created_at: signal.created_at, signal_id: id };
+        self.state.signal_creation_index.insert(&creation_key)?;
+        self.state.strategy_creation_index.load_entry_mut(&strategy_id).await?
+            .insert(&creation_key)?;
+        self.state.recent_signals_log.push(id);
+        let counters = self.state.global_counters.get_mut();
+        counters.total_signals += 1;
+        counters.open_signals += 1;
+
+        self.state.open_signal_count.insert(&strategy_id, open_signals + 1)?;
+
+        let mut updated = strategy.clone();
+        updated.last_signal_at = Some(self.now());
+        self.state.strategies.insert(&strategy_id, updated)?;
 
         // Add to strategy's signal list
-        let mut signal_ids = self.state.signals_by_strategy.get(&strategy_id).await
-            .ok().flatten().unwrap_or_default();
-        signal_ids.push(id);
-        self.state.signals_by_strategy.insert(&strategy_id, signal_ids)
-            .expect("Failed to update signal list");
+        self.state.signals_by_strategy.load_entry_mut(&strategy_id).await?
+            .push(id);
+        self.index_signal_status(&strategy.base_market, id, None, SignalStatus::Open).await?;
 
-        // Emit event for cross-chain subscribers
-        let stream = StreamName::from(b"signals");
-        self.runtime.emit(stream, &AgentHubEvent::SignalPublished {
+        // Deliver the signal to subscriber and follower chains; a delayed-public signal goes
+        // out to everyone again once disclosed
+        if let Some(disclosed_at) = signal.disclosure_time().filter(|at| *at > self.now()) {
+            let key = ResolutionKey { expires_at: disclosed_at, signal_id: id };
+            self.state.disclosure_schedule.insert(&key)?;
+        }
+        self.broadcast_signal(strategy, &signal).await?;
+        self.copy_signal(strategy, &signal).await?;
+
+        Ok(AgentHubResponse::SignalPublished { id })
+    }
+
+    /// Commit to a hidden signal: timing, entry and limits are settled now, the call later
+    async fn commit_signal(
+        &mut self,
+        owner: AccountOwner,
+        strategy_id: u64,
+        commitment_hash: CryptoHash,
+        horizon_secs: u64,
+        entry_value: Option<Price>,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        const REVEAL_WINDOW_MICROS: u64 = 10 * 60 * 1_000_000;
+
+        let parameters = self.runtime.application_parameters();
+        if horizon_secs < parameters.min_horizon_secs || horizon_secs > parameters.max_horizon_secs {
+            return Err(AgentHubError::InvalidHorizon);
+        }
+        let now = self.now();
+        let Some(expires_at) = signal_expiry(now, horizon_secs) else {
+            return Err(AgentHubError::InvalidHorizon);
+        };
+
+        let strategy = match self.state.strategies.get(&strategy_id).await? {
+            Some(s) => s,
+            _ => return Err(AgentHubError::StrategyNotFound),
+        };
+        if strategy.owner != owner {
+            return Err(AgentHubError::NotAuthorized);
+        }
+        if strategy.is_paused {
+            return Err(AgentHubError::StrategyPaused);
+        }
+        if self.state.open_commitments.contains_key(&strategy_id).await? {
+            return Err(AgentHubError::CommitmentPending);
+        }
+
+        let (entry_value, entry_observed_at) = match self.oracle_quote(&strategy).await? {
+            Some((_, quote)) => (Some(quote.price), Some(quote.observed_at)),
+            None => (entry_value, None),
+        };
+        let Some(entry_value) = entry_value.filter(|value| !value.is_zero()) else {
+            return Err(AgentHubError::InvalidEntryValue);
+        };
+        let price_decimals = self.market(&strategy.base_market).await?
+            .map_or(DEFAULT_PRICE_DECIMALS, |market| market.decimals);
+
+        let open_signals = self.state.open_signal_count.get(&strategy_id).await?
+            .unwrap_or(0);
+        if parameters.max_open_signals_per_strategy > 0
+            && open_signals >= parameters.max_open_signals_per_strategy
+        {
+            return Err(AgentHubError::TooManyOpenSignals);
+        }
+        if !self.record_publish(owner).await? {
+            return Err(AgentHubError::RateLimited);
+        }
+
+        let id = *self.state.next_signal_id.get();
+        self.state.next_signal_id.set(id + 1);
+
+        let reveal_deadline = now
+            .saturating_add(TimeDelta::from_micros(REVEAL_WINDOW_MICROS))
+            .min(expires_at);
+        let commitment = SignalCommitment {
+            id,
             strategy_id,
-            signal_id: id,
+            commitment_hash,
+            committed_at: now,
+            expires_at,
+            reveal_deadline,
+            entry_value,
+            price_decimals,
+            entry_observed_at,
+        };
+        self.state.signal_commitments.insert(&id, commitment)?;
+
+        // The commitment holds an open-signal slot until it is revealed or lapses
+        self.state.open_commitments.insert(&strategy_id, id)?;
+        self.state.commitment_deadlines.push_back((reveal_deadline, id));
+        self.state.global_counters.get_mut().open_signals += 1;
+        self.state.open_signal_count.insert(&strategy_id, open_signals + 1)?;
+
+        Ok(AgentHubResponse::SignalCommitted { id, reveal_deadline })
+    }
+
+    /// Reveal a committed signal and publish it as of its commit time
+    async fn reveal_signal(
+        &mut self,
+        owner: AccountOwner,
+        signal_id: u64,
+        direction: Direction,
+        confidence_bps: u16,
+        salt: String,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        let commitment = match self.state.signal_commitments.get(&signal_id).await? {
+            Some(c) => c,
+            _ => return Err(AgentHubError::CommitmentNotFound),
+        };
+        let strategy = match self.state.strategies.get(&commitment.strategy_id).await? {
+            Some(s) => s,
+            _ => return Err(AgentHubError::StrategyNotFound),
+        };
+        if strategy.owner != owner {
+            return Err(AgentHubError::NotAuthorized);
+        }
+        if strategy.is_paused {
+            return Err(AgentHubError::StrategyPaused);
+        }
+        if self.now() > commitment.reveal_deadline {
+            return Err(AgentHubError::RevealWindowClosed);
+        }
+        if confidence_bps > 10000 {
+            return Err(AgentHubError::InvalidConfidence);
+        }
+
+        let call = CommittedCall { strategy: strategy.global_id, direction, confidence_bps, salt };
+        if CryptoHash::new(&call) != commitment.commitment_hash {
+            return Err(AgentHubError::CommitmentMismatch);
+        }
+
+        // The slot taken at commit time passes to the published signal; commitments from
+        // before slots were reserved are checked against the limit now
+        if self.release_commitment(strategy.id, signal_id).await? {
+            self.decrement_open_signals(strategy.id).await?;
+        } else {
+            let open_signals = self.state.open_signal_count.get(&strategy.id).await?
+                .unwrap_or(0);
+            let max_open_signals = self.runtime.application_parameters().max_open_signals_per_strategy;
+            if max_open_signals > 0 && open_signals >= max_open_signals {
+                return Err(AgentHubError::TooManyOpenSignals);
+            }
+        }
+
+        self.state.signal_commitments.remove(&signal_id)?;
+
+        let signal = Signal {
+            id: signal_id,
+            global_id: GlobalId::new(self.runtime.chain_id(), signal_id),
+            strategy_id: strategy.id,
+            created_at: commitment.committed_at,
+            expires_at: commitment.expires_at,
             direction,
+            entry_value: Some(commitment.entry_value),
+            price_decimals: commitment.price_decimals,
+            entry_observed_at: commitment.entry_observed_at,
+            target_value: None,
+            stop_value: None,
             confidence_bps,
-        });
+            status: SignalStatus::Open,
+            result: None,
+            pnl_bps: None,
+            resolved_value: None,
+            resolution: None,
+            strategy_version: strategy.version,
+            visibility: SignalVisibility::Public,
+            attestation: None,
+            closed_early: false,
+            unresolved: false,
+            range: None,
+        };
+
+        self.record_new_signal(&strategy, signal).await
+    }
+
+    /// Clear a strategy's outstanding commitment if it is `signal_id`; false if the commitment
+    /// never held an open-signal slot
+    async fn release_commitment(&mut self, strategy_id: u64, signal_id: u64) -> Result<bool, ViewError> {
+        if self.state.open_commitments.get(&strategy_id).await? != Some(signal_id) {
+            return Ok(false);
+        }
+        self.state.open_commitments.remove(&strategy_id)?;
+        Ok(true)
+    }
+
+    /// Drop commitments whose reveal window has closed. A lapsed commitment counts as an
+    /// unresolved signal, so committing to a call and revealing only if it went well does not
+    /// pay.
+    async fn lapse_commitments(&mut self) -> Result<(), ViewError> {
+        const MAX_LAPSES_PER_BLOCK: usize = 20;
+
+        let now = self.now();
+        for _ in 0..MAX_LAPSES_PER_BLOCK {
+            let Some((reveal_deadline, signal_id)) = self.state.commitment_deadlines.front().await? else {
+                break;
+            };
+            if reveal_deadline >= now {
+                break;
+            }
+            self.state.commitment_deadlines.delete_front();
+
+            // Revealed commitments are gone already
+            let Some(commitment) = self.state.signal_commitments.get(&signal_id).await? else {
+                continue;
+            };
+            self.state.signal_commitments.remove(&signal_id)?;
+            let strategy_id = commitment.strategy_id;
+            if self.release_commitment(strategy_id, signal_id).await? {
+                self.decrement_open_signals(strategy_id).await?;
+            }
+
+            let version = match self.state.strategies.get(&strategy_id).await? {
+                Some(strategy) => strategy.version,
+                _ => continue,
+            };
+            let key = (strategy_id, version);
+            let lapsed = self.state.lapsed_commitments.get(&key).await?
+                .unwrap_or(0);
+            self.state.lapsed_commitments.insert(&key, lapsed + 1)?;
+            self.update_strategy_stats(strategy_id).await?;
+        }
+        Ok(())
+    }
+
+    /// Move a signal between (market, status) buckets of the signals-by-market index
+    async fn index_signal_status(
+        &mut self,
+        base_market: &str,
+        signal_id: u64,
+        from: Option<SignalStatus>,
+        to: SignalStatus,
+    ) -> Result<(), ViewError> {
+        if let Some(from) = from {
+            self.state.signals_by_market.load_entry_mut(&(base_market.to_string(), from)).await?
+                .remove(&ReverseId(signal_id))?;
+        }
+        self.state.signals_by_market.load_entry_mut(&(base_market.to_string(), to)).await?
+            .insert(&ReverseId(signal_id))?;
+        Ok(())
+    }
+
+    /// Resolve expired signals from the oracle. Linera cannot deliver a message at a future
+    /// time, so instead of a timelocked message each signal is scheduled at publication and
+    /// the schedule is worked off by every block this chain executes. Signals whose market has
+    /// no oracle are dropped from the schedule and left to `ResolveSignal`.
+    async fn resolve_due_signals(&mut self) -> Result<(), AgentHubError> {
+        const MAX_RESOLUTIONS_PER_BLOCK: usize = 20;
+
+        let now = self.now();
+        let mut due = Vec::new();
+        self.state.resolution_schedule.for_each_index_while(|key| {
+            if key.expires_at > now || due.len() >= MAX_RESOLUTIONS_PER_BLOCK {
+                return Ok(false);
+            }
+            due.push(key);
+            Ok(true)
+        }).await?;
 
-        AgentHubResponse::SignalPublished { id }
+        for key in due {
+            self.state.resolution_schedule.remove(&key)?;
+            let signal = match self.state.signals.get(&key.signal_id).await? {
+                Some(signal) if signal.status == SignalStatus::Open => signal,
+                _ => continue,
+            };
+            let Some(strategy) = self.state.strategies.get(&signal.strategy_id).await? else {
+                continue;
+            };
+            if let Some((oracle, quote)) = self.oracle_quote(&strategy).await? {
+                let provenance = ResolutionProvenance {
+                    source: ResolutionSource::Oracle,
+                    resolver: None,
+                    oracle: Some(oracle),
+                    observed_at: quote.observed_at,
+                    proof: None,
+                };
+                if let Err(error @ AgentHubError::Internal(_)) = self.settle_signal(signal, quote.price, provenance).await {
+                    return Err(error);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Auto-cancel signals still open `resolution_grace_secs` after expiry. They are flagged
+    /// `unresolved` and counted in the strategy's stats, so a strategist cannot dodge a losing
+    /// call by never resolving it.
+    async fn expire_unresolved_signals(&mut self) -> Result<(), ViewError> {
+        const MAX_EXPIRIES_PER_BLOCK: usize = 20;
+
+        let grace = TimeDelta::from_secs(self.runtime.application_parameters().resolution_grace_secs);
+        let now = self.now();
+        let mut due = Vec::new();
+        self.state.expiry_sweep.for_each_index_while(|key| {
+            if key.expires_at.saturating_add(grace) > now || due.len() >= MAX_EXPIRIES_PER_BLOCK {
+                return Ok(false);
+            }
+            due.push(key);
+            Ok(true)
+        }).await?;
+
+        for key in due {
+            self.state.expiry_sweep.remove(&key)?;
+            if grace == TimeDelta::ZERO {
+                continue;
+            }
+            let mut signal = match self.state.signals.get(&key.signal_id).await? {
+                Some(signal) if signal.status == SignalStatus::Open => signal,
+                _ => continue,
+            };
+            let Some(strategy) = self.state.strategies.get(&signal.strategy_id).await? else {
+                continue;
+            };
+            signal.unresolved = true;
+            self.mark_cancelled(&strategy, signal).await?;
+            self.update_strategy_stats(strategy.id).await?;
+        }
+        Ok(())
+    }
+
+    /// Decrement a strategy's open signal count after a resolution or cancellation
+    async fn decrement_open_signals(&mut self, strategy_id: u64) -> Result<(), ViewError> {
+        let counters = self.state.global_counters.get_mut();
+        counters.open_signals = counters.open_signals.saturating_sub(1);
+
+        let open_signals = self.state.open_signal_count.get(&strategy_id).await?
+            .unwrap_or(0);
+        self.state.open_signal_count.insert(&strategy_id, open_signals.saturating_sub(1))?;
+        Ok(())
+    }
+
+    /// Record a publish by the strategist, returning false if it would exceed the rate limits
+    async fn record_publish(&mut self, owner: AccountOwner) -> Result<bool, ViewError> {
+        const HOUR_MICROS: u64 = 60 * 60 * 1_000_000;
+        const DAY_MICROS: u64 = 24 * HOUR_MICROS;
+
+        let limits = *self.state.rate_limits.get();
+        let now = self.now();
+
+        let mut history = self.state.publish_history.get(&owner).await?
+            .unwrap_or_default();
+        history.retain(|t| now.delta_since(*t).as_micros() < DAY_MICROS);
+
+        let last_hour = history.iter()
+            .filter(|t| now.delta_since(**t).as_micros() < HOUR_MICROS)
+            .count() as u64;
+        let last_day = history.len() as u64;
+
+        if limits.max_signals_per_hour > 0 && last_hour >= limits.max_signals_per_hour as u64 {
+            return Ok(false);
+        }
+        if limits.max_signals_per_day > 0 && last_day >= limits.max_signals_per_day as u64 {
+            return Ok(false);
+        }
+
+        history.push(now);
+        self.state.publish_history.insert(&owner, history)?;
+        Ok(true)
+    }
+
+    /// Resolve an open signal with the final value
+    async fn resolve_signal(
+        &mut self,
+        signal_id: u64,
+        resolved_value: Price,
+        provenance: ResolutionProvenance,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        // Get signal
+        let signal = match self.state.signals.get(&signal_id).await? {
+            Some(s) => s,
+            _ => return Err(AgentHubError::SignalNotFound),
+        };
+
+        // Check signal is open
+        if signal.status != SignalStatus::Open {
+            return Err(AgentHubError::SignalAlreadyResolved);
+        }
+
+        // Only expired signals may be resolved, unless a target or stop was reached
+        if self.now() < signal.expires_at && !Self::exit_level_reached(&signal, resolved_value) {
+            return Err(AgentHubError::TooEarlyToResolve);
+        }
+
+        // Past the grace period the signal is auto-cancelled as unresolved instead
+        let grace_secs = self.runtime.application_parameters().resolution_grace_secs;
+        if grace_secs > 0 && self.now() >= signal.expires_at.saturating_add(TimeDelta::from_secs(grace_secs)) {
+            return Err(AgentHubError::ResolutionWindowClosed);
+        }
+
+        self.settle_signal(signal, resolved_value, provenance).await
+    }
+
+    /// Record a resolver's price for an open signal, taken within a few minutes of its expiry
+    async fn submit_price_observation(
+        &mut self,
+        resolver: AccountOwner,
+        signal_id: u64,
+        value: Price,
+        observed_at: Timestamp,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        const OBSERVATION_WINDOW_MICROS: u64 = 5 * 60 * 1_000_000;
+        const MAX_OBSERVATIONS: usize = 60;
+
+        let signal = match self.state.signals.get(&signal_id).await? {
+            Some(s) => s,
+            _ => return Err(AgentHubError::SignalNotFound),
+        };
+        if signal.status != SignalStatus::Open {
+            return Err(AgentHubError::SignalNotOpen);
+        }
+
+        let earliest = Timestamp::from(signal.expires_at.micros().saturating_sub(OBSERVATION_WINDOW_MICROS));
+        let latest = signal.expires_at.saturating_add(TimeDelta::from_micros(OBSERVATION_WINDOW_MICROS));
+        if value.is_zero() || observed_at < earliest || observed_at > latest || observed_at > self.now() {
+            return Err(AgentHubError::InvalidObservation);
+        }
+
+        let mut observations = self.state.price_observations.get(&signal_id).await?
+            .unwrap_or_default();
+        if observations.len() >= MAX_OBSERVATIONS
+            || observations.iter().any(|o| o.observed_at == observed_at)
+        {
+            return Err(AgentHubError::InvalidObservation);
+        }
+        let index = observations.partition_point(|o| o.observed_at < observed_at);
+        observations.insert(index, PriceObservation { resolver, value, observed_at });
+        let count = observations.len() as u32;
+        self.state.price_observations.insert(&signal_id, observations)?;
+
+        Ok(AgentHubResponse::PriceObserved { signal_id, observations: count })
+    }
+
+    /// Resolve a signal at the time-weighted average of its price observations, which a single
+    /// manipulated print cannot move far
+    async fn resolve_signal_with_twap(&mut self, resolver: AccountOwner, signal_id: u64) -> Result<AgentHubResponse, AgentHubError> {
+        const MIN_OBSERVATIONS: usize = 3;
+
+        let observations = self.state.price_observations.get(&signal_id).await?
+            .unwrap_or_default();
+        if observations.len() < MIN_OBSERVATIONS {
+            return Err(AgentHubError::InsufficientObservations);
+        }
+        let Some(value) = time_weighted_average(&observations) else {
+            return Err(AgentHubError::InsufficientObservations);
+        };
+        let provenance = ResolutionProvenance {
+            source: ResolutionSource::Twap,
+            resolver: Some(resolver),
+            oracle: None,
+            observed_at: observations.last().map_or_else(|| self.now(), |o| o.observed_at),
+            proof: None,
+        };
+        self.resolve_signal(signal_id, value, provenance).await
+    }
+
+    /// Check a resolution's signature against the resolver's registered key. Resolvers without
+    /// a key resolve unsigned; once one is registered every resolution must carry a proof.
+    async fn check_resolution_proof(
+        &mut self,
+        resolver: AccountOwner,
+        signal_id: u64,
+        resolved_value: Price,
+        proof: Option<ResolutionProofInput>,
+    ) -> Result<Option<ResolutionProof>, AgentHubError> {
+        let resolver_key = self.state.resolver_keys.get(&resolver).await?;
+        let (resolver_key, proof) = match (resolver_key, proof) {
+            (None, None) => return Ok(None),
+            (Some(resolver_key), Some(proof)) => (resolver_key, proof),
+            _ => return Err(AgentHubError::InvalidResolutionProof),
+        };
+
+        let proof = ResolutionProof {
+            observed_at: proof.observed_at,
+            signature: proof.signature,
+            resolver_key,
+        };
+        let signal = GlobalId::new(self.runtime.chain_id(), signal_id);
+        if proof.observed_at > self.now() || !proof.verify(signal, resolved_value) {
+            return Err(AgentHubError::InvalidResolutionProof);
+        }
+        Ok(Some(proof))
+    }
+
+    /// Close an open signal before expiry at the current value
+    async fn close_signal_early(
+        &mut self,
+        owner: AccountOwner,
+        signal_id: u64,
+        current_value: Price,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        let mut signal = match self.state.signals.get(&signal_id).await? {
+            Some(s) => s,
+            _ => return Err(AgentHubError::SignalNotFound),
+        };
+
+        match self.state.strategies.get(&signal.strategy_id).await? {
+            Some(strategy) if strategy.owner == owner => {
+                if signal.status != SignalStatus::Open {
+                    return Err(AgentHubError::SignalNotOpen);
+                }
+                let (current_value, provenance) = match self.oracle_quote(&strategy).await? {
+                    Some((oracle, quote)) => (quote.price, ResolutionProvenance {
+                        source: ResolutionSource::Oracle,
+                        resolver: Some(owner),
+                        oracle: Some(oracle),
+                        observed_at: quote.observed_at,
+                        proof: None,
+                    }),
+                    None => (current_value, ResolutionProvenance {
+                        source: ResolutionSource::Strategist,
+                        resolver: Some(owner),
+                        oracle: None,
+                        observed_at: self.now(),
+                        proof: None,
+                    }),
+                };
+                signal.closed_early = true;
+                self.settle_signal(signal, current_value, provenance).await
+            }
+            Some(_) => Err(AgentHubError::NotAuthorized),
+            _ => Err(AgentHubError::StrategyNotFound),
+        }
+    }
+
+    /// Commit a resolved signal to its strategy's Merkle accumulator
+    async fn append_merkle_leaf(&mut self, signal: &Signal) -> Result<(), ViewError> {
+        let Some(leaf) = SignalLeaf::hash(signal) else {
+            return Ok(());
+        };
+        let log = self.state.merkle_leaves.load_entry_mut(&signal.strategy_id).await?;
+        let index = log.count() as u64;
+        log.push(leaf);
+        self.state.merkle_leaf_index.insert(&signal.id, index)?;
+
+        let mut peaks = self.state.merkle_peaks.get(&signal.strategy_id).await?
+            .unwrap_or_default();
+        merkle_append(&mut peaks, index, leaf);
+        self.state.merkle_peaks.insert(&signal.strategy_id, peaks)?;
+        Ok(())
+    }
+
+    /// Count a win/lose outcome in its strategy's confidence bucket
+    async fn record_calibration(&mut self, signal: &Signal) -> Result<(), ViewError> {
+        let index = CalibrationBucket::index_for(signal.confidence_bps);
+        let key = (signal.strategy_id, index);
+        let mut bucket = self.state.calibration.get(&key).await?
+            .unwrap_or_else(|| CalibrationBucket::empty(index));
+        bucket.resolved_signals += 1;
+        if signal.result == Some(SignalResult::Win) {
+            bucket.winning_signals += 1;
+        }
+        bucket.win_rate_bps = (bucket.winning_signals * 10000 / bucket.resolved_signals) as u32;
+        self.state.calibration.insert(&key, bucket)?;
+        Ok(())
+    }
+
+    /// Record a change of a strategy's follower count in today's snapshot
+    async fn record_follower_snapshot(&mut self, strategy_id: u64, previous: u64, followers: u64) -> Result<(), ViewError> {
+        let day = day_of(self.now());
+        let key = (strategy_id, day);
+        let mut snapshot = self.state.follower_history.get(&key).await?
+            .unwrap_or(FollowerSnapshot { day, opening_followers: previous, followers });
+        snapshot.followers = followers;
+        self.state.follower_history.insert(&key, snapshot)?;
+        Ok(())
+    }
+
+    /// Add a resolved signal to its strategy's bucket for today
+    async fn record_daily_pnl(&mut self, signal: &Signal) -> Result<(), ViewError> {
+        let day = day_of(self.now());
+        let key = (signal.strategy_id, day);
+        let mut bucket = self.state.daily_pnl.get(&key).await?
+            .unwrap_or_else(|| DailyPnl::empty(day));
+        bucket.resolved_signals += 1;
+        bucket.net_pnl_bps = bucket.net_pnl_bps.saturating_add(signal.pnl_bps.unwrap_or(0));
+        self.state.daily_pnl.insert(&key, bucket)?;
+        Ok(())
+    }
+
+    /// Squared error between a signal's declared confidence and its outcome, in basis points
+    fn brier_error_bps(signal: &Signal) -> u64 {
+        let outcome = if signal.result == Some(SignalResult::Win) { 10000 } else { 0 };
+        let error = (signal.confidence_bps as i64 - outcome).unsigned_abs();
+        error * error / 10000
+    }
+
+    /// Latest price of a strategy's market and the oracle it came from: the market's
+    /// registered oracle or, for price-based market kinds, the application-wide one, if any
+    async fn oracle_quote(
+        &mut self,
+        strategy: &AgentStrategy,
+    ) -> Result<Option<(ApplicationId, PriceQuote)>, ViewError> {
+        let market = &strategy.base_market;
+        let oracle = match self.market(market).await?.and_then(|market| market.oracle) {
+            Some(oracle) => oracle,
+            None if strategy.market_kind.is_priced() => {
+                match self.runtime.application_parameters().price_oracle {
+                    Some(oracle) => oracle,
+                    None => return Ok(None),
+                }
+            }
+            None => return Ok(None),
+        };
+        let quote = self.runtime.call_application(
+            false,
+            oracle.with_abi::<PriceOracleAbi>(),
+            &PriceOracleRequest::GetPrice { market: market.clone() },
+        );
+        Ok(Some((oracle, quote)))
+    }
+
+    /// Copy a new signal for each auto-copy follower of its strategy, within their copy
+    /// settings, and record why it was skipped for the others
+    async fn copy_signal(&mut self, strategy: &AgentStrategy, signal: &Signal) -> Result<(), ViewError> {
+        let today = day_of(self.now());
+        let followers = match self.state.followers_by_strategy.try_load_entry(&strategy.id).await? {
+            Some(followers) => followers.indices().await?,
+            None => Vec::new(),
+        };
+        let mut live = Vec::new();
+        for FollowOrderKey { follower, .. } in followers {
+            let key = FollowerKey { strategy_id: strategy.id, follower };
+            let Some(record) = self.state.followers.get(&key).await? else {
+                continue;
+            };
+            if !record.auto_copy || record.max_exposure_units == 0 {
+                continue;
+            }
+
+            let settings = record.copy_settings;
+            let lost_today = self.state.copy_daily_loss.get(&(key.clone(), today)).await?
+                .unwrap_or(0);
+            let skip = if signal.confidence_bps < settings.min_confidence_bps {
+                Some(CopySkipReason::BelowMinConfidence)
+            } else if settings.daily_loss_limit_units > 0 && lost_today >= settings.daily_loss_limit_units {
+                Some(CopySkipReason::DailyLossLimit)
+            } else {
+                None
+            };
+            if let Some(reason) = skip {
+                self.record_copy_skip(key, signal.id, reason).await?;
+                continue;
+            }
+
+            let units = match settings.max_units_per_signal {
+                0 => record.max_exposure_units,
+                cap => cap.min(record.max_exposure_units),
+            };
+            if !settings.simulation && !self.exposure(follower).await?.allows(units) {
+                self.record_copy_skip(key, signal.id, CopySkipReason::ExposureCap).await?;
+                continue;
+            }
+            let position = self.open_position(signal, follower, units, settings.simulation).await?;
+            if !position.simulated {
+                live.push(position);
+            }
+        }
+
+        if !live.is_empty() {
+            self.place_prediction_bets(strategy, signal, &live).await?;
+        }
+        Ok(())
+    }
+
+    /// Remember that a follower's risk limits skipped a signal, keeping the latest few
+    async fn record_copy_skip(&mut self, key: FollowerKey, signal_id: u64, reason: CopySkipReason) -> Result<(), ViewError> {
+        const MAX_SKIPS: usize = 50;
+
+        let mut skips = self.state.copy_skips.get(&key).await?
+            .unwrap_or_default();
+        if skips.len() >= MAX_SKIPS {
+            skips.remove(0);
+        }
+        skips.push(CopySkip { signal_id, reason, timestamp: self.now() });
+        self.state.copy_skips.insert(&key, skips)?;
+        Ok(())
+    }
+
+    /// Open a position directly on one of this chain's open signals
+    async fn open_direct_position(&mut self, owner: AccountOwner, signal_id: u64, size_units: u64) -> Result<AgentHubResponse, AgentHubError> {
+        if size_units == 0 {
+            return Err(AgentHubError::InvalidPositionSize);
+        }
+        let Some(signal) = self.state.signals.get(&signal_id).await? else {
+            return Err(AgentHubError::SignalNotFound);
+        };
+        if signal.status != SignalStatus::Open {
+            return Err(AgentHubError::SignalNotOpen);
+        }
+        let Some(strategy) = self.state.strategies.get(&signal.strategy_id).await? else {
+            return Err(AgentHubError::StrategyNotFound);
+        };
+        if !self.exposure(owner).await?.allows(size_units) {
+            return Err(AgentHubError::ExposureCapExceeded);
+        }
+
+        let position = self.open_position(&signal, owner, size_units, false).await?;
+        let id = position.id;
+        self.place_prediction_bets(&strategy, &signal, &[position]).await?;
+        Ok(AgentHubResponse::PositionOpened { id })
+    }
+
+    /// Open a position of `size_units` on an open signal
+    async fn open_position(
+        &mut self,
+        signal: &Signal,
+        owner: AccountOwner,
+        size_units: u64,
+        simulated: bool,
+    ) -> Result<Position, ViewError> {
+        let id = *self.state.next_position_id.get() + 1;
+        self.state.next_position_id.set(id);
+        let position = Position {
+            id,
+            signal_id: signal.id,
+            strategy_id: signal.strategy_id,
+            owner,
+            size_units,
+            open_value: signal.entry_value,
+            close_value: None,
+            status: PositionStatus::Open,
+            simulated,
+            pnl_units: None,
+            opened_at: self.now(),
+            closed_at: None,
+        };
+        self.state.positions.insert(&id, position.clone())?;
+
+        self.state.positions_by_signal.load_entry_mut(&signal.id).await?.insert(&ReverseId(id))?;
+        self.state.positions_by_owner.load_entry_mut(&owner).await?.insert(&ReverseId(id))?;
+
+        if simulated {
+            let key = FollowerKey { strategy_id: signal.strategy_id, follower: owner };
+            let mut ledger = self.state.simulated_pnl.get(&key).await?
+                .unwrap_or_default();
+            ledger.copied_signals += 1;
+            self.state.simulated_pnl.insert(&key, ledger)?;
+        } else {
+            self.update_follower_stats(signal.strategy_id, owner, |stats| stats.open(size_units)).await?;
+            self.adjust_exposure(owner, size_units, 0).await?;
+        }
+        Ok(position)
+    }
+
+    /// An account's open exposure and cap
+    async fn exposure(&self, owner: AccountOwner) -> Result<FollowerExposure, ViewError> {
+        Ok(FollowerExposure {
+            owner,
+            open_units: self.state.open_exposure.get(&owner).await?.unwrap_or(0),
+            cap_units: self.state.exposure_caps.get(&owner).await?.unwrap_or(0),
+        })
+    }
+
+    /// Add `opened` and remove `released` units from an account's open exposure
+    async fn adjust_exposure(&mut self, owner: AccountOwner, opened: u64, released: u64) -> Result<(), ViewError> {
+        let open_units = self.state.open_exposure.get(&owner).await?
+            .unwrap_or(0)
+            .saturating_add(opened)
+            .saturating_sub(released);
+        if open_units == 0 {
+            self.state.open_exposure.remove(&owner)?;
+        } else {
+            self.state.open_exposure.insert(&owner, open_units)?;
+        }
+        Ok(())
+    }
+
+    /// Set an account's exposure cap, locally or on the strategies' chain
+    fn set_exposure_cap(&mut self, owner: AccountOwner, strategy_chain_id: String, cap_units: u64) -> Result<AgentHubResponse, AgentHubError> {
+        let chain_id = match strategy_chain_id.parse::<ChainId>() {
+            Ok(chain_id) => chain_id,
+            Err(_) => return Err(AgentHubError::InvalidChainId),
+        };
+
+        if chain_id == self.runtime.chain_id() {
+            self.apply_exposure_cap(owner, cap_units)?;
+        } else {
+            self.runtime.prepare_message(Message::ExposureCapUpdate {
+                follower: owner,
+                cap_units,
+            }.into())
+            .with_authentication()
+            .send_to(chain_id);
+        }
+        Ok(AgentHubResponse::ExposureCapSet { chain_id, cap_units })
+    }
+
+    /// Store an account's exposure cap on this chain
+    fn apply_exposure_cap(&mut self, owner: AccountOwner, cap_units: u64) -> Result<(), ViewError> {
+        if cap_units == 0 {
+            self.state.exposure_caps.remove(&owner)?;
+        } else {
+            self.state.exposure_caps.insert(&owner, cap_units)?;
+        }
+        Ok(())
+    }
+
+    /// Open positions on a signal
+    async fn open_positions(&self, signal_id: u64) -> Result<Vec<Position>, ViewError> {
+        let ids = match self.state.positions_by_signal.try_load_entry(&signal_id).await? {
+            Some(ids) => ids.indices().await?,
+            None => Vec::new(),
+        };
+        let mut positions = Vec::new();
+        for ReverseId(id) in ids {
+            if let Some(position) = self.state.positions.get(&id).await? {
+                if position.status == PositionStatus::Open {
+                    positions.push(position);
+                }
+            }
+        }
+        Ok(positions)
+    }
+
+    /// Settle a resolved signal's open positions: count losses against their owners' daily
+    /// loss limits and post virtual positions to the simulated P&L ledger. Returns the
+    /// settled positions.
+    async fn settle_positions(&mut self, signal: &Signal, pnl_bps: i64) -> Result<Vec<Position>, ViewError> {
+        let now = self.now();
+        let today = day_of(now);
+        let mut settled = Vec::new();
+        for mut position in self.open_positions(signal.id).await? {
+            position.settle(signal.resolved_value, pnl_bps, now);
+            self.state.positions.insert(&position.id, position.clone())?;
+
+            let key = FollowerKey { strategy_id: signal.strategy_id, follower: position.owner };
+            if position.simulated {
+                let mut ledger = self.state.simulated_pnl.get(&key).await?
+                    .unwrap_or_default();
+                ledger.record(position.size_units, pnl_bps);
+                self.state.simulated_pnl.insert(&key, ledger)?;
+            } else {
+                self.update_follower_stats(signal.strategy_id, position.owner, |stats| {
+                    stats.settle(position.size_units, pnl_bps)
+                }).await?;
+                self.adjust_exposure(position.owner, 0, position.size_units).await?;
+            }
+            if pnl_bps < 0 {
+                let lost = (position.size_units as u128 * pnl_bps.unsigned_abs() as u128).div_ceil(10000) as u64;
+                let lost_today = self.state.copy_daily_loss.get(&(key.clone(), today)).await?
+                    .unwrap_or(0);
+                self.state.copy_daily_loss.insert(&(key, today), lost_today.saturating_add(lost))?;
+            }
+            settled.push(position);
+        }
+        Ok(settled)
+    }
+
+    /// Close a cancelled signal's open positions without a result
+    async fn close_positions(&mut self, signal: &Signal) -> Result<(), ViewError> {
+        let now = self.now();
+        for mut position in self.open_positions(signal.id).await? {
+            position.close(now);
+            self.state.positions.insert(&position.id, position.clone())?;
+            if !position.simulated {
+                self.update_follower_stats(signal.strategy_id, position.owner, |stats| {
+                    stats.release(position.size_units)
+                }).await?;
+                self.adjust_exposure(position.owner, 0, position.size_units).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply `update` to a follower's copy-trading results on a strategy
+    async fn update_follower_stats(
+        &mut self,
+        strategy_id: u64,
+        follower: AccountOwner,
+        update: impl FnOnce(&mut FollowerStats),
+    ) -> Result<(), ViewError> {
+        let key = FollowerKey { strategy_id, follower };
+        let stats = match self.state.follower_stats.get(&key).await? {
+            Some(stats) => stats,
+            None => {
+                let mut strategy_ids = self.state.copy_stats_by_follower.get(&follower).await?
+                    .unwrap_or_default();
+                strategy_ids.push(strategy_id);
+                self.state.copy_stats_by_follower.insert(&follower, strategy_ids)?;
+                FollowerStats::new(strategy_id, follower)
+            }
+        };
+        let mut updated = stats.clone();
+        update(&mut updated);
+        self.update_copier_board(follower, &stats, &updated).await?;
+        self.state.follower_stats.insert(&key, updated)?;
+        Ok(())
+    }
+
+    /// Carry a change in a follower's per-strategy results into their totals and their
+    /// position on the copier leaderboard
+    async fn update_copier_board(&mut self, follower: AccountOwner, before: &FollowerStats, after: &FollowerStats) -> Result<(), ViewError> {
+        let mut totals = self.state.copier_stats.get(&follower).await?
+            .unwrap_or_else(|| CopierStats::new(follower));
+        let old_key = CopierKey {
+            realized_pnl_units: totals.realized_pnl_units,
+            hit_rate_bps: totals.hit_rate_bps,
+            follower,
+        };
+        let was_ranked = totals.wins + totals.losses > 0;
+        totals.apply(before, after);
+
+        if was_ranked {
+            self.state.copier_board.remove(&old_key)?;
+        }
+        if totals.wins + totals.losses > 0 {
+            let key = CopierKey {
+                realized_pnl_units: totals.realized_pnl_units,
+                hit_rate_bps: totals.hit_rate_bps,
+                follower,
+            };
+            self.state.copier_board.insert(&key)?;
+        }
+        self.state.copier_stats.insert(&follower, totals)?;
+        Ok(())
+    }
+
+    /// Update the risk limits of a follow, locally or on the strategy's chain
+    async fn update_copy_settings(
+        &mut self,
+        follower: AccountOwner,
+        strategy_id: u64,
+        strategy_chain_id: String,
+        settings: CopySettings,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        let target_chain = match strategy_chain_id.parse::<ChainId>() {
+            Ok(chain_id) => chain_id,
+            Err(_) => return Err(AgentHubError::InvalidChainId),
+        };
+        if settings.min_confidence_bps > 10000 {
+            return Err(AgentHubError::InvalidConfidence);
+        }
+
+        if target_chain == self.runtime.chain_id() {
+            self.apply_copy_settings(strategy_id, follower, settings).await?;
+        } else {
+            self.runtime.prepare_message(Message::CopySettingsUpdate {
+                strategy_id,
+                follower,
+                settings,
+            }.into())
+            .with_authentication()
+            .send_to(target_chain);
+        }
+
+        Ok(AgentHubResponse::CopySettingsUpdated { strategy: GlobalId::new(target_chain, strategy_id) })
+    }
+
+    /// Store a follower's copy settings on the strategy's chain
+    async fn apply_copy_settings(
+        &mut self,
+        strategy_id: u64,
+        follower: AccountOwner,
+        settings: CopySettings,
+    ) -> Result<(), AgentHubError> {
+        let key = FollowerKey { strategy_id, follower };
+        let Some(mut record) = self.state.followers.get(&key).await? else {
+            return Err(AgentHubError::NotFollowing);
+        };
+        record.copy_settings = settings;
+        self.state.followers.insert(&key, record)?;
+        self.record_change(ChangeEntity::Follower, format!("{strategy_id}:{follower}"), ChangeKind::Updated);
+        Ok(())
+    }
+
+    /// Place a bet on the prediction app for each position on a `PredictionApp` strategy's signal
+    async fn place_prediction_bets(&mut self, strategy: &AgentStrategy, signal: &Signal, positions: &[Position]) -> Result<(), ViewError> {
+        if strategy.market_kind != MarketKind::PredictionApp {
+            return Ok(());
+        }
+        let Some(app) = self.runtime.application_parameters().prediction_app else {
+            return Ok(());
+        };
+
+        let mut bettors = Vec::new();
+        for position in positions {
+            self.runtime.call_application(
+                false,
+                app.with_abi::<PredictionAppAbi>(),
+                &PredictionAppRequest::PlaceBet {
+                    bettor: position.owner,
+                    market: strategy.base_market.clone(),
+                    signal: signal.global_id,
+                    direction: signal.direction,
+                    stake_units: position.size_units,
+                    expires_at: signal.expires_at,
+                },
+            );
+            bettors.push(position.owner);
+        }
+
+        if !bettors.is_empty() {
+            self.state.prediction_bets.insert(&signal.id, bettors)?;
+        }
+        Ok(())
+    }
+
+    /// Settle (or refund, if cancelled) the prediction-app bets placed on a signal
+    async fn close_prediction_bets(&mut self, signal: &Signal) -> Result<(), ViewError> {
+        let Some(app) = self.runtime.application_parameters().prediction_app else {
+            return Ok(());
+        };
+        if !self.state.prediction_bets.contains_key(&signal.id).await? {
+            return Ok(());
+        }
+        let request = match signal.result {
+            Some(result) if signal.status == SignalStatus::Resolved => {
+                PredictionAppRequest::SettleBets { signal: signal.global_id, result }
+            }
+            _ => PredictionAppRequest::CancelBets { signal: signal.global_id },
+        };
+        self.runtime.call_application(false, app.with_abi::<PredictionAppAbi>(), &request);
+        self.state.prediction_bets.remove(&signal.id)?;
+        Ok(())
+    }
+
+    /// Score an open signal at `resolved_value` and propagate the outcome
+    async fn settle_signal(
+        &mut self,
+        mut signal: Signal,
+        resolved_value: Price,
+        provenance: ResolutionProvenance,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        let signal_id = signal.id;
+
+        // Calculate result and PnL. An early close at the strategist's own price proves
+        // nothing about the call, so it is scored flat.
+        let (result, pnl_bps) = if signal.closed_early && provenance.source == ResolutionSource::Strategist {
+            (SignalResult::Push, 0)
+        } else {
+            let rules = self.resolution_rules(signal.strategy_id).await?;
+            Self::calculate_signal_result(&signal, resolved_value, &rules)
+        };
+
+        // Update signal
+        signal.status = SignalStatus::Resolved;
+        signal.result = Some(result);
+        signal.pnl_bps = Some(pnl_bps);
+        signal.resolved_value = Some(resolved_value);
+        signal.resolution = Some(provenance);
+
+        let strategy_id = signal.strategy_id;
+        self.state.signals.insert(&signal_id, signal.clone())?;
+        self.state.pending_resolution.remove(&ResolutionKey { expires_at: signal.expires_at, signal_id })?;
+        self.record_change(ChangeEntity::Signal, signal_id, ChangeKind::Updated);
+        self.append_merkle_leaf(&signal).await?;
+        self.decrement_open_signals(strategy_id).await?;
+        self.close_prediction_bets(&signal).await?;
+
+        let counters = self.state.global_counters.get_mut();
+        counters.resolved_signals += 1;
+        if result == SignalResult::Win {
+            counters.winning_signals += 1;
+        }
+
+        if result != SignalResult::Push {
+            self.record_calibration(&signal).await?;
+        }
+        self.record_daily_pnl(&signal).await?;
+
+        // Update strategy stats
+        self.update_strategy_stats(strategy_id).await?;
+        self.refresh_version_stats(strategy_id, signal.strategy_version).await?;
+
+        if let Some(strategy) = self.state.strategies.get(&strategy_id).await? {
+            self.index_signal_status(
+                &strategy.base_market,
+                signal_id,
+                Some(SignalStatus::Open),
+                SignalStatus::Resolved,
+            ).await?;
+            let mut market_stats = self.state.market_stats.get(&strategy.base_market).await?
+                .unwrap_or_else(|| MarketStats::new(strategy.base_market.clone()));
+            market_stats.record(result, pnl_bps);
+            self.state.market_stats.insert(&strategy.base_market, market_stats)?;
+
+            // Full-horizon winning calls build the strategist's governance reputation
+            if result == SignalResult::Win && !signal.closed_early {
+                let reputation = self.state.reputation.get(&strategy.owner).await?
+                    .unwrap_or(0);
+                self.state.reputation.insert(&strategy.owner, reputation + 1)?;
+            }
+
+            // Early closes are timed by the strategist, so they earn no prizes, fees or refunds
+            let positions = self.settle_positions(&signal, pnl_bps).await?;
+            if !signal.closed_early {
+                self.score_tournaments(&strategy, &signal).await?;
+                self.accrue_performance_fees(&strategy, &positions, pnl_bps).await?;
+                self.count_toward_deposit_refund(strategy.owner).await?;
+            }
+
+            // Push the resolved signal to subscriber and follower chains
+            self.broadcast_signal(&strategy, &signal).await?;
+        }
+
+        // Emit event for cross-chain subscribers
+        let stream = StreamName::from(b"signals");
+        self.runtime.emit(stream, &AgentHubEvent::SignalResolved {
+            strategy_id,
+            signal_id,
+            result,
+            pnl_bps,
+        });
+
+        Ok(AgentHubResponse::SignalResolved {
+            id: signal_id,
+            result,
+            pnl_bps,
+        })
+    }
+
+    /// Count a resolved signal toward refunding the strategist's registration deposit, paying
+    /// it back once the configured number is reached
+    async fn count_toward_deposit_refund(&mut self, owner: AccountOwner) -> Result<(), ViewError> {
+        let Some(mut deposit) = self.state.registration_deposits.get(&owner).await? else {
+            return Ok(());
+        };
+        if deposit.refunded {
+            return Ok(());
+        }
+
+        deposit.resolved_signals += 1;
+        let required = self.runtime.application_parameters().deposit_refund_signals;
+        if deposit.resolved_signals >= required {
+            let chain_id = self.runtime.chain_id();
+            self.pay_tokens_from_app_account(Some(deposit.token), Account { chain_id, owner }, deposit.amount);
+            deposit.refunded = true;
+        }
+        self.state.registration_deposits.insert(&owner, deposit)?;
+        Ok(())
+    }
+
+    /// Give a legacy open signal the entry value it was published without
+    async fn backfill_entry_value(&mut self, signal_id: u64, entry_value: Price) -> Result<AgentHubResponse, AgentHubError> {
+        let mut signal = match self.state.signals.get(&signal_id).await? {
+            Some(s) => s,
+            _ => return Err(AgentHubError::SignalNotFound),
+        };
+        if signal.status != SignalStatus::Open {
+            return Err(AgentHubError::SignalNotOpen);
+        }
+        if entry_value.is_zero() || signal.entry_value.is_some_and(|value| !value.is_zero()) {
+            return Err(AgentHubError::InvalidEntryValue);
+        }
+
+        signal.entry_value = Some(entry_value);
+        self.state.signals.insert(&signal_id, signal)?;
+        self.record_change(ChangeEntity::Signal, signal_id, ChangeKind::Updated);
+
+        Ok(AgentHubResponse::Ok)
+    }
+
+    /// Whether a value reaches the signal's take-profit or stop-loss level
+    fn exit_level_reached(signal: &Signal, value: Price) -> bool {
+        let bullish = signal.direction.is_bullish();
+        let target_hit = signal.target_value.is_some_and(|target| {
+            if bullish { value >= target } else { value <= target }
+        });
+        let stop_hit = signal.stop_value.is_some_and(|stop| {
+            if bullish { value <= stop } else { value >= stop }
+        });
+        target_hit || stop_hit
+    }
+
+    /// Calculate signal result based on direction and price movement, under the
+    /// resolution rules of the signal's market
+    fn calculate_signal_result(
+        signal: &Signal,
+        resolved_value: Price,
+        rules: &ResolutionRules,
+    ) -> (SignalResult, i64) {
+        let entry = signal.entry_value.unwrap_or_default();
+        
+        if entry.is_zero() || resolved_value.is_zero() {
+            return (SignalResult::Push, 0);
+        }
+
+        // Calculate PnL in basis points
+        let pnl_bps = entry.change_bps(resolved_value);
+
+        // Determine result: range signals win inside their range, others by direction
+        let result = if let Some(range) = signal.range {
+            if range.contains(resolved_value) {
+                SignalResult::Win
+            } else {
+                SignalResult::Lose
+            }
+        } else {
+            let (favourable, adverse) = if signal.direction.is_bullish() {
+                (resolved_value > entry, resolved_value < entry)
+            } else {
+                (resolved_value < entry, resolved_value > entry)
+            };
+            let move_bps = pnl_bps.unsigned_abs();
+            if !favourable && !adverse {
+                rules.tie.result()
+            } else if move_bps < rules.push_band_bps as u64 {
+                SignalResult::Push
+            } else if favourable && move_bps >= rules.min_win_move_bps as u64 {
+                SignalResult::Win
+            } else {
+                SignalResult::Lose
+            }
+        };
+
+        // Adjust PnL sign based on direction (for DOWN, negative price move = positive PnL)
+        let adjusted_pnl = match signal.direction {
+            Direction::Down | Direction::Under | Direction::No => pnl_bps.saturating_neg(),
+            _ => pnl_bps,
+        };
+
+        (result, adjusted_pnl)
+    }
+
+    /// Resolution rules for a strategy's signals: its market's, else its market kind's,
+    /// else the defaults (no push band, no minimum move, ties push), with the push band
+    /// widened to the strategy's own
+    async fn resolution_rules(&self, strategy_id: u64) -> Result<ResolutionRules, ViewError> {
+        let Some(strategy) = self.state.strategies.get(&strategy_id).await? else {
+            return Ok(ResolutionRules::default());
+        };
+        let mut rules = match self.market(&strategy.base_market).await?.and_then(|market| market.rules) {
+            Some(rules) => rules,
+            None => self.state.kind_resolution_rules.get(&strategy.market_kind).await?
+                .unwrap_or_default(),
+        };
+        rules.push_band_bps = rules.push_band_bps.max(strategy.push_band_bps);
+        Ok(rules)
+    }
+
+    /// Cancel an open signal
+    async fn cancel_signal(&mut self, owner: AccountOwner, signal_id: u64) -> Result<AgentHubResponse, AgentHubError> {
+        // Get signal
+        let signal = match self.state.signals.get(&signal_id).await? {
+            Some(s) => s,
+            _ => return Err(AgentHubError::SignalNotFound),
+        };
+
+        // Check authorization
+        let strategy = match self.state.strategies.get(&signal.strategy_id).await? {
+            Some(s) => s,
+            _ => return Err(AgentHubError::StrategyNotFound),
+        };
+
+        if strategy.owner != owner {
+            return Err(AgentHubError::NotAuthorized);
+        }
+
+        // Check signal is open
+        if signal.status != SignalStatus::Open {
+            return Err(AgentHubError::SignalNotOpen);
+        }
+
+        self.mark_cancelled(&strategy, signal).await?;
+
+        Ok(AgentHubResponse::SignalCancelled { id: signal_id })
+    }
+
+    /// Move an open signal to `Cancelled`, update the counters and indexes, and push it to
+    /// subscriber and follower chains
+    async fn mark_cancelled(&mut self, strategy: &AgentStrategy, mut signal: Signal) -> Result<(), ViewError> {
+        let signal_id = signal.id;
+        signal.status = SignalStatus::Cancelled;
+        self.state.signals.insert(&signal_id, signal.clone())?;
+        self.state.pending_resolution.remove(&ResolutionKey { expires_at: signal.expires_at, signal_id })?;
+        self.record_change(ChangeEntity::Signal, signal_id, ChangeKind::Updated);
+        self.decrement_open_signals(signal.strategy_id).await?;
+        self.close_prediction_bets(&signal).await?;
+        self.close_positions(&signal).await?;
+        self.state.global_counters.get_mut().cancelled_signals += 1;
+        self.index_signal_status(
+            &strategy.base_market,
+            signal_id,
+            Some(SignalStatus::Open),
+            SignalStatus::Cancelled,
+        ).await?;
+
+        self.broadcast_signal(strategy, &signal).await?;
+        Ok(())
+    }
+
+    /// Whether an account on this chain holds an active subscription to `strategist`
+    async fn has_active_subscription_to(&mut self, strategist: AccountOwner) -> Result<bool, ViewError> {
+        let now = self.now();
+        Ok(!self.active_local_subscribers(strategist, now).await?.is_empty())
+    }
+
+    /// Accounts on this chain whose subscription to a strategist grants access at `now`
+    async fn active_local_subscribers(
+        &mut self,
+        strategist: AccountOwner,
+        now: Timestamp,
+    ) -> Result<Vec<AccountOwner>, ViewError> {
+        let chain_id = self.runtime.chain_id();
+        let subscribers = match self.state.local_subscribers.try_load_entry(&strategist).await? {
+            Some(subscribers) => subscribers.indices().await?,
+            None => Vec::new(),
+        };
+        let mut active = Vec::new();
+        for subscriber in subscribers {
+            let id = subscription_id(&subscriber, &strategist);
+            if let Some(sub) = self.state.subscriptions.get(&id).await? {
+                if sub.grants_access(now) && sub.subscriber_chain_id == chain_id {
+                    active.push(subscriber);
+                }
+            }
+        }
+        Ok(active)
+    }
+
+    /// Emit a disclosed signal on its strategist's stream for subscriber and follower chains.
+    /// Streams can be read by any chain, so an undisclosed signal is instead sent directly to
+    /// the chains of the strategist's active subscribers.
+    async fn broadcast_signal(&mut self, strategy: &AgentStrategy, signal: &Signal) -> Result<(), ViewError> {
+        let now = self.now();
+        if signal.is_disclosed(now) {
+            if signal.status == SignalStatus::Open {
+                let stream = StreamName::from(b"signals");
+                self.runtime.emit(stream, &AgentHubEvent::SignalPublished {
+                    strategy_id: signal.strategy_id,
+                    signal_id: signal.id,
+                    direction: signal.direction,
+                    confidence_bps: signal.confidence_bps,
+                });
+            }
+            self.runtime.emit(strategist_stream(&strategy.owner), &AgentHubEvent::SignalBroadcast {
+                signal: Box::new(signal.clone()),
+                strategy_name: strategy.name.clone(),
+                strategist: strategy.owner,
+                base_market: strategy.base_market.clone(),
+            });
+            return Ok(());
+        }
+
+        let chain_id = self.runtime.chain_id();
+        let subscription_ids = match self.state.subscribers_by_strategist.try_load_entry(&strategy.owner).await? {
+            Some(ids) => ids.indices().await?,
+            None => Vec::new(),
+        };
+        let mut chains = Vec::new();
+        for subscription_id in subscription_ids {
+            if let Some(sub) = self.state.subscriptions.get(&subscription_id).await? {
+                if sub.grants_access(now)
+                    && sub.subscriber_chain_id != chain_id
+                    && !chains.contains(&sub.subscriber_chain_id)
+                {
+                    chains.push(sub.subscriber_chain_id);
+                }
+            }
+        }
+        for chain in chains {
+            self.runtime.prepare_message(Message::RestrictedSignal {
+                signal: Box::new(signal.clone()),
+                strategist: strategy.owner,
+                base_market: strategy.base_market.clone(),
+            }.into())
+            .with_authentication()
+            .send_to(chain);
+        }
+        Ok(())
+    }
+
+    /// Broadcast delayed-public signals whose delay has passed while they are still open
+    async fn disclose_delayed_signals(&mut self) -> Result<(), ViewError> {
+        const MAX_DISCLOSURES_PER_BLOCK: usize = 20;
+
+        let now = self.now();
+        let mut due = Vec::new();
+        self.state.disclosure_schedule.for_each_index_while(|key| {
+            if key.expires_at > now || due.len() >= MAX_DISCLOSURES_PER_BLOCK {
+                return Ok(false);
+            }
+            due.push(key);
+            Ok(true)
+        }).await?;
+
+        for key in due {
+            self.state.disclosure_schedule.remove(&key)?;
+            let signal = match self.state.signals.get(&key.signal_id).await? {
+                Some(signal) if signal.status == SignalStatus::Open => signal,
+                _ => continue,
+            };
+            let Some(strategy) = self.state.strategies.get(&signal.strategy_id).await? else {
+                continue;
+            };
+            self.broadcast_signal(&strategy, &signal).await?;
+        }
+        Ok(())
+    }
+
+    /// Store a signal received from another chain, keyed globally so it cannot collide with
+    /// signals published on this chain, and notify the accounts here that track it
+    async fn receive_signal(&mut self, signal: Signal, strategist: AccountOwner, base_market: &str) -> Result<(), ViewError> {
+        let global_id = signal.global_id;
+        let previous = self.state.received_signals.get(&global_id).await?;
+        if previous.as_ref().is_none_or(|previous| previous.status != signal.status) {
+            self.notify_followers(&signal, strategist, base_market).await?;
+        }
+        if previous.is_none() {
+            self.state.received_signals_by_strategist.load_entry_mut(&strategist).await?.insert(&global_id)?;
+            self.state.received_signal_strategists.insert(&global_id, strategist)?;
+        }
+        self.state.received_signals.insert(&global_id, signal)?;
+        Ok(())
+    }
+
+    /// Emit a notification for each account on this chain that follows the signal's strategy
+    /// or subscribes to its strategist, unless their preferences filter it out
+    async fn notify_followers(
+        &mut self,
+        signal: &Signal,
+        strategist: AccountOwner,
+        base_market: &str,
+    ) -> Result<(), ViewError> {
+        let now = self.now();
+        let strategy = GlobalId::new(signal.global_id.chain_id, signal.strategy_id);
+
+        let mut recipients = match self.state.remote_follows_by_strategy.try_load_entry(&strategy).await? {
+            Some(followers) => followers.indices().await?,
+            None => Vec::new(),
+        };
+        for subscriber in self.active_local_subscribers(strategist, now).await? {
+            if !recipients.contains(&subscriber) {
+                recipients.push(subscriber);
+            }
+        }
+
+        for recipient in recipients {
+            let preferences = self.state.notification_preferences.get(&recipient).await?
+                .unwrap_or_default();
+            if preferences.wants(signal, base_market) {
+                self.runtime.emit(StreamName::from(b"notifications"), &AgentHubEvent::SignalNotification {
+                    recipient,
+                    signal: signal.global_id,
+                    status: signal.status,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Subscribe this chain to a strategist's signal stream (once per strategist)
+    async fn listen_to_strategist(
+        &mut self,
+        chain_id: ChainId,
+        strategist: AccountOwner,
+    ) -> Result<(), ViewError> {
+        let key = (chain_id, strategist);
+        let listeners = self.state.stream_listeners.get(&key).await?.unwrap_or(0);
+        if listeners == 0 {
+            let application_id = self.runtime.application_id().forget_abi();
+            self.runtime.subscribe_to_events(chain_id, application_id, strategist_stream(&strategist));
+        }
+        self.state.stream_listeners.insert(&key, listeners + 1)?;
+        Ok(())
+    }
+
+    /// Drop one listener of a strategist's signal stream, unsubscribing when none remain
+    async fn stop_listening_to_strategist(
+        &mut self,
+        chain_id: ChainId,
+        strategist: AccountOwner,
+    ) -> Result<(), ViewError> {
+        let key = (chain_id, strategist);
+        let listeners = self.state.stream_listeners.get(&key).await?.unwrap_or(0);
+        if listeners <= 1 {
+            let application_id = self.runtime.application_id().forget_abi();
+            self.runtime.unsubscribe_from_events(chain_id, application_id, strategist_stream(&strategist));
+            self.state.stream_listeners.remove(&key)?;
+        } else {
+            self.state.stream_listeners.insert(&key, listeners - 1)?;
+        }
+        Ok(())
+    }
+
+    /// Record a follower living on another chain and send them the confirmation
+    async fn confirm_remote_follow(
+        &mut self,
+        strategy_id: u64,
+        follower: AccountOwner,
+        follower_chain_id: ChainId,
+        auto_copy: bool,
+        max_exposure_units: u64,
+    ) -> Result<(), ViewError> {
+        // Remember followers living on other chains
+        let mut remote = self.state.remote_followers.get(&strategy_id).await?.unwrap_or_default();
+        remote.push(follower);
+        self.state.remote_followers.insert(&strategy_id, remote)?;
+
+        let Some(strategy) = self.state.strategies.get(&strategy_id).await? else {
+            return Ok(());
+        };
+        let chain_id = self.runtime.chain_id();
+
+        // Send confirmation back to follower's chain
+        self.runtime.prepare_message(Message::FollowConfirmed {
+            strategy: GlobalId::new(chain_id, strategy_id),
+            strategy_name: strategy.name,
+            strategist: strategy.owner,
+            follower,
+            auto_copy,
+            max_exposure_units,
+        }.into()).send_to(follower_chain_id);
+        Ok(())
+    }
+
+    /// Follow a strategy
+    async fn follow_strategy(
+        &mut self,
+        follower_owner: AccountOwner,
+        follower_chain_id: ChainId,
+        strategy_id: u64,
+        auto_copy: bool,
+        max_exposure_units: u64,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        // Check strategy exists
+        let strategy = match self.state.strategies.get(&strategy_id).await? {
+            Some(s) => s,
+            _ => return Err(AgentHubError::StrategyNotFound),
+        };
+
+        let key = FollowerKey { strategy_id, follower: follower_owner };
+
+        // Check not already following
+        if self.state.followers.contains_key(&key).await? {
+            return Err(AgentHubError::AlreadyFollowing);
+        }
+
+        let blocked = self.state.blocked_followers.get(&strategy_id).await?
+            .unwrap_or_default();
+        if blocked.contains(&follower_owner) {
+            return Err(AgentHubError::BlockedByStrategist);
+        }
+
+        // Private strategies can only be followed by invitation
+        if !strategy.is_public && follower_owner != strategy.owner {
+            let invitees = self.state.strategy_invites.get(&strategy_id).await?
+                .unwrap_or_default();
+            if !invitees.contains(&follower_owner) {
+                return Err(AgentHubError::NotInvited);
+            }
+        }
+
+        let follower = Follower {
+            strategy_id,
+            follower: follower_owner,
+            chain_id: follower_chain_id,
+            auto_copy,
+            max_exposure_units,
+            created_at: self.now(),
+            copy_settings: CopySettings::default(),
+        };
+
+        // A full strategy queues the request until a slot frees up
+        if let Some(max_followers) = strategy.max_followers {
+            let count = self.state.follower_count.get(&strategy_id).await?
+                .unwrap_or(0);
+            if count >= max_followers {
+                let mut waitlist = self.state.follower_waitlist.get(&strategy_id).await?
+                    .unwrap_or_default();
+                if !waitlist.iter().any(|entry| entry.follower == follower_owner) {
+                    waitlist.push(follower);
+                    self.state.follower_waitlist.insert(&strategy_id, waitlist)?;
+                }
+                return Err(AgentHubError::StrategyFull);
+            }
+        }
+
+        let order = FollowOrderKey { followed_at: follower.created_at, follower: follower_owner };
+        self.state.followers.insert(&key, follower)?;
+        self.record_change(ChangeEntity::Follower, format!("{strategy_id}:{follower_owner}"), ChangeKind::Created);
+
+        self.state.follows_by_follower.load_entry_mut(&follower_owner).await?.insert(&strategy_id)?;
+
+        self.state.followers_by_strategy.load_entry_mut(&strategy_id).await?.insert(&order)?;
+
+        // Increment follower count
+        let count = self.state.follower_count.get(&strategy_id).await?
+            .unwrap_or(0);
+        self.state.follower_count.insert(&strategy_id, count + 1)?;
+        self.record_follower_snapshot(strategy_id, count, count + 1).await?;
+        self.state.global_counters.get_mut().active_follows += 1;
+
+        // Returning followers count once towards the lifetime total
+        let mut lifetime = self.state.lifetime_follower_count.get(&strategy_id).await?
+            .unwrap_or(0);
+        if !self.state.past_followers.contains(&key).await? {
+            self.state.past_followers.insert(&key)?;
+            lifetime += 1;
+            self.state.lifetime_follower_count.insert(&strategy_id, lifetime)?;
+        }
+
+        // Update stats
+        let mut stats = self.state.strategy_stats.get(&strategy_id).await?
+            .unwrap_or_default();
+        stats.followers = count + 1;
+        stats.total_followers_ever = lifetime;
+        self.state.strategy_stats.insert(&strategy_id, stats)?;
+        self.record_change(ChangeEntity::StrategyStats, strategy_id, ChangeKind::Updated);
+
+        // Emit event for follow
+        let stream = StreamName::from(b"follows");
+        self.runtime.emit(stream, &AgentHubEvent::StrategyFollowed {
+            strategy_id,
+            follower: follower_owner,
+        });
+
+        Ok(AgentHubResponse::Followed { strategy_id })
+    }
+
+    /// Unfollow a strategy on the follower's request, counting the reason in its churn stats
+    async fn unfollow_with_reason(
+        &mut self,
+        follower_owner: AccountOwner,
+        strategy_id: u64,
+        reason: Option<UnfollowReason>,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        let key = FollowerKey { strategy_id, follower: follower_owner };
+        let was_following = self.state.followers.contains_key(&key).await?;
+        let response = self.unfollow_strategy(follower_owner, strategy_id).await;
+        if was_following && matches!(response, Ok(AgentHubResponse::Unfollowed { .. })) {
+            let mut churn = self.state.churn_stats.get(&strategy_id).await?
+                .unwrap_or(ChurnStats { strategy_id, ..Default::default() });
+            churn.record(reason);
+            self.state.churn_stats.insert(&strategy_id, churn)?;
+        }
+        response
+    }
+
+    /// Unfollow a strategy
+    async fn unfollow_strategy(&mut self, follower_owner: AccountOwner, strategy_id: u64) -> Result<AgentHubResponse, AgentHubError> {
+        let key = FollowerKey { strategy_id, follower: follower_owner };
+
+        // Check following; unfollowing while waitlisted just leaves the waitlist
+        let Some(record) = self.state.followers.get(&key).await? else {
+            let mut waitlist = self.state.follower_waitlist.get(&strategy_id).await?
+                .unwrap_or_default();
+            let waiting = waitlist.len();
+            waitlist.retain(|entry| entry.follower != follower_owner);
+            if waitlist.len() == waiting {
+                return Err(AgentHubError::NotFollowing);
+            }
+            self.state.follower_waitlist.insert(&strategy_id, waitlist)?;
+            return Ok(AgentHubResponse::Unfollowed { strategy_id });
+        };
+
+        self.state.followers.remove(&key)?;
+        self.record_change(ChangeEntity::Follower, format!("{strategy_id}:{follower_owner}"), ChangeKind::Removed);
+
+        self.state.follows_by_follower.load_entry_mut(&follower_owner).await?.remove(&strategy_id)?;
+
+        let order = FollowOrderKey { followed_at: record.created_at, follower: follower_owner };
+        self.state.followers_by_strategy.load_entry_mut(&strategy_id).await?.remove(&order)?;
+
+        // Decrement follower count
+        let count = self.state.follower_count.get(&strategy_id).await?
+            .unwrap_or(1);
+        let new_count = count.saturating_sub(1);
+        self.state.follower_count.insert(&strategy_id, new_count)?;
+        self.record_follower_snapshot(strategy_id, count, new_count).await?;
+        let counters = self.state.global_counters.get_mut();
+        counters.active_follows = counters.active_follows.saturating_sub(1);
+
+        // Update stats
+        let mut stats = self.state.strategy_stats.get(&strategy_id).await?
+            .unwrap_or_default();
+        stats.followers = new_count;
+        self.state.strategy_stats.insert(&strategy_id, stats)?;
+        self.record_change(ChangeEntity::StrategyStats, strategy_id, ChangeKind::Updated);
+
+        // Emit event for unfollow
+        let stream = StreamName::from(b"follows");
+        self.runtime.emit(stream, &AgentHubEvent::StrategyUnfollowed {
+            strategy_id,
+            follower: follower_owner,
+        });
+
+        self.admit_waitlisted_followers(strategy_id).await?;
+
+        Ok(AgentHubResponse::Unfollowed { strategy_id })
+    }
+
+    /// Fill free follower slots of a strategy from its waitlist, oldest request first
+    async fn admit_waitlisted_followers(&mut self, strategy_id: u64) -> Result<(), AgentHubError> {
+        let mut waitlist = self.state.follower_waitlist.get(&strategy_id).await?
+            .unwrap_or_default();
+        if waitlist.is_empty() {
+            return Ok(());
+        }
+        let Some(strategy) = self.state.strategies.get(&strategy_id).await? else {
+            return Ok(());
+        };
+        let chain_id = self.runtime.chain_id();
+        let mut count = self.state.follower_count.get(&strategy_id).await?
+            .unwrap_or(0);
+
+        while !waitlist.is_empty() && strategy.max_followers.is_none_or(|max| count < max) {
+            let entry = waitlist.remove(0);
+            match self
+                .follow_strategy(entry.follower, entry.chain_id, strategy_id, entry.auto_copy, entry.max_exposure_units)
+                .await
+            {
+                Ok(AgentHubResponse::Followed { .. }) => {}
+                Err(error @ AgentHubError::Internal(_)) => return Err(error),
+                _ => continue,
+            }
+            count += 1;
+            if entry.chain_id != chain_id {
+                self.confirm_remote_follow(
+                    strategy_id,
+                    entry.follower,
+                    entry.chain_id,
+                    entry.auto_copy,
+                    entry.max_exposure_units,
+                )
+                .await?;
+            }
+        }
+
+        self.state.follower_waitlist.insert(&strategy_id, waitlist)?;
+        Ok(())
+    }
+
+    /// Update strategy statistics based on all signals
+    async fn update_strategy_stats(&mut self, strategy_id: u64) -> Result<(), ViewError> {
+        let version = match self.state.strategies.get(&strategy_id).await? {
+            Some(strategy) => strategy.version,
+            None => 0,
+        };
+        let (stats, best_win_streak) = self.compute_stats(strategy_id, version).await?;
+        let StrategyStats { total_signals, win_rate_bps, .. } = stats;
+
+        let mut earned = Vec::new();
+        if total_signals >= 100 {
+            earned.push(BadgeKind::Centurion);
+        }
+        if total_signals >= 50 && win_rate_bps >= 7000 {
+            earned.push(BadgeKind::Sharpshooter);
+        }
+        if best_win_streak >= 10 {
+            earned.push(BadgeKind::HotStreak);
+        }
+
+        self.state.strategy_stats.insert(&strategy_id, stats.clone())?;
+        self.record_change(ChangeEntity::StrategyStats, strategy_id, ChangeKind::Updated);
+        self.update_leaderboard(strategy_id, &stats).await?;
+        self.sync_stats_to_hub(strategy_id, stats).await?;
+
+        if !earned.is_empty() {
+            self.award_badges(strategy_id, earned).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Stats of a strategy's resolved signals published under `version`, with the best
+    /// win streak among them
+    async fn compute_stats(&self, strategy_id: u64, version: u32) -> Result<(StrategyStats, u64), ViewError> {
+        let count = self.state.strategy_signal_count(strategy_id).await?;
+        let signal_ids = self.state.strategy_signal_ids(strategy_id, 0..count).await?;
+
+        let mut total_signals = 0u64;
+        let mut winning_signals = 0u64;
+        let mut losing_signals = 0u64;
+        let mut push_signals = 0u64;
+        let mut total_pnl: i64 = 0;
+        let mut win_streak = 0u64;
+        let mut best_win_streak = 0u64;
+        let mut early_closed_signals = 0u64;
+        let mut early_closed_pnl: i64 = 0;
+        let mut brier_sum: u64 = 0;
+        let mut unresolved_signals = 0u64;
+
+        for signal_id in signal_ids {
+            if let Some(signal) = self.state.signals.get(&signal_id).await? {
+                if signal.unresolved && signal.strategy_version == version {
+                    unresolved_signals += 1;
+                }
+                if signal.status == SignalStatus::Resolved && signal.strategy_version == version {
+                    total_signals += 1;
+                    total_pnl = total_pnl.saturating_add(signal.pnl_bps.unwrap_or(0));
+                    if signal.closed_early {
+                        early_closed_signals += 1;
+                        early_closed_pnl = early_closed_pnl.saturating_add(signal.pnl_bps.unwrap_or(0));
+                    }
+
+                    match signal.result {
+                        Some(SignalResult::Win) => {
+                            winning_signals += 1;
+                            brier_sum += Self::brier_error_bps(&signal);
+                            win_streak += 1;
+                            best_win_streak = best_win_streak.max(win_streak);
+                        }
+                        Some(SignalResult::Lose) => {
+                            losing_signals += 1;
+                            brier_sum += Self::brier_error_bps(&signal);
+                            win_streak = 0;
+                        }
+                        Some(SignalResult::Push) => push_signals += 1,
+                        None => {}
+                    }
+                }
+            }
+        }
+
+        unresolved_signals += self.state.lapsed_commitments.get(&(strategy_id, version)).await?
+            .unwrap_or(0);
+
+        let win_rate_bps = (winning_signals * 10000)
+            .checked_div(total_signals)
+            .unwrap_or(0) as u32;
+
+        let brier_score_bps = brier_sum
+            .checked_div(winning_signals + losing_signals)
+            .unwrap_or(0) as u32;
+
+        let avg_pnl_bps = if total_signals > 0 {
+            (total_pnl / total_signals as i64).clamp(i32::MIN as i64, i32::MAX as i64) as i32
+        } else {
+            0
+        };
+
+        let followers = self.state.follower_count.get(&strategy_id).await?
+            .unwrap_or(0);
+        let total_followers_ever = self.state.lifetime_follower_count.get(&strategy_id).await?
+            .unwrap_or(0);
+
+        let stats = StrategyStats {
+            strategy_id,
+            total_signals,
+            winning_signals,
+            losing_signals,
+            push_signals,
+            win_rate_bps,
+            avg_pnl_bps,
+            total_pnl_bps: total_pnl,
+            followers,
+            early_closed_signals,
+            early_closed_pnl_bps: early_closed_pnl,
+            brier_score_bps,
+            unresolved_signals,
+            total_followers_ever,
+        };
+
+        Ok((stats, best_win_streak))
+    }
+
+
+    /// Award a strategy's owner any badges they do not hold yet
+    async fn award_badges(&mut self, strategy_id: u64, earned: Vec<BadgeKind>) -> Result<(), ViewError> {
+        let Some(strategy) = self.state.strategies.get(&strategy_id).await? else {
+            return Ok(());
+        };
+        let mut badges = self.state.badges.get(&strategy.owner).await?
+            .unwrap_or_default();
+
+        let mut awarded = false;
+        for kind in earned {
+            if badges.iter().any(|badge| badge.kind == kind) {
+                continue;
+            }
+            badges.push(Badge { kind, strategy_id, awarded_at: self.now() });
+            awarded = true;
+
+            let stream = StreamName::from(b"badges");
+            self.runtime.emit(stream, &AgentHubEvent::BadgeAwarded {
+                strategist: strategy.owner,
+                kind,
+                strategy_id,
+            });
+        }
+
+        if awarded {
+            self.state.badges.insert(&strategy.owner, badges)?;
+        }
+        Ok(())
+    }
+
+    /// Follow a strategy hosted on another chain (sends cross-chain message)
+    async fn follow_remote_strategy(
+        &mut self,
+        follower: AccountOwner,
+        strategy_id: u64,
+        strategy_chain_id: String,
+        auto_copy: bool,
+        max_exposure_units: u64,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        let target_chain = match strategy_chain_id.parse::<ChainId>() {
+            Ok(chain_id) => chain_id,
+            Err(_) => return Err(AgentHubError::InvalidChainId),
+        };
+
+        let strategy = GlobalId::new(target_chain, strategy_id);
+
+        // Check not already following
+        let follows = self.state.remote_follows.get(&follower).await?
+            .unwrap_or_default();
+        if follows.iter().any(|f| f.strategy == strategy) {
+            return Err(AgentHubError::AlreadyFollowing);
+        }
+
+        let follower_chain_id = self.runtime.chain_id();
+
+        // Send follow request to the strategy's chain
+        self.runtime.prepare_message(Message::FollowRequest {
+            strategy_id,
+            follower,
+            follower_chain_id,
+            auto_copy,
+            max_exposure_units,
+        }.into())
+        .with_authentication()
+        .with_tracking()
+        .send_to(target_chain);
+        let sent_at = self.now();
+        self.track_request(OutboundRequest {
+            key: OutboundRequest::follow_key(&follower, strategy),
+            kind: RequestKind::Follow,
+            requester: follower,
+            target_chain,
+            strategy: Some(strategy),
+            strategist: None,
+            status: RequestStatus::Pending,
+            sent_at,
+            updated_at: None,
+        })?;
+
+        // The follow is recorded locally when confirmation arrives
+        Ok(AgentHubResponse::FollowRequested { strategy })
+    }
+
+    /// Record a request sent to another chain as pending until it is answered or fails
+    fn track_request(&mut self, request: OutboundRequest) -> Result<(), ViewError> {
+        let key = request.key.clone();
+        self.state.outbound_timeouts.push_back((request.sent_at, key.clone()));
+        self.state.outbound_requests.insert(&key, request)?;
+        Ok(())
+    }
+
+    /// Mark a tracked request confirmed or failed. A timed-out request can still be confirmed
+    /// by a late reply.
+    async fn settle_request(&mut self, key: String, status: RequestStatus) -> Result<(), ViewError> {
+        let now = self.now();
+        if let Some(request) = self.state.outbound_requests.get_mut(&key).await? {
+            if matches!(request.status, RequestStatus::Pending | RequestStatus::TimedOut) {
+                request.status = status;
+                request.updated_at = Some(now);
+            }
+        }
+        Ok(())
+    }
+
+    /// Mark the request a bounced message belongs to as failed
+    async fn handle_bounced_message(
+        &mut self,
+        message: Message,
+        origin: Option<ChainId>,
+    ) -> Result<(), ViewError> {
+        let Some(target_chain) = origin else {
+            return Ok(());
+        };
+        let key = match message {
+            Message::SubscriptionRequest { subscriber, strategist, .. } => {
+                OutboundRequest::subscribe_key(&subscriber, &strategist)
+            }
+            Message::FollowRequest { strategy_id, follower, .. } => {
+                OutboundRequest::follow_key(&follower, GlobalId::new(target_chain, strategy_id))
+            }
+            Message::MirrorRequest { strategy_id } => {
+                OutboundRequest::mirror_key(GlobalId::new(target_chain, strategy_id))
+            }
+            _ => return Ok(()),
+        };
+        self.settle_request(key, RequestStatus::Failed).await
+    }
+
+    /// Apply an event from a followed strategist's signal stream
+    async fn receive_event(&mut self, event: AgentHubEvent) -> Result<(), AgentHubError> {
+        if let AgentHubEvent::SignalBroadcast { signal, strategist, base_market, .. } = event {
+            // Only disclosed signals are broadcast; skip anything else defensively
+            if signal.is_disclosed(self.now()) {
+                self.receive_signal(*signal, strategist, &base_market).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Save the state so far, so that a handler that fails can be rolled back to this point
+    /// without losing what came before it in the block
+    async fn checkpoint(&mut self) -> Result<(), AgentHubError> {
+        self.state.save().await?;
+        Ok(())
+    }
+
+    /// Run the due sweeps and a message's handler, rolling both back if either fails
+    async fn apply_message(
+        &mut self,
+        message: Message,
+        origin: Option<ChainId>,
+        signer: Option<AccountOwner>,
+    ) -> Result<(), AgentHubError> {
+        self.checkpoint().await?;
+        let mut result = self.run_due_sweeps().await;
+        if result.is_ok() {
+            result = self.handle_message(message, origin, signer).await;
+        }
+        if result.is_err() {
+            self.state.rollback();
+        }
+        result
+    }
+
+    /// Handle a message that came back from its target chain, rolling it back if it fails
+    async fn apply_bounced_message(
+        &mut self,
+        message: Message,
+        origin: Option<ChainId>,
+    ) -> Result<(), AgentHubError> {
+        self.checkpoint().await?;
+        let result = self.handle_bounced_message(message, origin).await;
+        if result.is_err() {
+            self.state.rollback();
+        }
+        Ok(result?)
+    }
+
+    /// Apply a stream event, rolling it back if it fails
+    async fn apply_event(&mut self, event: AgentHubEvent) -> Result<(), AgentHubError> {
+        self.checkpoint().await?;
+        let result = self.receive_event(event).await;
+        if result.is_err() {
+            self.state.rollback();
+        }
+        result
+    }
+
+    /// Upgrade the next batch of state the first release left, if any is left, answering
+    /// `MigrationInProgress` until it is all current. A storage failure rolls the batch back.
+    async fn migrate_batch(&mut self) -> Result<(), AgentHubError> {
+        match migration::migrate_batch(&mut self.state, self.runtime.chain_id()).await {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(AgentHubError::MigrationInProgress),
+            Err(error) => {
+                self.state.rollback();
+                Err(error.into())
+            }
+        }
+    }
+
+    /// Keep a message or stream event whose handler failed so it can be replayed or refunded
+    /// instead of aborting the block
+    #[allow(clippy::too_many_arguments)]
+    fn record_dead_letter(
+        &mut self,
+        origin: Option<ChainId>,
+        message_tag: Option<u32>,
+        event_index: Option<u32>,
+        signer: Option<AccountOwner>,
+        payload: Vec<u8>,
+        bounced: bool,
+        error: AgentHubError,
+    ) {
+        let sequence = *self.state.dead_letter_count.get() + 1;
+        let letter = DeadLetter {
+            sequence,
+            origin,
+            message_tag,
+            event_index,
+            signer,
+            payload,
+            bounced,
+            error: error.to_string(),
+            received_at: self.now(),
+            status: DeadLetterStatus::Pending,
+        };
+        self.state.dead_letters.insert(&sequence, letter).expect("Failed to record dead letter");
+        self.state.dead_letter_count.set(sequence);
+    }
+
+    /// Load a dead letter that has been neither replayed nor refunded
+    async fn pending_dead_letter(&self, sequence: u64) -> Result<DeadLetter, AgentHubError> {
+        match self.state.dead_letters.get(&sequence).await? {
+            Some(letter) if letter.status == DeadLetterStatus::Pending => Ok(letter),
+            Some(_) => Err(AgentHubError::DeadLetterSettled),
+            None => Err(AgentHubError::DeadLetterNotFound),
+        }
+    }
+
+    /// Run a dead letter's handler again as it first ran, with its origin and signer. If it
+    /// fails again its writes are rolled back and the letter stays pending.
+    async fn replay_dead_letter(
+        &mut self,
+        owner: AccountOwner,
+        sequence: u64,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        if !self.is_admin(owner).await? {
+            return Err(AgentHubError::NotAuthorized);
+        }
+        let mut letter = self.pending_dead_letter(sequence).await?;
+        let undecodable = || AgentHubError::Internal("Undecodable dead letter".to_string());
+
+        if letter.event_index.is_some() {
+            let event = bcs::from_bytes(&letter.payload).map_err(|_| undecodable())?;
+            self.apply_event(event).await?;
+        } else if letter.bounced {
+            let message = bcs::from_bytes(&letter.payload).map_err(|_| undecodable())?;
+            self.apply_bounced_message(message, letter.origin).await?;
+        } else {
+            let message = bcs::from_bytes(&letter.payload).map_err(|_| undecodable())?;
+            self.apply_message(message, letter.origin, letter.signer).await?;
+        }
+
+        letter.status = DeadLetterStatus::Replayed;
+        self.state.dead_letters.insert(&sequence, letter)?;
+        Ok(AgentHubResponse::DeadLetterReplayed { sequence })
+    }
+
+    /// Return the payment a dead subscription request or fee funding carried to its sender,
+    /// settling the letter without replaying it
+    async fn refund_dead_letter(
+        &mut self,
+        owner: AccountOwner,
+        sequence: u64,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        if !self.is_admin(owner).await? {
+            return Err(AgentHubError::NotAuthorized);
+        }
+        let mut letter = self.pending_dead_letter(sequence).await?;
+        // A bounced message's payment was escrowed on the target chain, out of reach here
+        if letter.event_index.is_some() || letter.bounced {
+            return Err(AgentHubError::NothingToRefund);
+        }
+        let message = bcs::from_bytes(&letter.payload)
+            .map_err(|_| AgentHubError::Internal("Undecodable dead letter".to_string()))?;
+
+        let (token, destination, amount) = match message {
+            Message::SubscriptionRequest { subscriber, subscriber_chain_id, payment, token, .. } => {
+                (token, Account { chain_id: subscriber_chain_id, owner: subscriber }, payment)
+            }
+            Message::GiftSubscriptionRequest { gifter, gifter_chain_id, payment, token, .. } => {
+                (token, Account { chain_id: gifter_chain_id, owner: gifter }, payment)
+            }
+            Message::PerformanceFeesFunded { follower, amount, .. } => match letter.origin {
+                Some(chain_id) => (None, Account { chain_id, owner: follower }, amount),
+                None => return Err(AgentHubError::NothingToRefund),
+            },
+            // Tips go straight to the strategist; other messages carry no payment
+            _ => return Err(AgentHubError::NothingToRefund),
+        };
+        if amount == Amount::ZERO {
+            return Err(AgentHubError::NothingToRefund);
+        }
+
+        self.pay_tokens_from_app_account(token, destination, amount);
+        letter.status = DeadLetterStatus::Refunded;
+        self.state.dead_letters.insert(&sequence, letter)?;
+        Ok(AgentHubResponse::DeadLetterRefunded { sequence, amount })
+    }
+
+    /// Mark requests left unanswered for a day as timed out
+    async fn time_out_requests(&mut self) -> Result<(), ViewError> {
+        const REQUEST_TIMEOUT_MICROS: u64 = 24 * 60 * 60 * 1_000_000;
+        const MAX_TIMEOUTS_PER_BLOCK: usize = 20;
+
+        let now = self.now();
+        for _ in 0..MAX_TIMEOUTS_PER_BLOCK {
+            let Some((sent_at, key)) = self.state.outbound_timeouts.front().await? else {
+                break;
+            };
+            if now.delta_since(sent_at).as_micros() < REQUEST_TIMEOUT_MICROS {
+                break;
+            }
+            self.state.outbound_timeouts.delete_front();
+            // A request sent again since is checked under its newer entry
+            if let Some(request) = self.state.outbound_requests.get_mut(&key).await? {
+                if request.sent_at == sent_at && request.status == RequestStatus::Pending {
+                    request.status = RequestStatus::TimedOut;
+                    request.updated_at = Some(now);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Request a snapshot of a public strategy from its host chain
+    fn mirror_strategy(
+        &mut self,
+        requester: AccountOwner,
+        strategy_id: u64,
+        strategy_chain_id: String,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        let target_chain = match strategy_chain_id.parse::<ChainId>() {
+            Ok(chain_id) => chain_id,
+            Err(_) => return Err(AgentHubError::InvalidChainId),
+        };
+        let strategy = GlobalId::new(target_chain, strategy_id);
+
+        // The snapshot is stored when it arrives
+        self.runtime.prepare_message(Message::MirrorRequest { strategy_id }.into())
+            .with_tracking()
+            .send_to(target_chain);
+        let sent_at = self.now();
+        self.track_request(OutboundRequest {
+            key: OutboundRequest::mirror_key(strategy),
+            kind: RequestKind::Mirror,
+            requester,
+            target_chain,
+            strategy: Some(strategy),
+            strategist: None,
+            status: RequestStatus::Pending,
+            sent_at,
+            updated_at: None,
+        })?;
+
+        Ok(AgentHubResponse::MirrorRequested { strategy })
+    }
+
+    /// Send a public strategy's record, stats and latest disclosed signals to a mirroring chain
+    async fn send_mirror_snapshot(&mut self, strategy_id: u64, destination: ChainId) -> Result<(), ViewError> {
+        const MAX_MIRRORED_SIGNALS: usize = 100;
+
+        let Some(strategy) = self.state.strategies.get(&strategy_id).await? else {
+            return Ok(());
+        };
+        if !strategy.is_public {
+            return Ok(());
+        }
+        let stats = self.state.strategy_stats.get(&strategy_id).await?
+            .unwrap_or_default();
+
+        let now = self.now();
+        let count = self.state.strategy_signal_count(strategy_id).await?;
+        let signal_ids = self.state.strategy_signal_ids(strategy_id, 0..count).await?;
+        let mut signals = Vec::new();
+        for signal_id in signal_ids.into_iter().rev() {
+            if signals.len() >= MAX_MIRRORED_SIGNALS {
+                break;
+            }
+            if let Some(signal) = self.state.signals.get(&signal_id).await? {
+                if signal.is_disclosed(now) {
+                    signals.push(signal);
+                }
+            }
+        }
+
+        self.runtime.prepare_message(Message::MirrorSnapshot {
+            strategy: Box::new(strategy),
+            stats,
+            signals,
+        }.into())
+        .send_to(destination);
+        Ok(())
+    }
+
+    /// Unfollow a strategy hosted on another chain
+    async fn unfollow_remote_strategy(
+        &mut self,
+        follower: AccountOwner,
+        strategy_id: u64,
+        strategy_chain_id: String,
+        reason: Option<UnfollowReason>,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        let target_chain = match strategy_chain_id.parse::<ChainId>() {
+            Ok(chain_id) => chain_id,
+            Err(_) => return Err(AgentHubError::InvalidChainId),
+        };
+
+        let mut follows = self.state.remote_follows.get(&follower).await?
+            .unwrap_or_default();
+        let before = follows.len();
+        let strategy = GlobalId::new(target_chain, strategy_id);
+        let removed: Vec<RemoteFollow> = follows.iter()
+            .filter(|f| f.strategy == strategy)
+            .cloned()
+            .collect();
+        follows.retain(|f| f.strategy != strategy);
+        if follows.len() == before {
+            return Err(AgentHubError::NotFollowing);
+        }
+
+        for follow in removed {
+            self.stop_listening_to_strategist(target_chain, follow.strategist).await?;
+        }
+
+        self.state.remote_follows.insert(&follower, follows)?;
+        self.state.remote_follows_by_strategy.load_entry_mut(&strategy).await?.remove(&follower)?;
+
+        // Notify the strategy's chain
+        self.runtime.prepare_message(Message::UnfollowRequest {
+            strategy_id,
+            follower,
+            reason,
+        }.into())
+        .with_authentication()
+        .send_to(target_chain);
+
+        Ok(AgentHubResponse::Unfollowed { strategy_id })
+    }
+
+    // =========================================================================
+    // Admin Methods
+    // =========================================================================
+
+    /// Change a strategist's display name, moving its name index entry
+    async fn update_display_name(&mut self, owner: AccountOwner, display_name: String) -> Result<AgentHubResponse, AgentHubError> {
+        let mut strategist = match self.state.strategists.get(&owner).await? {
+            Some(strategist) => strategist,
+            _ => return Err(AgentHubError::StrategistNotRegistered),
+        };
+
+        let name_key = normalize_name(&display_name);
+        if name_key.is_empty() {
+            return Err(AgentHubError::InvalidDisplayName);
+        }
+        match self.state.name_index.get(&name_key).await? {
+            Some(holder) if holder != owner => return Err(AgentHubError::NameTaken),
+            _ => {}
+        }
+
+        let old_key = normalize_name(&strategist.display_name);
+        if old_key != name_key {
+            if let Some(holder) = self.state.name_index.get(&old_key).await? {
+                if holder == owner {
+                    self.state.name_index.remove(&old_key)?;
+                }
+            }
+            self.state.name_index.insert(&name_key, owner)?;
+        }
+
+        strategist.display_name = display_name;
+        self.state.strategists.insert(&owner, strategist)?;
+        self.record_change(ChangeEntity::Strategist, owner, ChangeKind::Updated);
+
+        Ok(AgentHubResponse::DisplayNameUpdated { owner })
+    }
+
+    /// Set a strategist's verified flag
+    async fn set_strategist_verified(&mut self, owner: AccountOwner, verified: bool) -> Result<AgentHubResponse, AgentHubError> {
+        let mut strategist = match self.state.strategists.get(&owner).await? {
+            Some(strategist) => strategist,
+            _ => return Err(AgentHubError::StrategistNotRegistered),
+        };
+
+        strategist.verified = verified;
+        self.state.strategists.insert(&owner, strategist)?;
+        self.record_change(ChangeEntity::Strategist, owner, ChangeKind::Updated);
+
+        Ok(AgentHubResponse::StrategistVerificationSet { strategist: owner, verified })
+    }
+
+    /// Check whether the caller is an admin (the configured admin always is)
+    async fn is_admin(&mut self, owner: AccountOwner) -> Result<bool, ViewError> {
+        if self.runtime.application_parameters().admin == owner {
+            return Ok(true);
+        }
+        Ok(matches!(self.state.roles.get(&owner).await?, Some(Role::Admin)))
+    }
+
+    /// Check whether the caller holds `role` (admins hold every role)
+    async fn has_role(&mut self, owner: AccountOwner, role: Role) -> Result<bool, ViewError> {
+        if self.is_admin(owner).await? {
+            return Ok(true);
+        }
+        Ok(matches!(self.state.roles.get(&owner).await?, Some(r) if r == role))
+    }
+
+    /// Grant or revoke a role, locally or on another chain
+    async fn set_role(
+        &mut self,
+        admin: AccountOwner,
+        owner: AccountOwner,
+        role: Option<Role>,
+        target_chain_id: Option<String>,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        if !self.is_admin(admin).await? {
+            return Err(AgentHubError::NotAuthorized);
+        }
+
+        match target_chain_id {
+            Some(target_chain_id) => {
+                let Ok(target_chain) = target_chain_id.parse::<ChainId>() else {
+                    return Err(AgentHubError::InvalidChainId);
+                };
+                self.runtime.prepare_message(Message::SetRole { owner, role }.into())
+                    .with_authentication()
+                    .send_to(target_chain);
+            }
+            None => {
+                self.apply_role(owner, role)?;
+            }
+        }
+
+        match role {
+            Some(role) => Ok(AgentHubResponse::RoleGranted { owner, role }),
+            None => Ok(AgentHubResponse::RoleRevoked { owner }),
+        }
+    }
+
+    /// Store or remove an account's role on this chain
+    fn apply_role(&mut self, owner: AccountOwner, role: Option<Role>) -> Result<(), ViewError> {
+        match role {
+            Some(role) => self.state.roles.insert(&owner, role)?,
+            None => self.state.roles.remove(&owner)?,
+        }
+        Ok(())
+    }
+
+    /// Add or update a market in the registry, here or on another chain
+    async fn register_market(
+        &mut self,
+        admin: AccountOwner,
+        mut market: Market,
+        target_chain_id: Option<String>,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        if !self.is_admin(admin).await? {
+            return Err(AgentHubError::NotAuthorized);
+        }
+
+        market.symbol = Market::normalize_symbol(&market.symbol);
+        if market.symbol.is_empty() {
+            return Err(AgentHubError::InvalidMarket);
+        }
+        let symbol = market.symbol.clone();
+
+        match target_chain_id {
+            Some(target_chain_id) => {
+                let Ok(target_chain) = target_chain_id.parse::<ChainId>() else {
+                    return Err(AgentHubError::InvalidChainId);
+                };
+                self.runtime.prepare_message(Message::SetMarket { market }.into())
+                    .with_authentication()
+                    .send_to(target_chain);
+            }
+            None => {
+                self.apply_market(market)?;
+            }
+        }
+
+        Ok(AgentHubResponse::MarketUpdated { symbol })
+    }
+
+    /// Activate or deactivate a registered market, here or on another chain
+    async fn set_market_active(
+        &mut self,
+        admin: AccountOwner,
+        symbol: String,
+        is_active: bool,
+        target_chain_id: Option<String>,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        if !self.is_admin(admin).await? {
+            return Err(AgentHubError::NotAuthorized);
+        }
+
+        let symbol = Market::normalize_symbol(&symbol);
+        match target_chain_id {
+            Some(target_chain_id) => {
+                let Ok(target_chain) = target_chain_id.parse::<ChainId>() else {
+                    return Err(AgentHubError::InvalidChainId);
+                };
+                self.runtime
+                    .prepare_message(Message::SetMarketActive { symbol: symbol.clone(), is_active }.into())
+                    .with_authentication()
+                    .send_to(target_chain);
+            }
+            None => {
+                match self.apply_market_active(&symbol, is_active).await {
+                    Ok(true) => {}
+                    Ok(false) => return Err(AgentHubError::MarketNotFound),
+                    Err(error) => return Err(error.into()),
+                }
+            }
+        }
+
+        Ok(AgentHubResponse::MarketUpdated { symbol })
+    }
+
+    /// Store a market in this chain's registry
+    fn apply_market(&mut self, market: Market) -> Result<(), ViewError> {
+        let symbol = market.symbol.clone();
+        self.state.markets.insert(&symbol, market)?;
+        self.record_change(ChangeEntity::Market, symbol, ChangeKind::Updated);
+        Ok(())
+    }
+
+    /// Set a registered market's active flag on this chain; false if it is not registered
+    async fn apply_market_active(&mut self, symbol: &str, is_active: bool) -> Result<bool, ViewError> {
+        let Some(mut market) = self.state.markets.get(symbol).await? else {
+            return Ok(false);
+        };
+        market.is_active = is_active;
+        self.apply_market(market)?;
+        Ok(true)
+    }
+
+    /// Set or clear a market kind's default resolution rules, here or on another chain
+    async fn set_resolution_rules(
+        &mut self,
+        admin: AccountOwner,
+        market_kind: MarketKind,
+        rules: Option<ResolutionRules>,
+        target_chain_id: Option<String>,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        if !self.is_admin(admin).await? {
+            return Err(AgentHubError::NotAuthorized);
+        }
+
+        match target_chain_id {
+            Some(target_chain_id) => {
+                let Ok(target_chain) = target_chain_id.parse::<ChainId>() else {
+                    return Err(AgentHubError::InvalidChainId);
+                };
+                self.runtime
+                    .prepare_message(Message::SetResolutionRules {
+                        market_kind: market_kind.clone(),
+                        rules,
+                    }.into())
+                    .with_authentication()
+                    .send_to(target_chain);
+            }
+            None => {
+                self.apply_resolution_rules(&market_kind, rules)?;
+            }
+        }
+
+        Ok(AgentHubResponse::ResolutionRulesUpdated { market_kind })
+    }
+
+    /// Store or clear a market kind's resolution rules on this chain
+    fn apply_resolution_rules(
+        &mut self,
+        market_kind: &MarketKind,
+        rules: Option<ResolutionRules>,
+    ) -> Result<(), ViewError> {
+        match rules {
+            Some(rules) => self.state.kind_resolution_rules.insert(market_kind, rules)?,
+            None => self.state.kind_resolution_rules.remove(market_kind)?,
+        }
+        Ok(())
+    }
+
+    /// Registered market for a symbol, if any
+    async fn market(&self, symbol: &str) -> Result<Option<Market>, ViewError> {
+        self.state.markets.get(&Market::normalize_symbol(symbol)).await
+    }
+
+    /// Update signal publishing rate limits
+    async fn set_rate_limits(
+        &mut self,
+        owner: AccountOwner,
+        max_signals_per_hour: u32,
+        max_signals_per_day: u32,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        if !self.is_admin(owner).await? {
+            return Err(AgentHubError::NotAuthorized);
+        }
+
+        let limits = RateLimitConfig {
+            max_signals_per_hour,
+            max_signals_per_day,
+        };
+        self.state.rate_limits.set(limits);
+
+        Ok(AgentHubResponse::RateLimitsUpdated { limits })
+    }
+
+    /// Withdraw protocol fees from the treasury
+    async fn withdraw_treasury(&mut self, owner: AccountOwner, amount: Amount, to: Account) -> Result<AgentHubResponse, AgentHubError> {
+        if !self.is_admin(owner).await? {
+            return Err(AgentHubError::NotAuthorized);
+        }
+
+        let balance = *self.state.treasury_balance.get();
+        let Ok(remaining) = balance.try_sub(amount) else {
+            return Err(AgentHubError::InsufficientTreasury);
+        };
+
+        self.state.treasury_balance.set(remaining);
+        self.pay_from_app_account(to, amount);
+
+        Ok(AgentHubResponse::TreasuryWithdrawn { amount, to })
+    }
+
+    // =========================================================================
+    // Governance Methods
+    // =========================================================================
+
+    /// Voting weight of an account: 1 + reputation for strategists, 1 for followers
+    async fn voting_weight(&mut self, owner: AccountOwner) -> Result<u64, ViewError> {
+        if self.state.strategists.contains_key(&owner).await? {
+            let reputation = self.state.reputation.get(&owner).await?
+                .unwrap_or(0);
+            return Ok(1 + reputation);
+        }
+        let follows = match self.state.follows_by_follower.try_load_entry(&owner).await? {
+            Some(follows) => follows.count().await?,
+            None => 0,
+        };
+        let remote_follows = self.state.remote_follows.get(&owner).await?
+            .unwrap_or_default();
+        let subscriptions = self.state.subscriptions_by_subscriber.get(&owner).await?
+            .unwrap_or_default();
+        if follows == 0 && remote_follows.is_empty() && subscriptions.is_empty() {
+            Ok(0)
+        } else {
+            Ok(1)
+        }
+    }
+
+    /// Whether this chain is the hub chain, where governance runs
+    fn is_hub_chain(&mut self) -> bool {
+        *self.state.hub_chain_id.get() == Some(self.runtime.chain_id())
+    }
+
+    /// Create a parameter-change proposal
+    async fn create_proposal(
+        &mut self,
+        owner: AccountOwner,
+        description: String,
+        change: ParameterChange,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        const VOTING_PERIOD_MICROS: u64 = 3 * 24 * 60 * 60 * 1_000_000;
+        const TIMELOCK_MICROS: u64 = 24 * 60 * 60 * 1_000_000;
+
+        if !self.is_hub_chain() {
+            return Err(AgentHubError::NotHubChain);
+        }
+        if change.is_empty() || change.protocol_fee_bps.is_some_and(|bps| bps > 10000) {
+            return Err(AgentHubError::InvalidProposal);
+        }
+        if self.voting_weight(owner).await? == 0 {
+            return Err(AgentHubError::NotEligibleToVote);
+        }
+
+        let id = *self.state.next_proposal_id.get() + 1;
+        self.state.next_proposal_id.set(id);
+
+        let now = self.now();
+        let voting_ends_at = now.saturating_add(TimeDelta::from_micros(VOTING_PERIOD_MICROS));
+        let proposal = Proposal {
+            id,
+            proposer: owner,
+            description,
+            change,
+            votes_for: 0,
+            votes_against: 0,
+            created_at: now,
+            voting_ends_at,
+            executable_at: voting_ends_at.saturating_add(TimeDelta::from_micros(TIMELOCK_MICROS)),
+            status: ProposalStatus::Active,
+        };
+        self.state.proposals.insert(&id, proposal)?;
+        self.record_change(ChangeEntity::Proposal, id, ChangeKind::Created);
+        self.state.pending_proposals.get_mut().push(id);
+
+        Ok(AgentHubResponse::ProposalCreated { id })
+    }
+
+    /// Cast a weighted vote on an active proposal
+    async fn vote(&mut self, owner: AccountOwner, proposal_id: u64, support: bool) -> Result<AgentHubResponse, AgentHubError> {
+        if !self.is_hub_chain() {
+            return Err(AgentHubError::NotHubChain);
+        }
+        let mut proposal = match self.state.proposals.get(&proposal_id).await? {
+            Some(p) => p,
+            _ => return Err(AgentHubError::ProposalNotFound),
+        };
+        if proposal.status != ProposalStatus::Active || self.now() >= proposal.voting_ends_at {
+            return Err(AgentHubError::VotingClosed);
+        }
+
+        let vote_key = (proposal_id, owner);
+        if self.state.proposal_votes.contains_key(&vote_key).await? {
+            return Err(AgentHubError::AlreadyVoted);
+        }
+        let weight = self.voting_weight(owner).await?;
+        if weight == 0 {
+            return Err(AgentHubError::NotEligibleToVote);
+        }
+
+        if support {
+            proposal.votes_for += weight;
+        } else {
+            proposal.votes_against += weight;
+        }
+        self.state.proposal_votes.insert(&vote_key, support)?;
+        self.state.proposals.insert(&proposal_id, proposal)?;
+        self.record_change(ChangeEntity::Proposal, proposal_id, ChangeKind::Updated);
+
+        Ok(AgentHubResponse::VoteCast { proposal_id, weight })
+    }
+
+    /// Tally proposals whose vote has ended and apply passed ones once their timelock expires.
+    /// Other chains take an executed change only when an admin pushes it from the hub.
+    async fn process_proposals(&mut self) -> Result<(), ViewError> {
+        let pending = self.state.pending_proposals.get().clone();
+        if pending.is_empty() || !self.is_hub_chain() {
+            return Ok(());
+        }
+        let now = self.now();
+        let mut still_pending = Vec::with_capacity(pending.len());
+
+        for id in pending {
+            let Some(mut proposal) = self.state.proposals.get(&id).await? else {
+                continue;
+            };
+            if proposal.status == ProposalStatus::Active && now >= proposal.voting_ends_at {
+                proposal.status = if proposal.votes_for > proposal.votes_against {
+                    ProposalStatus::Passed
+                } else {
+                    ProposalStatus::Rejected
+                };
+            }
+            if proposal.status == ProposalStatus::Passed && now >= proposal.executable_at {
+                self.apply_parameter_change(&proposal.change);
+                proposal.status = ProposalStatus::Executed;
+            }
+            if matches!(proposal.status, ProposalStatus::Active | ProposalStatus::Passed) {
+                still_pending.push(id);
+            }
+            self.state.proposals.insert(&id, proposal)?;
+            self.record_change(ChangeEntity::Proposal, id, ChangeKind::Updated);
+        }
+
+        self.state.pending_proposals.set(still_pending);
+        Ok(())
+    }
+
+    /// Send an executed proposal's change from the hub to another chain
+    async fn push_parameter_change(
+        &mut self,
+        admin: AccountOwner,
+        proposal_id: u64,
+        target_chain_id: String,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        if !self.is_admin(admin).await? {
+            return Err(AgentHubError::NotAuthorized);
+        }
+        if !self.is_hub_chain() {
+            return Err(AgentHubError::NotHubChain);
+        }
+        let Ok(target_chain) = target_chain_id.parse::<ChainId>() else {
+            return Err(AgentHubError::InvalidChainId);
+        };
+        let proposal = match self.state.proposals.get(&proposal_id).await? {
+            Some(proposal) => proposal,
+            None => return Err(AgentHubError::ProposalNotFound),
+        };
+        if proposal.status != ProposalStatus::Executed {
+            return Err(AgentHubError::ProposalNotExecuted);
+        }
+
+        self.runtime.prepare_message(Message::ParameterChangeApplied {
+            proposal_id,
+            change: proposal.change,
+        }.into())
+        .with_authentication()
+        .send_to(target_chain);
+
+        Ok(AgentHubResponse::ParameterChangePushed { proposal_id, target_chain })
+    }
+
+    /// Apply a passed parameter change
+    fn apply_parameter_change(&mut self, change: &ParameterChange) {
+        if let Some(bps) = change.protocol_fee_bps {
+            self.state.protocol_fee_bps.set(Some(bps));
+        }
+        let limits = self.state.rate_limits.get_mut();
+        if let Some(per_hour) = change.max_signals_per_hour {
+            limits.max_signals_per_hour = per_hour;
+        }
+        if let Some(per_day) = change.max_signals_per_day {
+            limits.max_signals_per_day = per_day;
+        }
+    }
+
+    // =========================================================================
+    // Tournament Methods
+    // =========================================================================
+
+    /// Create a tournament and move its prize pool into the application's account
+    #[allow(clippy::too_many_arguments)]
+    async fn create_tournament(
+        &mut self,
+        owner: AccountOwner,
+        name: String,
+        market_kind: Option<MarketKind>,
+        base_market: Option<String>,
+        start_timestamp: Timestamp,
+        end_timestamp: Timestamp,
+        prize_pool: Amount,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        if !self.is_admin(owner).await? {
+            return Err(AgentHubError::NotAuthorized);
+        }
+        if end_timestamp <= start_timestamp || end_timestamp <= self.now() {
+            return Err(AgentHubError::InvalidTournament);
+        }
+
+        if prize_pool > Amount::ZERO {
+            let chain_id = self.runtime.chain_id();
+            let app_account = self.escrow_account(chain_id);
+            self.runtime.transfer(owner, app_account, prize_pool);
+        }
+
+        let id = *self.state.next_tournament_id.get() + 1;
+        self.state.next_tournament_id.set(id);
+
+        let tournament = Tournament {
+            id,
+            name,
+            market_kind,
+            base_market,
+            start_timestamp,
+            end_timestamp,
+            prize_pool,
+            settled: false,
+            created_at: self.now(),
+        };
+        self.state.tournaments.insert(&id, tournament)?;
+        self.record_change(ChangeEntity::Tournament, id, ChangeKind::Created);
+        self.state.open_tournaments.get_mut().push(id);
+
+        Ok(AgentHubResponse::TournamentCreated { id })
+    }
+
+    /// Add a resolved signal's outcome to every open tournament it was published in
+    async fn score_tournaments(&mut self, strategy: &AgentStrategy, signal: &Signal) -> Result<(), ViewError> {
+        let (Some(result), Some(pnl_bps)) = (signal.result, signal.pnl_bps) else {
+            return Ok(());
+        };
+
+        for tournament_id in self.state.open_tournaments.get().clone() {
+            let Some(tournament) = self.state.tournaments.get(&tournament_id).await? else {
+                continue;
+            };
+            if !tournament.accepts(strategy)
+                || signal.created_at < tournament.start_timestamp
+                || signal.created_at >= tournament.end_timestamp
+            {
+                continue;
+            }
+
+            let key = (tournament_id, strategy.id);
+            let mut entry = match self.state.tournament_entries.get(&key).await? {
+                Some(entry) => {
+                    let rank = TournamentScoreKey { score_bps: entry.score_bps, strategy_id: strategy.id };
+                    self.state.tournament_participants.load_entry_mut(&tournament_id).await?.remove(&rank)?;
+                    entry
+                }
+                None => TournamentEntry {
+                    tournament_id,
+                    strategy_id: strategy.id,
+                    owner: strategy.owner,
+                    resolved_signals: 0,
+                    winning_signals: 0,
+                    score_bps: 0,
+                    prize: Amount::ZERO,
+                },
+            };
+            entry.resolved_signals += 1;
+            if result == SignalResult::Win {
+                entry.winning_signals += 1;
+            }
+            entry.score_bps = entry.score_bps.saturating_add(pnl_bps);
+            let rank = TournamentScoreKey { score_bps: entry.score_bps, strategy_id: strategy.id };
+            self.state.tournament_participants.load_entry_mut(&tournament_id).await?.insert(&rank)?;
+            self.state.tournament_entries.insert(&key, entry)?;
+        }
+        Ok(())
+    }
+
+    /// Pay a finished tournament's prize pool to its top three strategies (50/30/20)
+    async fn settle_tournament(&mut self, owner: AccountOwner, tournament_id: u64) -> Result<AgentHubResponse, AgentHubError> {
+        const PRIZE_SHARES_BPS: [u128; 3] = [5000, 3000, 2000];
+
+        if !self.is_admin(owner).await? {
+            return Err(AgentHubError::NotAuthorized);
+        }
+        let mut tournament = match self.state.tournaments.get(&tournament_id).await? {
+            Some(t) => t,
+            _ => return Err(AgentHubError::TournamentNotFound),
+        };
+        if tournament.settled {
+            return Err(AgentHubError::TournamentAlreadySettled);
+        }
+        if self.now() < tournament.end_timestamp {
+            return Err(AgentHubError::TournamentNotEnded);
+        }
+
+        let mut leaders = Vec::new();
+        if let Some(participants) = self.state.tournament_participants.try_load_entry(&tournament_id).await? {
+            participants.for_each_index_while(|rank| {
+                leaders.push(rank.strategy_id);
+                Ok(leaders.len() < PRIZE_SHARES_BPS.len())
+            }).await?;
+        }
+        let mut entries = Vec::new();
+        for strategy_id in leaders {
+            if let Some(entry) = self.state.tournament_entries.get(&(tournament_id, strategy_id)).await? {
+                entries.push(entry);
+            }
+        }
+
+        // Shares of missing places go to the winner; with no entries the pool goes to the treasury
+        let winners = entries.len().min(PRIZE_SHARES_BPS.len());
+        let pool = tournament.prize_pool.to_attos();
+        let mut distributed = Amount::ZERO;
+        for (place, entry) in entries.iter_mut().take(winners).enumerate().rev() {
+            let prize = if place == 0 {
+                tournament.prize_pool.saturating_sub(distributed)
+            } else {
+                Amount::from_attos(pool / 10000 * PRIZE_SHARES_BPS[place]
+                    + pool % 10000 * PRIZE_SHARES_BPS[place] / 10000)
+            };
+            let destination = Account { chain_id: self.runtime.chain_id(), owner: entry.owner };
+            self.pay_from_app_account(destination, prize);
+            distributed = distributed.saturating_add(prize);
+            entry.prize = prize;
+            self.state.tournament_entries.insert(&(tournament_id, entry.strategy_id), entry.clone())?;
+        }
+        if winners == 0 {
+            self.credit_treasury(tournament.prize_pool);
+        }
+
+        tournament.settled = true;
+        self.state.tournaments.insert(&tournament_id, tournament)?;
+        self.record_change(ChangeEntity::Tournament, tournament_id, ChangeKind::Updated);
+        self.state.open_tournaments.get_mut().retain(|id| *id != tournament_id);
+
+        Ok(AgentHubResponse::TournamentSettled { id: tournament_id, distributed })
+    }
+
+    // =========================================================================
+    // Subscription Methods
+    // =========================================================================
+
+    /// Enable subscription for this strategist
+    async fn enable_subscription(
+        &mut self,
+        owner: AccountOwner,
+        description: Option<String>,
+        price: Amount,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        // Check if strategist is registered
+        if !self.state.strategists.contains_key(&owner).await? {
+            return Err(AgentHubError::StrategistNotRegistered);
+        }
+
+        // Keep the token, period and trial set through their own operations
+        let mut offer = self.state.subscription_offers.get(&owner).await?.unwrap_or_else(|| SubscriptionOffer::disabled(owner));
+        offer.description = description;
+        offer.price = price;
+        offer.is_enabled = true;
+
+        self.state.subscription_offers.insert(&owner, offer)?;
+
+        Ok(AgentHubResponse::SubscriptionEnabled { strategist: owner })
+    }
+
+    /// Set the price, payment token and period length of this strategist's subscription offer
+    async fn set_subscription_price(
+        &mut self,
+        owner: AccountOwner,
+        amount: Amount,
+        token: Option<ApplicationId>,
+        duration_days: u32,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        if !self.state.strategists.contains_key(&owner).await? {
+            return Err(AgentHubError::StrategistNotRegistered);
+        }
+        if duration_days == 0 || duration_days > MAX_SUBSCRIPTION_DAYS {
+            return Err(AgentHubError::InvalidSubscriptionDuration);
+        }
+
+        let mut offer = self.state.subscription_offers.get(&owner).await?.unwrap_or_else(|| SubscriptionOffer::disabled(owner));
+        offer.price = amount;
+        offer.token = token;
+        offer.duration_days = duration_days;
+
+        self.state.subscription_offers.insert(&owner, offer)?;
+
+        Ok(AgentHubResponse::SubscriptionPriceSet { strategist: owner, amount, duration_days })
+    }
+
+    /// Set the free trial granted to this strategist's first-time subscribers
+    async fn set_subscription_trial(&mut self, owner: AccountOwner, trial_days: u32) -> Result<AgentHubResponse, AgentHubError> {
+        if !self.state.strategists.contains_key(&owner).await? {
+            return Err(AgentHubError::StrategistNotRegistered);
+        }
+        if trial_days > MAX_TRIAL_DAYS {
+            return Err(AgentHubError::InvalidTrialPeriod);
+        }
+
+        let mut offer = self.state.subscription_offers.get(&owner).await?.unwrap_or_else(|| SubscriptionOffer::disabled(owner));
+        offer.trial_days = trial_days;
+
+        self.state.subscription_offers.insert(&owner, offer)?;
+
+        Ok(AgentHubResponse::SubscriptionTrialSet { strategist: owner, trial_days })
+    }
+
+    /// Disable subscription for this strategist
+    async fn disable_subscription(&mut self, owner: AccountOwner) -> Result<AgentHubResponse, AgentHubError> {
+        // Check if subscription offer exists
+        if let Some(mut offer) = self.state.subscription_offers.get(&owner).await? {
+            offer.is_enabled = false;
+            self.state.subscription_offers.insert(&owner, offer)?;
+        }
+
+        Ok(AgentHubResponse::SubscriptionDisabled { strategist: owner })
+    }
+
+    /// Subscribe to a strategist (sends cross-chain message)
+    async fn subscribe_to_strategist(
+        &mut self,
+        subscriber: AccountOwner,
+        strategist: AccountOwner,
+        strategist_chain_id: String,
+        payment: Amount,
+        token: Option<ApplicationId>,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        let target_chain = match strategist_chain_id.parse::<ChainId>() {
+            Ok(chain_id) => chain_id,
+            Err(_) => return Err(AgentHubError::InvalidChainId),
+        };
+
+        // Check if already subscribed (a trial can still be converted to a paid subscription,
+        // and an expired subscription renewed)
+        let subscription_id = subscription_id(&subscriber, &strategist);
+        if let Some(sub) = self.state.subscriptions.get(&subscription_id).await? {
+            if sub.grants_access(self.now()) && !sub.is_trial {
+                return Err(AgentHubError::AlreadySubscribed);
+            }
+        }
+
+        let timestamp = self.now();
+        let subscriber_chain_id = self.runtime.chain_id();
+
+        // Pay into the application's escrow account on the strategist's chain
+        if payment > Amount::ZERO {
+            let escrow_account = self.escrow_account(target_chain);
+            self.transfer_tokens(token, subscriber, escrow_account, payment);
+        }
+
+        // Send subscription request to strategist's chain
+        self.runtime.prepare_message(Message::SubscriptionRequest {
+            subscriber,
+            subscriber_chain_id,
+            strategist,
+            timestamp,
+            payment,
+            token,
+        }.into())
+        .with_tracking()
+        .send_to(target_chain);
+        self.track_request(OutboundRequest {
+            key: OutboundRequest::subscribe_key(&subscriber, &strategist),
+            kind: RequestKind::Subscribe,
+            requester: subscriber,
+            target_chain,
+            strategy: None,
+            strategist: Some(strategist),
+            status: RequestStatus::Pending,
+            sent_at: timestamp,
+            updated_at: None,
+        })?;
+
+        // The subscription is recorded locally under this ID when confirmation arrives
+        Ok(AgentHubResponse::Subscribed { subscription_id })
+    }
+
+    /// Pay for a subscription to `strategist` on behalf of `recipient`
+    #[allow(clippy::too_many_arguments)]
+    async fn gift_subscription(
+        &mut self,
+        gifter: AccountOwner,
+        recipient: AccountOwner,
+        recipient_chain_id: String,
+        strategist: AccountOwner,
+        strategist_chain_id: String,
+        payment: Amount,
+        token: Option<ApplicationId>,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        let (Ok(recipient_chain), Ok(target_chain)) = (
+            recipient_chain_id.parse::<ChainId>(),
+            strategist_chain_id.parse::<ChainId>(),
+        ) else {
+            return Err(AgentHubError::InvalidChainId);
+        };
+        if payment == Amount::ZERO || gifter == recipient {
+            return Err(AgentHubError::InvalidGift);
+        }
+
+        let escrow_account = self.escrow_account(target_chain);
+        self.transfer_tokens(token, gifter, escrow_account, payment);
+
+        let gifter_chain_id = self.runtime.chain_id();
+        let timestamp = self.now();
+        self.runtime.prepare_message(Message::GiftSubscriptionRequest {
+            gifter,
+            gifter_chain_id,
+            recipient,
+            recipient_chain_id: recipient_chain,
+            strategist,
+            timestamp,
+            payment,
+            token,
+        }.into()).send_to(target_chain);
+
+        Ok(AgentHubResponse::SubscriptionGifted {
+            subscription_id: subscription_id(&recipient, &strategist),
+        })
+    }
+
+    /// Pay a strategist a tip and have it recorded on their chain
+    async fn tip_strategist(
+        &mut self,
+        tipper: AccountOwner,
+        strategist: AccountOwner,
+        strategist_chain_id: String,
+        amount: Amount,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        let target_chain = match strategist_chain_id.parse::<ChainId>() {
+            Ok(chain_id) => chain_id,
+            Err(_) => return Err(AgentHubError::InvalidChainId),
+        };
+        if amount == Amount::ZERO || tipper == strategist {
+            return Err(AgentHubError::InvalidTip);
+        }
+
+        let destination = Account { chain_id: target_chain, owner: strategist };
+        self.runtime.transfer(tipper, destination, amount);
+
+        if target_chain == self.runtime.chain_id() {
+            let tipper_chain_id = self.runtime.chain_id();
+            self.record_tip(tipper, tipper_chain_id, strategist, amount).await?;
+        } else {
+            self.runtime.prepare_message(Message::TipSent { tipper, strategist, amount }.into())
+                .with_authentication()
+                .send_to(target_chain);
+        }
+
+        Ok(AgentHubResponse::TipSent { strategist, amount })
+    }
+
+    /// Record a tip on the strategist's chain and announce it
+    async fn record_tip(
+        &mut self,
+        tipper: AccountOwner,
+        tipper_chain_id: ChainId,
+        strategist: AccountOwner,
+        amount: Amount,
+    ) -> Result<(), ViewError> {
+        let tip = Tip {
+            tipper,
+            tipper_chain_id,
+            strategist,
+            amount,
+            timestamp: self.now(),
+        };
+        let mut tips = self.state.tips_by_strategist.get(&strategist).await?.unwrap_or_default();
+        tips.push(tip);
+        self.state.tips_by_strategist.insert(&strategist, tips)?;
+
+        let total = self.state.tips_received.get(&strategist).await?.unwrap_or_default();
+        self.state.tips_received.insert(&strategist, total.saturating_add(amount))?;
+
+        let stream = StreamName::from(b"tips");
+        self.runtime.emit(stream, &AgentHubEvent::TipReceived { tipper, strategist, amount });
+        Ok(())
+    }
+
+    /// Set the share of auto-copy followers' profits charged by a strategy
+    async fn set_performance_fee(&mut self, owner: AccountOwner, strategy_id: u64, fee_bps: u16) -> Result<AgentHubResponse, AgentHubError> {
+        const MAX_PERFORMANCE_FEE_BPS: u16 = 5000;
+
+        let mut strategy = match self.state.strategies.get(&strategy_id).await? {
+            Some(s) => s,
+            _ => return Err(AgentHubError::StrategyNotFound),
+        };
+        if strategy.owner != owner {
+            return Err(AgentHubError::NotAuthorized);
+        }
+        if fee_bps > MAX_PERFORMANCE_FEE_BPS {
+            return Err(AgentHubError::InvalidPerformanceFee);
+        }
+
+        strategy.performance_fee_bps = fee_bps;
+        self.state.strategies.insert(&strategy_id, strategy)?;
+        self.record_change(ChangeEntity::Strategy, strategy_id, ChangeKind::Updated);
+
+        Ok(AgentHubResponse::PerformanceFeeSet { strategy_id, fee_bps })
+    }
+
+    /// Change a strategy's follower cap, admitting waitlisted followers into any new slots
+    async fn set_max_followers(
+        &mut self,
+        owner: AccountOwner,
+        strategy_id: u64,
+        max_followers: Option<u64>,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        let mut strategy = match self.state.strategies.get(&strategy_id).await? {
+            Some(s) => s,
+            _ => return Err(AgentHubError::StrategyNotFound),
+        };
+        if strategy.owner != owner {
+            return Err(AgentHubError::NotAuthorized);
+        }
+
+        strategy.max_followers = max_followers;
+        self.state.strategies.insert(&strategy_id, strategy)?;
+        self.record_change(ChangeEntity::Strategy, strategy_id, ChangeKind::Updated);
+
+        self.admit_waitlisted_followers(strategy_id).await?;
+
+        Ok(AgentHubResponse::MaxFollowersSet { strategy_id, max_followers })
+    }
+
+    /// Replace a strategy's tags, keeping the tag index of public strategies in step
+    async fn set_strategy_tags(
+        &mut self,
+        owner: AccountOwner,
+        strategy_id: u64,
+        tags: Vec<String>,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        let mut strategy = match self.state.strategies.get(&strategy_id).await? {
+            Some(s) => s,
+            _ => return Err(AgentHubError::StrategyNotFound),
+        };
+        if strategy.owner != owner {
+            return Err(AgentHubError::NotAuthorized);
+        }
+        let Some(tags) = normalize_tags(&tags) else {
+            return Err(AgentHubError::InvalidTags);
+        };
+
+        if strategy.is_public {
+            for tag in strategy.tags.iter().filter(|tag| !tags.contains(tag)) {
+                let mut ids = self.state.strategies_by_tag.get(tag).await?
+                    .unwrap_or_default();
+                ids.retain(|id| *id != strategy_id);
+                if ids.is_empty() {
+                    self.state.strategies_by_tag.remove(tag)?;
+                } else {
+                    self.state.strategies_by_tag.insert(tag, ids)?;
+                }
+            }
+            for tag in tags.iter().filter(|tag| !strategy.tags.contains(tag)) {
+                let mut ids = self.state.strategies_by_tag.get(tag).await?
+                    .unwrap_or_default();
+                ids.push(strategy_id);
+                self.state.strategies_by_tag.insert(tag, ids)?;
+            }
+        }
+
+        strategy.tags = tags.clone();
+        self.state.strategies.insert(&strategy_id, strategy)?;
+        self.record_change(ChangeEntity::Strategy, strategy_id, ChangeKind::Updated);
+
+        Ok(AgentHubResponse::StrategyTagsSet { strategy_id, tags })
+    }
+
+    /// Block an account from a strategy, removing its follow (and telling its chain) and any
+    /// waitlisted request
+    async fn block_follower(
+        &mut self,
+        owner: AccountOwner,
+        strategy_id: u64,
+        follower: AccountOwner,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        match self.state.strategies.get(&strategy_id).await? {
+            Some(strategy) if strategy.owner == owner => {}
+            Some(_) => return Err(AgentHubError::NotAuthorized),
+            _ => return Err(AgentHubError::StrategyNotFound),
+        }
+
+        let mut blocked = self.state.blocked_followers.get(&strategy_id).await?
+            .unwrap_or_default();
+        if !blocked.contains(&follower) {
+            blocked.push(follower);
+            self.state.blocked_followers.insert(&strategy_id, blocked)?;
+        }
+
+        // Unfollowing also drops a waitlisted request
+        let key = FollowerKey { strategy_id, follower };
+        let record = self.state.followers.get(&key).await?;
+        if let Err(error @ AgentHubError::Internal(_)) = self.unfollow_strategy(follower, strategy_id).await {
+            return Err(error);
+        }
+
+        let chain_id = self.runtime.chain_id();
+        if let Some(record) = record.filter(|record| record.chain_id != chain_id) {
+            let mut remote = self.state.remote_followers.get(&strategy_id).await?
+                .unwrap_or_default();
+            remote.retain(|account| *account != follower);
+            self.state.remote_followers.insert(&strategy_id, remote)?;
+
+            self.runtime.prepare_message(Message::FollowRevoked {
+                strategy: GlobalId::new(chain_id, strategy_id),
+                follower,
+            }.into()).send_to(record.chain_id);
+        }
+
+        Ok(AgentHubResponse::FollowerBlocked { strategy_id, follower })
+    }
+
+    /// Lift a block so the account can follow the strategy again
+    async fn unblock_follower(
+        &mut self,
+        owner: AccountOwner,
+        strategy_id: u64,
+        follower: AccountOwner,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        match self.state.strategies.get(&strategy_id).await? {
+            Some(strategy) if strategy.owner == owner => {}
+            Some(_) => return Err(AgentHubError::NotAuthorized),
+            _ => return Err(AgentHubError::StrategyNotFound),
+        }
+
+        let mut blocked = self.state.blocked_followers.get(&strategy_id).await?
+            .unwrap_or_default();
+        blocked.retain(|account| *account != follower);
+        self.state.blocked_followers.insert(&strategy_id, blocked)?;
+
+        Ok(AgentHubResponse::FollowerUnblocked { strategy_id, follower })
+    }
+
+    /// Invite an account to a private strategy, or revoke its invitation
+    async fn set_invited(
+        &mut self,
+        owner: AccountOwner,
+        strategy_id: u64,
+        invitee: AccountOwner,
+        invited: bool,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        match self.state.strategies.get(&strategy_id).await? {
+            Some(strategy) if strategy.owner == owner => {}
+            Some(_) => return Err(AgentHubError::NotAuthorized),
+            _ => return Err(AgentHubError::StrategyNotFound),
+        }
+
+        let mut invitees = self.state.strategy_invites.get(&strategy_id).await?
+            .unwrap_or_default();
+        invitees.retain(|account| *account != invitee);
+        if invited {
+            invitees.push(invitee);
+        }
+        self.state.strategy_invites.insert(&strategy_id, invitees)?;
+
+        if invited {
+            Ok(AgentHubResponse::FollowerInvited { strategy_id, invitee })
+        } else {
+            Ok(AgentHubResponse::InviteRevoked { strategy_id, invitee })
+        }
+    }
+
+    /// Pause or resume a strategy's signal publication and announce it on the strategist's stream
+    async fn set_strategy_paused(
+        &mut self,
+        owner: AccountOwner,
+        strategy_id: u64,
+        paused: bool,
+        reason: Option<String>,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        let mut strategy = match self.state.strategies.get(&strategy_id).await? {
+            Some(s) => s,
+            _ => return Err(AgentHubError::StrategyNotFound),
+        };
+        if strategy.owner != owner {
+            return Err(AgentHubError::NotAuthorized);
+        }
+
+        let changed = strategy.is_paused != paused;
+        strategy.is_paused = paused;
+        strategy.pause_reason = reason.clone();
+        let (global_id, stream) = (strategy.global_id, strategist_stream(&strategy.owner));
+        self.state.strategies.insert(&strategy_id, strategy)?;
+        self.record_change(ChangeEntity::Strategy, strategy_id, ChangeKind::Updated);
+
+        // Followers and subscribers listen on the strategist's stream
+        if changed {
+            let event = if paused {
+                AgentHubEvent::StrategyPaused { strategy: global_id, strategist: owner, reason }
+            } else {
+                AgentHubEvent::StrategyResumed { strategy: global_id, strategist: owner }
+            };
+            self.runtime.emit(stream, &event);
+        }
+
+        if paused {
+            Ok(AgentHubResponse::StrategyPaused { strategy_id })
+        } else {
+            Ok(AgentHubResponse::StrategyResumed { strategy_id })
+        }
+    }
+
+    /// Pay a performance-fee allowance into escrow on the strategy's chain
+    async fn fund_performance_fees(
+        &mut self,
+        follower: AccountOwner,
+        strategy_id: u64,
+        strategy_chain_id: String,
+        amount: Amount,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        let target_chain = match strategy_chain_id.parse::<ChainId>() {
+            Ok(chain_id) => chain_id,
+            Err(_) => return Err(AgentHubError::InvalidChainId),
+        };
+
+        let escrow_account = self.escrow_account(target_chain);
+        self.runtime.transfer(follower, escrow_account, amount);
+
+        if target_chain == self.runtime.chain_id() {
+            self.credit_fee_allowance(strategy_id, follower, amount).await?;
+        } else {
+            self.runtime.prepare_message(Message::PerformanceFeesFunded {
+                strategy_id,
+                follower,
+                amount,
+            }.into())
+            .with_authentication()
+            .send_to(target_chain);
+        }
+
+        Ok(AgentHubResponse::PerformanceFeesFunded {
+            strategy: GlobalId::new(target_chain, strategy_id),
+            amount,
+        })
+    }
+
+    /// Add to a follower's performance-fee allowance on the strategy's chain
+    async fn credit_fee_allowance(
+        &mut self,
+        strategy_id: u64,
+        follower: AccountOwner,
+        amount: Amount,
+    ) -> Result<(), ViewError> {
+        let key = FollowerKey { strategy_id, follower };
+        let allowance = self.state.fee_allowances.get(&key).await?.unwrap_or_default();
+        self.state.fee_allowances.insert(&key, allowance.saturating_add(amount))?;
+        Ok(())
     }
 
-    /// Resolve an open signal with the final value
-    async fn resolve_signal(
+    /// Get back an unused fee allowance, here or from the strategy's chain
+    async fn withdraw_fee_allowance(
         &mut self,
-        signal_id: u64,
-        resolved_value: u64,
-    ) -> AgentHubResponse {
-        // Get signal
-        let mut signal = match self.state.signals.get(&signal_id).await {
-            Ok(Some(s)) => s,
-            _ => return AgentHubError::SignalNotFound.into(),
+        follower: AccountOwner,
+        strategy_id: u64,
+        strategy_chain_id: String,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        let target_chain = match strategy_chain_id.parse::<ChainId>() {
+            Ok(chain_id) => chain_id,
+            Err(_) => return Err(AgentHubError::InvalidChainId),
         };
+        let strategy = GlobalId::new(target_chain, strategy_id);
 
-        // Check signal is open
-        if signal.status != SignalStatus::Open {
-            return AgentHubError::SignalAlreadyResolved.into();
+        if target_chain != self.runtime.chain_id() {
+            self.runtime.prepare_message(Message::FeeAllowanceWithdrawal {
+                strategy_id,
+                follower,
+            }.into())
+            .with_authentication()
+            .send_to(target_chain);
+            return Ok(AgentHubResponse::FeeAllowanceWithdrawalRequested { strategy });
         }
 
-        // Calculate result and PnL
-        let (result, pnl_bps) = self.calculate_signal_result(&signal, resolved_value);
+        let destination = Account { chain_id: target_chain, owner: follower };
+        match self.refund_fee_allowance(strategy_id, follower, destination).await {
+            Ok(amount) => Ok(AgentHubResponse::FeeAllowanceWithdrawn { strategy, amount }),
+            Err(error) => Err(error),
+        }
+    }
 
-        // Update signal
-        signal.status = SignalStatus::Resolved;
-        signal.result = Some(result);
-        signal.pnl_bps = Some(pnl_bps);
-        signal.resolved_value = Some(resolved_value);
+    /// Pay a former follower's whole remaining fee allowance out of escrow. Current followers
+    /// must unfollow first, so an allowance cannot be pulled just before a fee is charged.
+    async fn refund_fee_allowance(
+        &mut self,
+        strategy_id: u64,
+        follower: AccountOwner,
+        destination: Account,
+    ) -> Result<Amount, AgentHubError> {
+        let key = FollowerKey { strategy_id, follower };
+        if self.state.followers.contains_key(&key).await? {
+            return Err(AgentHubError::StillFollowing);
+        }
+        let allowance = self.state.fee_allowances.get(&key).await?.unwrap_or_default();
+        if allowance == Amount::ZERO {
+            return Err(AgentHubError::NoFeeAllowance);
+        }
+        self.state.fee_allowances.remove(&key)?;
+        self.pay_from_app_account(destination, allowance);
+        Ok(allowance)
+    }
 
-        let strategy_id = signal.strategy_id;
-        self.state.signals.insert(&signal_id, signal)
-            .expect("Failed to update signal");
+    /// Move the performance fee on a profitable signal from the allowance of each owner of a
+    /// position on it to the strategist. Position units count as whole tokens.
+    async fn accrue_performance_fees(&mut self, strategy: &AgentStrategy, positions: &[Position], pnl_bps: i64) -> Result<(), ViewError> {
+        if pnl_bps <= 0 || strategy.performance_fee_bps == 0 {
+            return Ok(());
+        }
+        let pnl_bps = pnl_bps as u128;
+        let fee_bps = strategy.performance_fee_bps as u128;
 
-        // Update strategy stats
-        let _ = self.update_strategy_stats(strategy_id).await;
+        let mut accrued = Amount::ZERO;
+        for position in positions.iter().filter(|position| !position.simulated) {
+            let key = FollowerKey { strategy_id: strategy.id, follower: position.owner };
+            let allowance = self.state.fee_allowances.get(&key).await?
+                .unwrap_or_default();
+            if allowance == Amount::ZERO {
+                continue;
+            }
 
-        // Emit event for cross-chain subscribers
-        let stream = StreamName::from(b"signals");
-        self.runtime.emit(stream, &AgentHubEvent::SignalResolved {
-            strategy_id,
-            signal_id,
-            result,
-            pnl_bps,
-        });
+            let exposure = Amount::from_tokens(position.size_units as u128).to_attos();
+            let profit = exposure / 10000 * pnl_bps + exposure % 10000 * pnl_bps / 10000;
+            let fee = Amount::from_attos(profit / 10000 * fee_bps + profit % 10000 * fee_bps / 10000);
+            let charged = fee.min(allowance);
+            self.state.fee_allowances.insert(&key, allowance.saturating_sub(charged))?;
+            accrued = accrued.saturating_add(charged);
+        }
 
-        AgentHubResponse::SignalResolved {
-            id: signal_id,
-            result,
-            pnl_bps,
+        if accrued > Amount::ZERO {
+            let balance = self.state.performance_fees.get(&strategy.owner).await?
+                .unwrap_or_default();
+            self.state.performance_fees.insert(&strategy.owner, balance.saturating_add(accrued))?;
         }
+        Ok(())
     }
 
-    /// Calculate signal result based on direction and price movement
-    fn calculate_signal_result(&self, signal: &Signal, resolved_value: u64) -> (SignalResult, i64) {
-        let entry = signal.entry_value.unwrap_or(0);
-        
-        if entry == 0 || resolved_value == 0 {
-            return (SignalResult::Push, 0);
+    /// Pay out a strategist's accrued performance fees, less the protocol fee
+    async fn claim_performance_fees(&mut self, owner: AccountOwner) -> Result<AgentHubResponse, AgentHubError> {
+        let amount = self.state.performance_fees.get(&owner).await?
+            .unwrap_or_default();
+        if amount == Amount::ZERO {
+            return Err(AgentHubError::NoPerformanceFees);
         }
+        self.state.performance_fees.remove(&owner)?;
 
-        // Calculate PnL in basis points
-        let pnl_bps = ((resolved_value as i64 - entry as i64) * 10000) / entry as i64;
-
-        // Determine result based on direction
-        let result = match signal.direction {
-            Direction::Up | Direction::Over | Direction::Yes => {
-                if resolved_value > entry {
-                    SignalResult::Win
-                } else if resolved_value < entry {
-                    SignalResult::Lose
-                } else {
-                    SignalResult::Push
-                }
-            }
-            Direction::Down | Direction::Under | Direction::No => {
-                if resolved_value < entry {
-                    SignalResult::Win
-                } else if resolved_value > entry {
-                    SignalResult::Lose
-                } else {
-                    SignalResult::Push
-                }
-            }
-        };
+        let fee = self.protocol_fee(amount);
+        let referral_share = self.pay_referral_share(owner, fee).await?;
+        self.credit_treasury(fee.saturating_sub(referral_share));
 
-        // Adjust PnL sign based on direction (for DOWN, negative price move = positive PnL)
-        let adjusted_pnl = match signal.direction {
-            Direction::Down | Direction::Under | Direction::No => -pnl_bps,
-            _ => pnl_bps,
-        };
+        let chain_id = self.runtime.chain_id();
+        self.pay_from_app_account(Account { chain_id, owner }, amount.saturating_sub(fee));
 
-        (result, adjusted_pnl)
+        Ok(AgentHubResponse::PerformanceFeesClaimed { amount })
     }
 
-    /// Cancel an open signal
-    async fn cancel_signal(&mut self, owner: AccountOwner, signal_id: u64) -> AgentHubResponse {
-        // Get signal
-        let mut signal = match self.state.signals.get(&signal_id).await {
-            Ok(Some(s)) => s,
-            _ => return AgentHubError::SignalNotFound.into(),
-        };
+    /// Handle an incoming subscription request on the strategist's chain.
+    ///
+    /// Requests may be delivered more than once; the subscription ID is derived from the
+    /// (subscriber, strategist) pair, so a retry re-confirms the existing active subscription
+    /// instead of creating a duplicate. Refused or duplicate payments go back to `refund_account`,
+    /// which is the gifter for gifted subscriptions.
+    #[allow(clippy::too_many_arguments)]
+    async fn handle_subscription_request(
+        &mut self,
+        subscriber: AccountOwner,
+        subscriber_chain_id: ChainId,
+        strategist: AccountOwner,
+        timestamp: Timestamp,
+        payment: Amount,
+        token: Option<ApplicationId>,
+        refund_account: Account,
+    ) -> Result<(), ViewError> {
+        let subscription_id = subscription_id(&subscriber, &strategist);
+        let chain_id = self.runtime.chain_id();
 
-        // Check authorization
-        let strategy = match self.state.strategies.get(&signal.strategy_id).await {
-            Ok(Some(s)) => s,
-            _ => return AgentHubError::StrategyNotFound.into(),
+        // The strategist must be offering subscriptions at or below the paid price, in the
+        // token the payment was made in. Unpaid first-time requests start a free trial if the
+        // offer has one.
+        let offer = self.state.subscription_offers.get(&strategist).await?;
+        let trial_claimed = self.state.trials_claimed.contains_key(&subscription_id).await?;
+        let Some(offer) = offer.filter(|offer| offer.is_enabled) else {
+            self.pay_tokens_from_app_account(token, refund_account, payment);
+            return Ok(());
         };
-
-        if strategy.owner != owner {
-            return AgentHubError::NotAuthorized.into();
-        }
-
-        // Check signal is open
-        if signal.status != SignalStatus::Open {
-            return AgentHubError::SignalNotOpen.into();
+        let paid = offer.token == token && payment >= offer.price;
+        let trial = payment == Amount::ZERO
+            && offer.price > Amount::ZERO
+            && offer.trial_days > 0
+            && !trial_claimed;
+        if !paid && !trial {
+            self.pay_tokens_from_app_account(token, refund_account, payment);
+            return Ok(());
         }
 
-        // Cancel signal
-        signal.status = SignalStatus::Cancelled;
-        self.state.signals.insert(&signal_id, signal)
-            .expect("Failed to update signal");
+        // An expired subscription is renewed like a new one
+        let previous = self.state.subscriptions.get(&subscription_id).await?;
+        let renewed = previous.is_some();
+        let now = self.now();
+        let existing = previous.filter(|sub| sub.grants_access(now));
+        let subscription = match existing {
+            // Duplicate request for an active subscription, or an unpaid one during a trial:
+            // re-confirm it unchanged and return any payment that came with the retry
+            Some(existing) if !(existing.is_trial && paid) => {
+                self.pay_tokens_from_app_account(token, refund_account, payment);
+                existing
+            }
+            existing => {
+                // Paying during a trial converts it, with the paid period starting when the trial ends
+                let converted_trial = existing.is_some();
+                let start_timestamp = existing
+                    .map_or(timestamp, |trial| trial.end_timestamp.max(timestamp));
+                let period_micros = if paid { offer.period_micros() } else { offer.trial_micros() };
+                let subscription = Subscription {
+                    id: subscription_id.clone(),
+                    subscriber,
+                    subscriber_chain_id,
+                    strategist,
+                    strategist_chain_id: chain_id,
+                    start_timestamp,
+                    end_timestamp: start_timestamp
+                        .saturating_add(TimeDelta::from_micros(period_micros)),
+                    is_active: true,
+                    is_trial: !paid,
+                };
 
-        AgentHubResponse::SignalCancelled { id: signal_id }
-    }
+                // Store subscription
+                self.state.subscriptions.insert(&subscription_id, subscription.clone())?;
+                let change = if renewed { ChangeKind::Updated } else { ChangeKind::Created };
+                self.record_change(ChangeEntity::Subscription, &subscription_id, change);
+                if !paid {
+                    self.state.trials_claimed.insert(&subscription_id, timestamp)?;
+                }
 
-    /// Follow a strategy
-    async fn follow_strategy(
-        &mut self,
-        follower_owner: AccountOwner,
-        strategy_id: u64,
-        auto_copy: bool,
-        max_exposure_units: u64,
-    ) -> AgentHubResponse {
-        // Check strategy exists
-        if !self.state.strategies.contains_key(&strategy_id).await.unwrap_or(false) {
-            return AgentHubError::StrategyNotFound.into();
-        }
+                // Hold the payment in escrow for the subscription period
+                if payment > Amount::ZERO {
+                    self.release_vested(&subscription_id).await?;
+                    let escrow = SubscriptionEscrow {
+                        subscription_id: subscription_id.clone(),
+                        subscriber,
+                        subscriber_chain_id,
+                        strategist,
+                        amount: payment,
+                        released: Amount::ZERO,
+                        refunded: Amount::ZERO,
+                        start_timestamp: subscription.start_timestamp,
+                        end_timestamp: subscription.end_timestamp,
+                        token,
+                    };
+                    self.state.escrows.insert(&subscription_id, escrow)?;
+                }
 
-        let key = FollowerKey { strategy_id, follower: follower_owner.clone() };
+                // Add to strategist's subscribers list
+                self.state.subscribers_by_strategist.load_entry_mut(&strategist).await?
+                    .insert(&subscription_id)?;
 
-        // Check not already following
-        if self.state.followers.contains_key(&key).await.unwrap_or(false) {
-            return AgentHubError::AlreadyFollowing.into();
-        }
+                // Emit event for subscription created
+                if !converted_trial {
+                    let stream = StreamName::from(b"subscriptions");
+                    self.runtime.emit(stream, &AgentHubEvent::SubscriptionCreated {
+                        subscription_id: subscription_id.clone(),
+                        subscriber,
+                        strategist,
+                    });
+                }
 
-        let follower = Follower {
-            strategy_id,
-            follower: follower_owner,
-            auto_copy,
-            max_exposure_units,
-            created_at: self.now(),
+                subscription
+            }
         };
 
-        self.state.followers.insert(&key, follower)
-            .expect("Failed to insert follower");
+        // Send confirmation back to subscriber's chain
+        self.runtime.prepare_message(Message::SubscriptionConfirmed {
+            subscription_id,
+            subscriber,
+            strategist,
+            strategist_chain_id: chain_id,
+            start_timestamp: subscription.start_timestamp,
+            end_timestamp: subscription.end_timestamp,
+            is_trial: subscription.is_trial,
+        }.into()).send_to(subscription.subscriber_chain_id);
+        Ok(())
+    }
 
-        // Increment follower count
-        let count = self.state.follower_count.get(&strategy_id).await
-            .ok().flatten().unwrap_or(0);
-        self.state.follower_count.insert(&strategy_id, count + 1)
-            .expect("Failed to update follower count");
+    /// Cancel a subscription early: stop it locally and ask the strategist's chain for a refund
+    async fn cancel_subscription_early(
+        &mut self,
+        subscriber: AccountOwner,
+        strategist: AccountOwner,
+    ) -> Result<AgentHubResponse, AgentHubError> {
+        let subscription_id = subscription_id(&subscriber, &strategist);
+        let strategist_chain = match self.state.subscriptions.get(&subscription_id).await? {
+            Some(sub) if sub.is_active => sub.strategist_chain_id,
+            _ => return Err(AgentHubError::NotSubscribed),
+        };
 
-        // Update stats
-        let mut stats = self.state.strategy_stats.get(&strategy_id).await
-            .ok().flatten().unwrap_or_default();
-        stats.followers = count + 1;
-        self.state.strategy_stats.insert(&strategy_id, stats)
-            .expect("Failed to update stats");
+        let response = self.unsubscribe_from_strategist(subscriber, strategist).await;
+        if !matches!(response, Ok(AgentHubResponse::Unsubscribed { .. })) {
+            return response;
+        }
 
-        // Emit event for follow
-        let stream = StreamName::from(b"follows");
-        self.runtime.emit(stream, &AgentHubEvent::StrategyFollowed {
-            strategy_id,
-            follower: follower_owner,
-        });
+        self.runtime.prepare_message(Message::CancelSubscriptionRequest {
+            subscriber,
+            strategist,
+        }.into())
+        .with_authentication()
+        .send_to(strategist_chain);
 
-        AgentHubResponse::Followed { strategy_id }
+        Ok(AgentHubResponse::SubscriptionCancelledEarly { strategist })
     }
 
-    /// Unfollow a strategy
-    async fn unfollow_strategy(&mut self, follower_owner: AccountOwner, strategy_id: u64) -> AgentHubResponse {
-        let key = FollowerKey { strategy_id, follower: follower_owner };
-
-        // Check following
-        if !self.state.followers.contains_key(&key).await.unwrap_or(false) {
-            return AgentHubError::NotFollowing.into();
-        }
+    /// Handle an early cancellation on the strategist's chain: settle the vested part and
+    /// refund the rest of the escrow to the subscriber
+    async fn handle_cancel_subscription(
+        &mut self,
+        subscriber: AccountOwner,
+        strategist: AccountOwner,
+    ) -> Result<(), ViewError> {
+        let subscription_id = subscription_id(&subscriber, &strategist);
+        let mut subscription = match self.state.subscriptions.get(&subscription_id).await? {
+            Some(sub) if sub.is_active => sub,
+            _ => return Ok(()),
+        };
 
-        self.state.followers.remove(&key).expect("Failed to remove follower");
+        self.release_vested(&subscription_id).await?;
 
-        // Decrement follower count
-        let count = self.state.follower_count.get(&strategy_id).await
-            .ok().flatten().unwrap_or(1);
-        let new_count = count.saturating_sub(1);
-        self.state.follower_count.insert(&strategy_id, new_count)
-            .expect("Failed to update follower count");
+        if let Some(mut escrow) = self.state.escrows.get(&subscription_id).await? {
+            let refund = escrow.remaining();
+            escrow.refunded = escrow.refunded.saturating_add(refund);
+            let refund_account = Account {
+                chain_id: escrow.subscriber_chain_id,
+                owner: escrow.subscriber,
+            };
+            self.pay_tokens_from_app_account(escrow.token, refund_account, refund);
+            self.state.escrows.insert(&subscription_id, escrow)?;
+        }
 
-        // Update stats
-        let mut stats = self.state.strategy_stats.get(&strategy_id).await
-            .ok().flatten().unwrap_or_default();
-        stats.followers = new_count;
-        self.state.strategy_stats.insert(&strategy_id, stats)
-            .expect("Failed to update stats");
+        subscription.is_active = false;
+        self.state.subscriptions.insert(&subscription_id, subscription)?;
+        self.record_change(ChangeEntity::Subscription, &subscription_id, ChangeKind::Updated);
 
-        // Emit event for unfollow
-        let stream = StreamName::from(b"follows");
-        self.runtime.emit(stream, &AgentHubEvent::StrategyUnfollowed {
-            strategy_id,
-            follower: follower_owner,
+        let stream = StreamName::from(b"subscriptions");
+        self.runtime.emit(stream, &AgentHubEvent::SubscriptionCancelled {
+            subscription_id,
+            subscriber,
+            strategist,
         });
-
-        AgentHubResponse::Unfollowed { strategy_id }
+        Ok(())
     }
 
-    /// Update strategy statistics based on all signals
-    async fn update_strategy_stats(&mut self, strategy_id: u64) -> AgentHubResponse {
-        let signal_ids = self.state.signals_by_strategy.get(&strategy_id).await
-            .ok().flatten().unwrap_or_default();
+    /// Release the vested part of an escrow to the strategist
+    async fn release_escrow(&mut self, subscription_id: String) -> Result<AgentHubResponse, AgentHubError> {
+        if !self.state.escrows.contains_key(&subscription_id).await? {
+            return Err(AgentHubError::EscrowNotFound);
+        }
 
-        let mut total_signals = 0u64;
-        let mut winning_signals = 0u64;
-        let mut losing_signals = 0u64;
-        let mut push_signals = 0u64;
-        let mut total_pnl: i64 = 0;
+        let amount = self.release_vested(&subscription_id).await?;
+        Ok(AgentHubResponse::EscrowReleased { subscription_id, amount })
+    }
 
-        for signal_id in signal_ids {
-            if let Ok(Some(signal)) = self.state.signals.get(&signal_id).await {
-                if signal.status == SignalStatus::Resolved {
-                    total_signals += 1;
-                    total_pnl += signal.pnl_bps.unwrap_or(0);
+    /// Pay out whatever has vested but not yet been released, returning the amount paid
+    async fn release_vested(&mut self, subscription_id: &String) -> Result<Amount, ViewError> {
+        let Some(mut escrow) = self.state.escrows.get(subscription_id).await? else {
+            return Ok(Amount::ZERO);
+        };
 
-                    match signal.result {
-                        Some(SignalResult::Win) => winning_signals += 1,
-                        Some(SignalResult::Lose) => losing_signals += 1,
-                        Some(SignalResult::Push) => push_signals += 1,
-                        None => {}
-                    }
-                }
-            }
+        let now = self.now();
+        let vested = escrow.vested(now).min(escrow.amount.saturating_sub(escrow.refunded));
+        let amount = vested.saturating_sub(escrow.released);
+        if amount == Amount::ZERO {
+            return Ok(Amount::ZERO);
         }
 
-        let win_rate_bps = if total_signals > 0 {
-            ((winning_signals as u64 * 10000) / total_signals) as u32
-        } else {
-            0
-        };
-
-        let avg_pnl_bps = if total_signals > 0 {
-            (total_pnl / total_signals as i64) as i32
-        } else {
-            0
-        };
+        escrow.released = escrow.released.saturating_add(amount);
 
-        let followers = self.state.follower_count.get(&strategy_id).await
-            .ok().flatten().unwrap_or(0);
+        // Keep the protocol fee (less any referral share) in the treasury and pay the rest to the
+        // strategist. The treasury only holds native tokens, so fungible-token escrows pay out in full.
+        let fee = if escrow.token.is_none() { self.protocol_fee(amount) } else { Amount::ZERO };
+        let referral_share = self.pay_referral_share(escrow.strategist, fee).await?;
+        self.credit_treasury(fee.saturating_sub(referral_share));
 
-        let stats = StrategyStats {
-            strategy_id,
-            total_signals,
-            winning_signals,
-            losing_signals,
-            push_signals,
-            win_rate_bps,
-            avg_pnl_bps,
-            total_pnl_bps: total_pnl,
-            followers,
-        };
+        let chain_id = self.runtime.chain_id();
+        let strategist_account = Account { chain_id, owner: escrow.strategist };
+        self.pay_tokens_from_app_account(escrow.token, strategist_account, amount.saturating_sub(fee));
+        self.state.escrows.insert(subscription_id, escrow)?;
 
-        self.state.strategy_stats.insert(&strategy_id, stats)
-            .expect("Failed to update stats");
+        Ok(amount)
+    }
 
-        AgentHubResponse::Ok
+    /// Protocol fee owed on a payment, per the configured basis points
+    fn protocol_fee(&mut self, amount: Amount) -> Amount {
+        let fee_bps = self.state.protocol_fee_bps.get()
+            .unwrap_or_else(|| self.runtime.application_parameters().protocol_fee_bps)
+            .min(10000);
+        Amount::from_attos(amount.to_attos() / 10000 * fee_bps as u128
+            + amount.to_attos() % 10000 * fee_bps as u128 / 10000)
     }
 
-    // =========================================================================
-    // Subscription Methods
-    // =========================================================================
+    /// Pay the strategist's referrer their share of a protocol fee, returning the amount paid
+    async fn pay_referral_share(&mut self, strategist: AccountOwner, fee: Amount) -> Result<Amount, ViewError> {
+        const REFERRAL_SHARE_BPS: u128 = 2000;
 
-    /// Enable subscription for this strategist
-    async fn enable_subscription(
-        &mut self,
-        owner: AccountOwner,
-        description: Option<String>,
-    ) -> AgentHubResponse {
-        // Check if strategist is registered
-        if !self.state.strategists.contains_key(&owner).await.unwrap_or(false) {
-            return AgentHubError::StrategistNotRegistered.into();
+        let Some(agent_hub::Strategist { referrer: Some(referrer), .. }) =
+            self.state.strategists.get(&strategist).await?
+        else {
+            return Ok(Amount::ZERO);
+        };
+
+        let share = Amount::from_attos(fee.to_attos() / 10000 * REFERRAL_SHARE_BPS
+            + fee.to_attos() % 10000 * REFERRAL_SHARE_BPS / 10000);
+        if share == Amount::ZERO {
+            return Ok(Amount::ZERO);
         }
 
-        let offer = SubscriptionOffer {
-            strategist: owner.clone(),
-            description,
-            is_enabled: true,
-        };
+        let chain_id = self.runtime.chain_id();
+        self.pay_from_app_account(Account { chain_id, owner: referrer }, share);
+        let earned = self.state.referral_earnings.get(&referrer).await?
+            .unwrap_or_default();
+        self.state.referral_earnings.insert(&referrer, earned.saturating_add(share))?;
 
-        self.state.subscription_offers.insert(&owner, offer)
-            .expect("Failed to enable subscription");
+        Ok(share)
+    }
 
-        AgentHubResponse::SubscriptionEnabled { strategist: owner }
+    /// Add collected fees to the treasury balance
+    fn credit_treasury(&mut self, fee: Amount) {
+        let balance = self.state.treasury_balance.get().saturating_add(fee);
+        self.state.treasury_balance.set(balance);
     }
 
-    /// Disable subscription for this strategist
-    async fn disable_subscription(&mut self, owner: AccountOwner) -> AgentHubResponse {
-        // Check if subscription offer exists
-        if let Ok(Some(mut offer)) = self.state.subscription_offers.get(&owner).await {
-            offer.is_enabled = false;
-            self.state.subscription_offers.insert(&owner, offer)
-                .expect("Failed to disable subscription");
+    /// The application's escrow account on the given chain
+    fn escrow_account(&mut self, chain_id: ChainId) -> Account {
+        Account {
+            chain_id,
+            owner: AccountOwner::from(self.runtime.application_id()),
         }
+    }
 
-        AgentHubResponse::SubscriptionDisabled { strategist: owner }
+    /// Transfer tokens out of the application's account on this chain (escrow and treasury funds)
+    fn pay_from_app_account(&mut self, destination: Account, amount: Amount) {
+        self.pay_tokens_from_app_account(None, destination, amount);
     }
 
-    /// Subscribe to a strategist (sends cross-chain message)
-    async fn subscribe_to_strategist(
+    /// Like `pay_from_app_account`, but in the given fungible token (native tokens if unset)
+    fn pay_tokens_from_app_account(
         &mut self,
-        subscriber: AccountOwner,
-        strategist: AccountOwner,
-        strategist_chain_id: String,
-    ) -> AgentHubResponse {
-        // Check if already subscribed
-        let existing_subs = self.state.subscriptions_by_subscriber.get(&subscriber).await
-            .ok().flatten().unwrap_or_default();
-        
-        for sub_id in &existing_subs {
-            if let Ok(Some(sub)) = self.state.subscriptions.get(sub_id).await {
-                if sub.strategist == strategist && sub.is_active {
-                    return AgentHubError::AlreadySubscribed.into();
-                }
+        token: Option<ApplicationId>,
+        destination: Account,
+        amount: Amount,
+    ) {
+        if amount == Amount::ZERO {
+            return;
+        }
+        let escrow_owner = AccountOwner::from(self.runtime.application_id());
+        self.transfer_tokens(token, escrow_owner, destination, amount);
+    }
+
+    /// Transfer native tokens, or tokens of a fungible token application, from `source`
+    fn transfer_tokens(
+        &mut self,
+        token: Option<ApplicationId>,
+        source: AccountOwner,
+        destination: Account,
+        amount: Amount,
+    ) {
+        match token {
+            None => self.runtime.transfer(source, destination, amount),
+            Some(token) => {
+                self.runtime.call_application(
+                    true,
+                    token.with_abi::<FungibleTokenAbi>(),
+                    &FungibleOperation::Transfer {
+                        owner: source,
+                        amount,
+                        target_account: fungible::Account {
+                            chain_id: destination.chain_id,
+                            owner: destination.owner,
+                        },
+                    },
+                );
             }
         }
+    }
 
-        let timestamp = self.now().micros();
-        let subscriber_chain_id = self.runtime.chain_id().to_string();
+    /// Handle a subscription confirmation on the subscriber's chain (idempotent)
+    async fn handle_subscription_confirmed(&mut self, subscription: Subscription) -> Result<(), ViewError> {
+        let subscription_id = subscription.id.clone();
+        let subscriber = subscription.subscriber;
 
-        // Send subscription request to strategist's chain
-        if let Ok(target_chain) = strategist_chain_id.parse::<ChainId>() {
-            self.runtime.prepare_message(Message::SubscriptionRequest {
-                subscriber: subscriber.clone(),
-                subscriber_chain_id,
-                strategist: strategist.clone(),
-                timestamp,
-            }).send_to(target_chain);
+        let was_active = matches!(
+            self.state.subscriptions.get(&subscription_id).await?,
+            Some(existing) if existing.is_active
+        );
+
+        // Store subscription locally
+        self.state.subscriptions.insert(&subscription_id, subscription.clone())?;
+        let change = if was_active { ChangeKind::Updated } else { ChangeKind::Created };
+        self.record_change(ChangeEntity::Subscription, &subscription_id, change);
+
+        // Add to subscriber's subscriptions list
+        let mut subs = self.state.subscriptions_by_subscriber.get(&subscriber).await?.unwrap_or_default();
+        if !subs.contains(&subscription_id) {
+            subs.push(subscription_id);
+            self.state.subscriptions_by_subscriber.insert(&subscriber, subs)?;
         }
+        self.state.local_subscribers.load_entry_mut(&subscription.strategist).await?.insert(&subscriber)?;
 
-        // Return pending status - actual subscription is created when confirmation arrives
-        AgentHubResponse::Subscribed { 
-            subscription_id: format!("pending-{}", timestamp) 
+        // Start receiving the strategist's signals
+        if !was_active {
+            self.state.global_counters.get_mut().active_subscriptions += 1;
+            self.listen_to_strategist(subscription.strategist_chain_id, subscription.strategist)
+                .await?;
         }
+        Ok(())
     }
 
     /// Unsubscribe from a strategist
@@ -740,15 +5464,15 @@ impl AgentHubContract {
         &mut self,
         subscriber: AccountOwner,
         strategist: AccountOwner,
-    ) -> AgentHubResponse {
+    ) -> Result<AgentHubResponse, AgentHubError> {
         // Find active subscription
-        let existing_subs = self.state.subscriptions_by_subscriber.get(&subscriber).await
-            .ok().flatten().unwrap_or_default();
+        let existing_subs = self.state.subscriptions_by_subscriber.get(&subscriber).await?
+            .unwrap_or_default();
         
         let mut found_sub_id: Option<String> = None;
         
         for sub_id in &existing_subs {
-            if let Ok(Some(sub)) = self.state.subscriptions.get(sub_id).await {
+            if let Some(sub) = self.state.subscriptions.get(sub_id).await? {
                 if sub.strategist == strategist && sub.is_active {
                     found_sub_id = Some(sub_id.clone());
                     break;
@@ -759,23 +5483,263 @@ impl AgentHubContract {
         match found_sub_id {
             Some(sub_id) => {
                 // Mark subscription as inactive
-                if let Ok(Some(mut sub)) = self.state.subscriptions.get(&sub_id).await {
+                if let Some(mut sub) = self.state.subscriptions.get(&sub_id).await? {
                     sub.is_active = false;
-                    self.state.subscriptions.insert(&sub_id, sub)
-                        .expect("Failed to update subscription");
+                    let strategist_chain = sub.strategist_chain_id;
+                    self.state.subscriptions.insert(&sub_id, sub)?;
+                    self.record_change(ChangeEntity::Subscription, &sub_id, ChangeKind::Updated);
+                    let counters = self.state.global_counters.get_mut();
+                    counters.active_subscriptions = counters.active_subscriptions.saturating_sub(1);
+
+                    // Stop receiving the strategist's signals
+                    self.stop_listening_to_strategist(strategist_chain, strategist).await?;
                 }
 
                 // Emit event for subscription cancelled
                 let stream = StreamName::from(b"subscriptions");
                 self.runtime.emit(stream, &AgentHubEvent::SubscriptionCancelled {
                     subscription_id: sub_id,
-                    subscriber: subscriber.clone(),
-                    strategist: strategist.clone(),
+                    subscriber,
+                    strategist,
                 });
 
-                AgentHubResponse::Unsubscribed { strategist }
+                Ok(AgentHubResponse::Unsubscribed { strategist })
             }
-            None => AgentHubError::NotSubscribed.into(),
+            None => Err(AgentHubError::NotSubscribed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use linera_sdk::util::BlockingWait;
+
+    use super::*;
+
+    const DAY: u64 = 24 * 60 * 60 * 1_000_000;
+
+    fn owner(name: &str) -> AccountOwner {
+        AccountOwner::from(CryptoHash::test_hash(name))
+    }
+
+    fn chain_id() -> ChainId {
+        ChainId(CryptoHash::test_hash("strategist chain"))
+    }
+
+    fn app_owner() -> AccountOwner {
+        AccountOwner::from(ApplicationId::new(CryptoHash::test_hash("agent hub")))
+    }
+
+    /// A freshly instantiated contract on `chain_id()` at `now`, with the given balances mocked
+    fn create_contract(
+        now: Timestamp,
+        balances: impl IntoIterator<Item = (AccountOwner, Amount)>,
+    ) -> AgentHubContract {
+        let parameters = AgentHubParameters {
+            min_horizon_secs: 60,
+            max_horizon_secs: 30 * 24 * 60 * 60,
+            max_open_signals_per_strategy: 0,
+            protocol_fee_bps: 250,
+            admin: owner("admin"),
+            price_oracle: None,
+            prediction_app: None,
+            deposit_token: None,
+            registration_deposit: Amount::ZERO,
+            deposit_refund_signals: 0,
+            resolution_grace_secs: 0,
+        };
+        let runtime = ContractRuntime::new()
+            .with_application_parameters(parameters)
+            .with_application_id(ApplicationId::new(CryptoHash::test_hash("agent hub")).with_abi())
+            .with_chain_id(chain_id())
+            .with_system_time(now)
+            .with_owner_balances(balances);
+        let mut contract = AgentHubContract::load(runtime).blocking_wait();
+        contract
+            .instantiate(InstantiationArgument { hub_chain_id: chain_id().to_string(), roles: Vec::new() })
+            .blocking_wait();
+        contract
+    }
+
+    fn execute_as(contract: &mut AgentHubContract, signer: AccountOwner, operation: Operation) -> AgentHubResponse {
+        contract.runtime.set_authenticated_signer(Some(signer));
+        contract.execute_operation(operation).blocking_wait()
+    }
+
+    #[test]
+    fn protocol_fee_rounds_down_without_overflow() {
+        let mut contract = create_contract(Timestamp::from(0), []);
+
+        // 250 bps of 9_999 attos is 249.975
+        assert_eq!(contract.protocol_fee(Amount::from_attos(9_999)), Amount::from_attos(249));
+        assert_eq!(contract.protocol_fee(Amount::from_attos(39)), Amount::ZERO);
+        assert_eq!(contract.protocol_fee(Amount::from_attos(40)), Amount::from_attos(1));
+        assert_eq!(contract.protocol_fee(Amount::MAX), Amount::from_attos(u128::MAX / 40));
+
+        // A governed rate overrides the parameter and is capped at the whole payment
+        contract.state.protocol_fee_bps.set(Some(20_000));
+        assert_eq!(contract.protocol_fee(Amount::from_attos(9_999)), Amount::from_attos(9_999));
+        assert_eq!(contract.protocol_fee(Amount::MAX), Amount::MAX);
+    }
+
+    /// Settle a tournament with entries scored `scores` and a prize pool of `pool` attos,
+    /// returning the prize each entry was paid
+    fn settle_tournament_with(scores: &[i64], pool: u128) -> (AgentHubContract, Vec<Amount>) {
+        let owners = (0..scores.len()).map(|place| owner(&format!("entrant {place}"))).collect::<Vec<_>>();
+        let balances = owners.iter().map(|owner| (*owner, Amount::ZERO))
+            .chain([(app_owner(), Amount::from_attos(pool))]);
+        let mut contract = create_contract(Timestamp::from(2 * DAY), balances);
+
+        contract.state.tournaments.insert(&1, Tournament {
+            id: 1,
+            name: "Cup".to_string(),
+            market_kind: None,
+            base_market: None,
+            start_timestamp: Timestamp::from(0),
+            end_timestamp: Timestamp::from(DAY),
+            prize_pool: Amount::from_attos(pool),
+            settled: false,
+            created_at: Timestamp::from(0),
+        }).unwrap();
+        for (strategy_id, (owner, score_bps)) in (1..).zip(owners.iter().zip(scores)) {
+            contract.state.tournament_entries.insert(&(1, strategy_id), TournamentEntry {
+                tournament_id: 1,
+                strategy_id,
+                owner: *owner,
+                resolved_signals: 1,
+                winning_signals: 1,
+                score_bps: *score_bps,
+                prize: Amount::ZERO,
+            }).unwrap();
+            contract.state.tournament_participants.load_entry_mut(&1).blocking_wait().unwrap()
+                .insert(&TournamentScoreKey { score_bps: *score_bps, strategy_id }).unwrap();
         }
+
+        let response = execute_as(&mut contract, owner("admin"), Operation::SettleTournament { tournament_id: 1 });
+        assert!(matches!(response, AgentHubResponse::TournamentSettled { .. }), "{response:?}");
+        let prizes = owners.iter().map(|owner| contract.runtime.owner_balance(*owner)).collect();
+        (contract, prizes)
+    }
+
+    #[test]
+    fn prize_split_pays_missing_places_to_the_winner() {
+        let (mut contract, prizes) = settle_tournament_with(&[100, 300], 1_000);
+        // Second place keeps its 30% share; the winner takes the rest
+        assert_eq!(prizes, [Amount::from_attos(300), Amount::from_attos(700)]);
+        assert_eq!(contract.runtime.owner_balance(app_owner()), Amount::ZERO);
+        let entry = contract.state.tournament_entries.get(&(1, 2)).blocking_wait().unwrap().unwrap();
+        assert_eq!(entry.prize, Amount::from_attos(700));
+
+        let (mut contract, prizes) = settle_tournament_with(&[50], 999);
+        assert_eq!(prizes, [Amount::from_attos(999)]);
+        assert_eq!(contract.runtime.owner_balance(app_owner()), Amount::ZERO);
+    }
+
+    #[test]
+    fn prize_split_rounds_down_and_leaves_no_dust() {
+        let (mut contract, prizes) = settle_tournament_with(&[3, 2, 1, 0], 1_001);
+        assert_eq!(
+            prizes,
+            [Amount::from_attos(501), Amount::from_attos(300), Amount::from_attos(200), Amount::ZERO],
+        );
+        assert_eq!(contract.runtime.owner_balance(app_owner()), Amount::ZERO);
+    }
+
+    #[test]
+    fn prize_pool_without_entries_goes_to_the_treasury() {
+        let (mut contract, _) = settle_tournament_with(&[], 1_000);
+        assert_eq!(*contract.state.treasury_balance.get(), Amount::from_attos(1_000));
+        assert_eq!(contract.runtime.owner_balance(app_owner()), Amount::from_attos(1_000));
+        assert!(contract.state.tournaments.get(&1).blocking_wait().unwrap().unwrap().settled);
+    }
+
+    #[test]
+    fn paying_during_a_trial_converts_it_from_the_trial_end() {
+        let start = Timestamp::from(DAY);
+        let mut contract = create_contract(start, []);
+        let strategist = owner("strategist");
+        let subscriber = owner("subscriber");
+        let subscriber_chain_id = ChainId(CryptoHash::test_hash("subscriber chain"));
+        let refund_account = Account { chain_id: subscriber_chain_id, owner: subscriber };
+        let id = subscription_id(&subscriber, &strategist);
+        contract.state.subscription_offers.insert(&strategist, SubscriptionOffer {
+            strategist,
+            description: None,
+            price: Amount::from_attos(100),
+            token: None,
+            duration_days: 30,
+            trial_days: 7,
+            is_enabled: true,
+        }).unwrap();
+
+        // An unpaid first request starts the trial
+        contract.handle_subscription_request(
+            subscriber, subscriber_chain_id, strategist, start, Amount::ZERO, None, refund_account,
+        ).blocking_wait().unwrap();
+        let trial = contract.state.subscriptions.get(&id).blocking_wait().unwrap().unwrap();
+        assert!(trial.is_trial);
+        assert_eq!(trial.end_timestamp, Timestamp::from(8 * DAY));
+        assert!(contract.state.escrows.get(&id).blocking_wait().unwrap().is_none());
+
+        // Paying two days in converts it, with the paid period starting when the trial ends
+        let paid_at = Timestamp::from(3 * DAY);
+        contract.runtime.set_system_time(paid_at);
+        contract.handle_subscription_request(
+            subscriber, subscriber_chain_id, strategist, paid_at, Amount::from_attos(100), None, refund_account,
+        ).blocking_wait().unwrap();
+        let paid = contract.state.subscriptions.get(&id).blocking_wait().unwrap().unwrap();
+        assert!(!paid.is_trial);
+        assert_eq!(paid.start_timestamp, Timestamp::from(8 * DAY));
+        assert_eq!(paid.end_timestamp, Timestamp::from(38 * DAY));
+        let escrow = contract.state.escrows.get(&id).blocking_wait().unwrap().unwrap();
+        assert_eq!(escrow.amount, Amount::from_attos(100));
+        assert_eq!(escrow.start_timestamp, paid.start_timestamp);
+        assert_eq!(escrow.end_timestamp, paid.end_timestamp);
+
+        // The trial cannot be claimed again once it has been used
+        assert!(contract.state.trials_claimed.contains_key(&id).blocking_wait().unwrap());
+    }
+
+    #[test]
+    fn waitlisted_follower_is_admitted_when_a_slot_frees_up() {
+        let strategist = owner("strategist");
+        let (first, second) = (owner("first follower"), owner("second follower"));
+        let mut contract = create_contract(Timestamp::from(DAY), []);
+
+        execute_as(&mut contract, strategist, Operation::RegisterStrategist {
+            display_name: "alice".to_string(),
+            referrer: None,
+            deposit: None,
+        });
+        let response = execute_as(&mut contract, strategist, Operation::CreateAgentStrategy {
+            name: "Momentum".to_string(),
+            description: "Trend following".to_string(),
+            market_kind: MarketKind::Crypto,
+            base_market: "BTC".to_string(),
+            is_public: true,
+            is_ai_controlled: false,
+            push_band_bps: None,
+            model: None,
+        });
+        let AgentHubResponse::StrategyCreated { id: strategy_id } = response else {
+            panic!("strategy not created: {response:?}");
+        };
+        execute_as(&mut contract, strategist, Operation::SetMaxFollowers { strategy_id, max_followers: Some(1) });
+
+        let follow = Operation::FollowStrategy { strategy_id, auto_copy: false, max_exposure_units: 0 };
+        let response = execute_as(&mut contract, first, follow.clone());
+        assert!(matches!(response, AgentHubResponse::Followed { .. }), "{response:?}");
+        let response = execute_as(&mut contract, second, follow);
+        assert!(matches!(response, AgentHubResponse::Error { .. }), "{response:?}");
+        let waitlist = contract.state.follower_waitlist.get(&strategy_id).blocking_wait().unwrap().unwrap();
+        assert_eq!(waitlist.iter().map(|entry| entry.follower).collect::<Vec<_>>(), [second]);
+
+        execute_as(&mut contract, first, Operation::UnfollowStrategy { strategy_id, reason: None });
+        let following = |follower| FollowerKey { strategy_id, follower };
+        assert!(!contract.state.followers.contains_key(&following(first)).blocking_wait().unwrap());
+        assert!(contract.state.followers.contains_key(&following(second)).blocking_wait().unwrap());
+        assert_eq!(contract.state.follower_count.get(&strategy_id).blocking_wait().unwrap(), Some(1));
+        let waitlist = contract.state.follower_waitlist.get(&strategy_id).blocking_wait().unwrap().unwrap();
+        assert!(waitlist.is_empty());
     }
 }
@@ -4,12 +4,26 @@
 // linera publish-and-create \
 //   target/wasm32-unknown-unknown/release/agent_hub_contract.wasm \
 //   target/wasm32-unknown-unknown/release/agent_hub_service.wasm \
+//   --json-parameters '{"min_horizon_secs": 60, "max_horizon_secs": 2592000,
+//     "max_open_signals_per_strategy": 100, "protocol_fee_bps": 250, "admin": "<ADMIN_OWNER>",
+//     "price_oracle": null, "prediction_app": null}' \
 //   --json-argument '{"hub_chain_id": "<HUB_CHAIN_ID>"}'
 
-use async_graphql::{Enum, InputObject, Request, Response, SimpleObject};
+// `GraphQLMutationRoot` generates one resolver argument per operation field.
+#![allow(clippy::too_many_arguments)]
+
+use async_graphql::{
+    Enum, InputObject, InputValueError, InputValueResult, Name, Request, Response, Scalar, ScalarType,
+    SimpleObject, Value,
+};
 use linera_sdk::{
     graphql::GraphQLMutationRoot,
-    linera_base_types::{AccountOwner, Timestamp, ContractAbi, ServiceAbi},
+    linera_base_types::{
+        Account, AccountOwner, Amount, ApplicationId, BcsHashable, BcsSignable, ChainId, ContractAbi,
+        CryptoHash, DataBlobHash, Ed25519PublicKey, Ed25519Signature, ServiceAbi, StreamName,
+        Timestamp,
+    },
+    views::ViewError,
 };
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -18,23 +32,113 @@ use thiserror::Error;
 // ENUMS
 // ============================================================================
 
-/// Market type for the strategy
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+/// Market type for the strategy.
+/// New variants are appended so previously stored kinds keep their encoding.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum MarketKind {
     /// Crypto markets (BTC, ETH via oracle)
+    #[default]
     Crypto,
     /// Sports betting markets
     Sports,
     /// External prediction apps (Arcade, LineraOdds, TrueMarket)
     PredictionApp,
+    /// Currency pairs (EUR-USD, USD-JPY)
+    Forex,
+    /// Stocks and indices
+    Equities,
+    /// Metals, energy and agricultural futures
+    Commodities,
+    /// Any other asset class, named by the admin or strategist
+    Custom(String),
 }
 
-impl Default for MarketKind {
-    fn default() -> Self {
-        MarketKind::Crypto
+impl MarketKind {
+    /// Built-in kinds, used where every kind must be enumerated
+    pub const BUILT_IN: [MarketKind; 6] = [
+        MarketKind::Crypto,
+        MarketKind::Sports,
+        MarketKind::PredictionApp,
+        MarketKind::Forex,
+        MarketKind::Equities,
+        MarketKind::Commodities,
+    ];
+
+    /// Whether signals in this kind are scored against a price feed rather than an
+    /// event outcome (custom kinds only use the oracle of their registered market)
+    pub fn is_priced(&self) -> bool {
+        matches!(
+            self,
+            MarketKind::Crypto | MarketKind::Forex | MarketKind::Equities | MarketKind::Commodities
+        )
+    }
+
+    fn graphql_name(&self) -> String {
+        match self {
+            MarketKind::Crypto => "CRYPTO".into(),
+            MarketKind::Sports => "SPORTS".into(),
+            MarketKind::PredictionApp => "PREDICTION_APP".into(),
+            MarketKind::Forex => "FOREX".into(),
+            MarketKind::Equities => "EQUITIES".into(),
+            MarketKind::Commodities => "COMMODITIES".into(),
+            MarketKind::Custom(name) => format!("CUSTOM:{name}"),
+        }
+    }
+}
+
+/// Exposed over GraphQL as the built-in enum names (`CRYPTO`, `FOREX`, ...) or
+/// `"CUSTOM:<name>"` for custom kinds
+#[Scalar(name = "MarketKind")]
+impl ScalarType for MarketKind {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        let name = match &value {
+            Value::Enum(name) => name.as_str(),
+            Value::String(name) => name.as_str(),
+            _ => return Err(InputValueError::expected_type(value)),
+        };
+        if let Some(custom) = name.strip_prefix("CUSTOM:") {
+            let custom = custom.trim();
+            if custom.is_empty() {
+                return Err(InputValueError::custom("custom market kind needs a name"));
+            }
+            return Ok(MarketKind::Custom(custom.to_string()));
+        }
+        MarketKind::BUILT_IN
+            .into_iter()
+            .find(|kind| kind.graphql_name() == name)
+            .ok_or_else(|| InputValueError::expected_type(value))
+    }
+
+    fn to_value(&self) -> Value {
+        match self {
+            MarketKind::Custom(_) => Value::String(self.graphql_name()),
+            _ => Value::Enum(Name::new(self.graphql_name())),
+        }
     }
 }
 
+/// Why a follower left a strategy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum UnfollowReason {
+    TooRisky,
+    Inactive,
+    PoorPerformance,
+    Other,
+}
+
+/// Ordering for strategy listings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum StrategySort {
+    /// Most recently created first
+    Newest,
+    /// Most followers first
+    MostFollowers,
+    /// Highest win rate first
+    BestWinRate,
+    /// Most resolved signals first
+    MostSignals,
+}
+
 /// Signal direction prediction
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
 pub enum Direction {
@@ -46,17 +150,161 @@ pub enum Direction {
     No,
 }
 
+impl Direction {
+    pub const ALL: [Direction; 6] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Over,
+        Direction::Under,
+        Direction::Yes,
+        Direction::No,
+    ];
+
+    /// Whether the call is for the market to rise or the outcome to happen
+    pub fn is_bullish(self) -> bool {
+        matches!(self, Direction::Up | Direction::Over | Direction::Yes)
+    }
+}
+
 /// Status of a signal
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Enum)]
 pub enum SignalStatus {
+    #[default]
     Open,
     Resolved,
     Cancelled,
 }
 
-impl Default for SignalStatus {
-    fn default() -> Self {
-        SignalStatus::Open
+/// Where a signal's resolved value came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum ResolutionSource {
+    /// Price-oracle application queried by the contract
+    Oracle,
+    /// Value submitted by an account holding the oracle role
+    Resolver,
+    /// Time-weighted average of resolver observations
+    Twap,
+    /// Current value supplied by the strategist when closing early
+    Strategist,
+}
+
+/// Kind of entity a change-feed record refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum ChangeEntity {
+    Strategist,
+    Strategy,
+    StrategyStats,
+    Signal,
+    Follower,
+    Subscription,
+    Market,
+    Proposal,
+    Tournament,
+}
+
+/// What happened to an entity in a change-feed record
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum ChangeKind {
+    Created,
+    Updated,
+    Removed,
+}
+
+/// How a signal resolved exactly at its entry value scores
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum TieHandling {
+    #[default]
+    Push,
+    Lose,
+}
+
+impl TieHandling {
+    pub fn result(self) -> SignalResult {
+        match self {
+            TieHandling::Push => SignalResult::Push,
+            TieHandling::Lose => SignalResult::Lose,
+        }
+    }
+}
+
+/// Privileged role held by an account
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum Role {
+    /// Full control, including granting and revoking roles
+    Admin,
+    /// Moderation of strategists and content
+    Moderator,
+    /// Allowed to resolve signals with market values
+    Oracle,
+}
+
+/// Achievement awarded to a strategist for a strategy's track record
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum BadgeKind {
+    /// 100 resolved signals
+    Centurion,
+    /// 70%+ win rate over at least 50 resolved signals
+    Sharpshooter,
+    /// 10 winning signals in a row
+    HotStreak,
+}
+
+/// Lifecycle of a governance proposal
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum ProposalStatus {
+    /// Open for voting
+    #[default]
+    Active,
+    /// Vote passed; waiting for the timelock to expire
+    Passed,
+    /// Vote failed
+    Rejected,
+    /// Change has been applied
+    Executed,
+}
+
+/// Who a signal is delivered and listed to while it is open. Restricted signals are still
+/// stored in the state of the strategist's chain (and of subscriber chains they are sent to),
+/// so anyone who syncs those chains can read them; visibility is not encryption.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SignalVisibility {
+    /// Visible to everyone immediately
+    #[default]
+    Public,
+    /// Delivered and listed only to subscribers until resolved or cancelled
+    SubscribersOnly,
+    /// Delivered to subscribers immediately and to everyone after `delay_secs`
+    DelayedPublic { delay_secs: u64 },
+}
+
+/// Exposed over GraphQL as `PUBLIC`, `SUBSCRIBERS_ONLY` or `"DELAYED_PUBLIC:<delay_secs>"`
+#[Scalar(name = "SignalVisibility")]
+impl ScalarType for SignalVisibility {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        let name = match &value {
+            Value::Enum(name) => name.as_str(),
+            Value::String(name) => name.as_str(),
+            _ => return Err(InputValueError::expected_type(value)),
+        };
+        match name {
+            "PUBLIC" => Ok(SignalVisibility::Public),
+            "SUBSCRIBERS_ONLY" => Ok(SignalVisibility::SubscribersOnly),
+            _ => name
+                .strip_prefix("DELAYED_PUBLIC:")
+                .and_then(|delay| delay.trim().parse().ok())
+                .map(|delay_secs| SignalVisibility::DelayedPublic { delay_secs })
+                .ok_or_else(|| InputValueError::expected_type(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        match self {
+            SignalVisibility::Public => Value::Enum(Name::new("PUBLIC")),
+            SignalVisibility::SubscribersOnly => Value::Enum(Name::new("SUBSCRIBERS_ONLY")),
+            SignalVisibility::DelayedPublic { delay_secs } => {
+                Value::String(format!("DELAYED_PUBLIC:{delay_secs}"))
+            }
+        }
     }
 }
 
@@ -72,18 +320,47 @@ pub enum SignalResult {
 // STRUCTS
 // ============================================================================
 
+/// Globally unique identifier: the hosting chain plus its chain-local counter value
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+    SimpleObject, InputObject,
+)]
+#[graphql(input_name = "GlobalIdInput")]
+pub struct GlobalId {
+    pub chain_id: ChainId,
+    pub local_id: u64,
+}
+
+impl GlobalId {
+    pub fn new(chain_id: ChainId, local_id: u64) -> Self {
+        GlobalId { chain_id, local_id }
+    }
+}
+
+/// Per-strategist event stream carrying that strategist's signals.
+/// Subscriber and follower chains subscribe to it instead of being messaged one by one.
+pub fn strategist_stream(strategist: &AccountOwner) -> StreamName {
+    StreamName::from(format!("signals:{strategist}"))
+}
+
 /// A strategist who creates and manages agent strategies
 #[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
 pub struct Strategist {
     pub owner: AccountOwner,
     pub display_name: String,
+    /// Strategist who referred this one, credited a share of their protocol fees
+    pub referrer: Option<AccountOwner>,
     pub created_at: Timestamp,
+    /// Identity confirmed by a moderator, so impersonations of well-known agents stand out
+    pub verified: bool,
 }
 
 /// An AI agent strategy that publishes trading signals
 #[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
 pub struct AgentStrategy {
     pub id: u64,
+    /// Cross-chain unique ID (host chain + `id`)
+    pub global_id: GlobalId,
     pub owner: AccountOwner,
     pub name: String,
     pub description: String,
@@ -92,19 +369,440 @@ pub struct AgentStrategy {
     pub base_market: String,
     pub is_public: bool,
     pub is_ai_controlled: bool,
+    /// Moves smaller than this (in basis points) score as a push; the wider of this and
+    /// the market's push band applies
+    pub push_band_bps: u32,
+    /// Strategy this one was forked from, for attribution
+    pub forked_from: Option<GlobalId>,
+    /// Model behind an AI-controlled strategy, for auditing its claims
+    pub model: Option<ModelInfo>,
+    /// Key the strategy's AI agent signs signal attestations with
+    pub agent_key: Option<Ed25519PublicKey>,
+    /// Current version; bumped when the strategy materially changes, starting a fresh
+    /// track record
+    pub version: u32,
+    pub version_started_at: Timestamp,
+    /// Share of auto-copy followers' profits accruing to the owner, in basis points
+    pub performance_fee_bps: u16,
+    /// Paused strategies cannot publish new signals (e.g. while their agent is retrained)
+    pub is_paused: bool,
+    pub pause_reason: Option<String>,
+    /// Follower capacity; further follow requests join the waitlist
+    pub max_followers: Option<u64>,
+    /// Normalized discovery tags (see `normalize_tags`)
+    pub tags: Vec<String>,
+    /// When the strategy last published a signal
+    pub last_signal_at: Option<Timestamp>,
+    pub created_at: Timestamp,
+}
+
+/// Decimals of signal values in markets missing from the registry (cents)
+pub const DEFAULT_PRICE_DECIMALS: u8 = 2;
+
+/// Fixed-point price: an integer mantissa in units of 10^-decimals of the market's quote
+/// currency. Exposed over GraphQL as a decimal string of the mantissa.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub struct Price(pub u128);
+
+impl Price {
+    pub const ZERO: Price = Price(0);
+
+    pub fn mantissa(self) -> u128 {
+        self.0
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Relative move from `self` to `exit` in basis points, saturating at the `i64` bounds.
+    /// A zero `self` has no defined move and yields 0.
+    pub fn change_bps(self, exit: Price) -> i64 {
+        let (entry, exit) = (self.0, exit.0);
+        if entry == 0 {
+            return 0;
+        }
+        let diff = exit.abs_diff(entry);
+        // diff * 10000 / entry, split so neither part overflows u128
+        let remainder = diff % entry;
+        let fraction = match remainder.checked_mul(10000) {
+            Some(scaled) => scaled / entry,
+            None => remainder / (entry / 10000),
+        };
+        let bps = (diff / entry).saturating_mul(10000).saturating_add(fraction);
+        let bps = i64::try_from(bps).unwrap_or(i64::MAX);
+        if exit < entry { -bps } else { bps }
+    }
+}
+
+#[Scalar]
+impl ScalarType for Price {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        let mantissa = match &value {
+            Value::String(s) => s.parse::<u128>().ok(),
+            Value::Number(n) => n.as_u64().map(u128::from),
+            _ => None,
+        };
+        mantissa.map(Price).ok_or_else(|| InputValueError::expected_type(value))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.0.to_string())
+    }
+}
+
+/// Model metadata declared by an AI-controlled strategy
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, SimpleObject, InputObject)]
+#[graphql(input_name = "ModelInfoInput")]
+pub struct ModelInfo {
+    /// Model name (e.g., "gpt-4o", "claude-sonnet")
+    pub model_name: String,
+    pub model_version: String,
+    /// Hash of the prompt and configuration driving the model
+    pub config_hash: String,
+}
+
+/// Attestation supplied when publishing a signal: the agent key's signature over the
+/// call and a hash of the model inputs/outputs that produced it
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, InputObject)]
+pub struct AttestationInput {
+    pub payload_hash: CryptoHash,
+    pub signature: Ed25519Signature,
+}
+
+/// Attestation stored on a signal, with the agent key that signed it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct Attestation {
+    /// Hash of the model inputs/outputs behind the call
+    pub payload_hash: CryptoHash,
+    pub signature: Ed25519Signature,
+    pub agent_key: Ed25519PublicKey,
+}
+
+/// What an agent signs to attest a signal (BCS, with the type-name prefix)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AttestationPayload {
+    /// Strategy the signal is published under (host chain + strategy ID)
+    pub strategy: GlobalId,
+    pub strategy_version: u32,
+    pub direction: Direction,
+    pub confidence_bps: u16,
+    pub payload_hash: CryptoHash,
+}
+
+impl BcsSignable<'_> for AttestationPayload {}
+
+impl Attestation {
+    /// Whether the signature covers this signal's call and inputs/outputs hash
+    pub fn verify(&self, signal: &Signal) -> bool {
+        self.verify_call(
+            GlobalId::new(signal.global_id.chain_id, signal.strategy_id),
+            signal.strategy_version,
+            signal.direction,
+            signal.confidence_bps,
+        )
+    }
+
+    /// Whether the signature covers the given call and this inputs/outputs hash
+    pub fn verify_call(
+        &self,
+        strategy: GlobalId,
+        strategy_version: u32,
+        direction: Direction,
+        confidence_bps: u16,
+    ) -> bool {
+        let payload = AttestationPayload {
+            strategy,
+            strategy_version,
+            direction,
+            confidence_bps,
+            payload_hash: self.payload_hash,
+        };
+        self.signature.check(&payload, self.agent_key).is_ok()
+    }
+}
+
+/// Resolver signature supplied with `ResolveSignal`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, InputObject)]
+pub struct ResolutionProofInput {
+    /// When the resolver observed the value
+    pub observed_at: Timestamp,
+    pub signature: Ed25519Signature,
+}
+
+/// Resolver signature stored with a resolution, with the registered key that made it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct ResolutionProof {
+    pub observed_at: Timestamp,
+    pub signature: Ed25519Signature,
+    pub resolver_key: Ed25519PublicKey,
+}
+
+/// What a resolver signs to resolve a signal (BCS, with the type-name prefix)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResolutionPayload {
+    /// Signal being resolved (host chain + signal ID)
+    pub signal: GlobalId,
+    pub resolved_value: Price,
+    pub observed_at: Timestamp,
+}
+
+impl BcsSignable<'_> for ResolutionPayload {}
+
+impl ResolutionProof {
+    /// Whether the signature covers this signal's resolution at `resolved_value`
+    pub fn verify(&self, signal: GlobalId, resolved_value: Price) -> bool {
+        let payload = ResolutionPayload {
+            signal,
+            resolved_value,
+            observed_at: self.observed_at,
+        };
+        self.signature.check(&payload, self.resolver_key).is_ok()
+    }
+}
+
+/// A committed but not yet revealed signal: its timing and entry are fixed at commit
+/// time while the call stays hidden until revealed
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct SignalCommitment {
+    /// ID the signal takes when revealed
+    pub id: u64,
+    pub strategy_id: u64,
+    /// Hash of the `CommittedCall` to be revealed
+    pub commitment_hash: CryptoHash,
+    pub committed_at: Timestamp,
+    pub expires_at: Timestamp,
+    /// Reveals after this are rejected
+    pub reveal_deadline: Timestamp,
+    pub entry_value: Price,
+    pub price_decimals: u8,
+    pub entry_observed_at: Option<Timestamp>,
+}
+
+/// Hidden content of a signal commitment; its `CryptoHash` is the commitment hash
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CommittedCall {
+    /// Strategy the signal is committed under (host chain + strategy ID)
+    pub strategy: GlobalId,
+    pub direction: Direction,
+    pub confidence_bps: u16,
+    pub salt: String,
+}
+
+impl BcsHashable<'_> for CommittedCall {}
+
+/// Fields of a resolved signal committed to its strategy's Merkle accumulator
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SignalLeaf {
+    pub signal: GlobalId,
+    pub strategy_version: u32,
     pub created_at: Timestamp,
+    pub expires_at: Timestamp,
+    pub direction: Direction,
+    pub confidence_bps: u16,
+    pub entry_value: Option<Price>,
+    pub resolved_value: Option<Price>,
+    pub result: SignalResult,
+    pub pnl_bps: i64,
+}
+
+impl BcsHashable<'_> for SignalLeaf {}
+
+impl SignalLeaf {
+    /// Leaf hash of a resolved signal (`None` while it is open or if it was cancelled)
+    pub fn hash(signal: &Signal) -> Option<CryptoHash> {
+        if signal.status != SignalStatus::Resolved {
+            return None;
+        }
+        let leaf = SignalLeaf {
+            signal: signal.global_id,
+            strategy_version: signal.strategy_version,
+            created_at: signal.created_at,
+            expires_at: signal.expires_at,
+            direction: signal.direction,
+            confidence_bps: signal.confidence_bps,
+            entry_value: signal.entry_value,
+            resolved_value: signal.resolved_value,
+            result: signal.result?,
+            pnl_bps: signal.pnl_bps?,
+        };
+        Some(CryptoHash::new(&leaf))
+    }
+}
+
+/// Inner node of a Merkle accumulator
+#[derive(Debug, Serialize, Deserialize)]
+struct MerkleNode {
+    left: CryptoHash,
+    right: CryptoHash,
+}
+
+impl BcsHashable<'_> for MerkleNode {}
+
+/// Hash of an inner Merkle node
+pub fn merkle_parent(left: &CryptoHash, right: &CryptoHash) -> CryptoHash {
+    CryptoHash::new(&MerkleNode { left: *left, right: *right })
+}
+
+/// Root of an accumulator from its peaks (largest subtree first): `H(P0, H(P1, ... Pn))`
+pub fn merkle_root(peaks: &[CryptoHash]) -> Option<CryptoHash> {
+    peaks.iter().rev().copied().reduce(|acc, peak| merkle_parent(&peak, &acc))
+}
+
+/// Add the leaf at position `index` to an accumulator's peaks, merging equal-sized subtrees
+/// like carrying in a binary counter
+pub fn merkle_append(peaks: &mut Vec<CryptoHash>, index: u64, leaf: CryptoHash) {
+    let mut node = leaf;
+    let mut carry = index;
+    while carry & 1 == 1 {
+        let Some(left) = peaks.pop() else {
+            break;
+        };
+        node = merkle_parent(&left, &node);
+        carry >>= 1;
+    }
+    peaks.push(node);
+}
+
+/// One sibling on a Merkle inclusion path
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct MerkleStep {
+    pub hash: CryptoHash,
+    /// Whether `hash` is the left operand when combining
+    pub is_left: bool,
+}
+
+/// Proof that a resolved signal is part of its strategy's on-chain record
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct SignalInclusionProof {
+    pub signal_id: u64,
+    pub strategy_id: u64,
+    /// Position of the signal in resolution order
+    pub leaf_index: u64,
+    pub leaf_count: u64,
+    /// `CryptoHash` of the signal's `SignalLeaf`
+    pub leaf: CryptoHash,
+    /// Siblings from the leaf up to the root
+    pub path: Vec<MerkleStep>,
+    pub root: CryptoHash,
+}
+
+impl SignalInclusionProof {
+    /// Whether folding the path over the leaf yields the root
+    pub fn verify(&self) -> bool {
+        let computed = self.path.iter().fold(self.leaf, |acc, step| {
+            if step.is_left {
+                merkle_parent(&step.hash, &acc)
+            } else {
+                merkle_parent(&acc, &step.hash)
+            }
+        });
+        computed == self.root
+    }
+}
+
+/// An open signal awaiting resolution, as listed for resolvers. The call itself is withheld
+/// while the signal is restricted or its strategy private; resolving needs only the market
+/// and expiry.
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct PendingSignal {
+    pub id: u64,
+    pub strategy_id: u64,
+    pub base_market: String,
+    pub expires_at: Timestamp,
+    /// Full signal, once it may be shown to everyone
+    pub signal: Option<Signal>,
+}
+
+impl Signal {
+    /// Whether the signal may be shown to non-subscribers at `now`: always once it is no
+    /// longer open, otherwise as its visibility allows
+    pub fn is_disclosed(&self, now: Timestamp) -> bool {
+        if self.status != SignalStatus::Open {
+            return true;
+        }
+        match self.visibility {
+            SignalVisibility::Public => true,
+            SignalVisibility::SubscribersOnly => false,
+            SignalVisibility::DelayedPublic { .. } => self.disclosure_time().is_some_and(|at| now >= at),
+        }
+    }
+
+    /// When a delayed-public signal opens up to everyone; `None` for other visibilities
+    pub fn disclosure_time(&self) -> Option<Timestamp> {
+        match self.visibility {
+            SignalVisibility::DelayedPublic { delay_secs } => Some(Timestamp::from(
+                self.created_at.micros().saturating_add(delay_secs.saturating_mul(1_000_000)),
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// Resolution metadata of a signal, tracing its outcome to the price source
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct ResolutionProvenance {
+    pub source: ResolutionSource,
+    /// Account that submitted or triggered the resolution (`None` for scheduled ones)
+    pub resolver: Option<AccountOwner>,
+    /// Price-oracle application the value was read from
+    pub oracle: Option<ApplicationId>,
+    /// When the resolved value was observed
+    pub observed_at: Timestamp,
+    /// Resolver signature over the resolution, if the resolver has a registered key
+    pub proof: Option<ResolutionProof>,
+}
+
+/// Inclusive value range a range signal predicts the resolved value will land in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, SimpleObject, InputObject)]
+#[graphql(input_name = "PriceRangeInput")]
+pub struct PriceRange {
+    pub low: Price,
+    pub high: Price,
+}
+
+impl PriceRange {
+    pub fn contains(&self, value: Price) -> bool {
+        self.low <= value && value <= self.high
+    }
 }
 
 /// A trading signal published by an agent strategy
 #[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
 pub struct Signal {
     pub id: u64,
+    /// Cross-chain unique ID (host chain + `id`)
+    pub global_id: GlobalId,
     pub strategy_id: u64,
     pub created_at: Timestamp,
     pub expires_at: Timestamp,
     pub direction: Direction,
-    /// Entry price/value at signal time (in cents for crypto)
-    pub entry_value: Option<u64>,
+    /// Entry price/value at signal time, in units of 10^-`price_decimals`. Always set for new signals;
+    /// `None` only on legacy signals published before it was required, which score as a push.
+    pub entry_value: Option<Price>,
+    /// Decimals of this signal's values (entry, target, stop, range, resolution)
+    pub price_decimals: u8,
+    /// When the price oracle observed `entry_value` (`None` if supplied by the strategist)
+    pub entry_observed_at: Option<Timestamp>,
+    /// Take-profit level: reaching it allows resolution before expiry
+    pub target_value: Option<Price>,
+    /// Stop-loss level: reaching it allows resolution before expiry
+    pub stop_value: Option<Price>,
+    /// Version of the strategy the signal was published under
+    pub strategy_version: u32,
+    /// Who may see the signal while it is open
+    pub visibility: SignalVisibility,
+    /// Agent attestation of the model call behind this signal, if supplied
+    pub attestation: Option<Attestation>,
+    /// Closed by the strategist before expiry rather than resolved at full horizon
+    pub closed_early: bool,
+    /// Auto-cancelled because no resolution arrived within the grace period after expiry
+    pub unresolved: bool,
+    /// For range signals: wins if the resolved value lands inside, loses otherwise.
+    /// PnL still follows `direction`.
+    pub range: Option<PriceRange>,
     /// Confidence in basis points (0-10000 = 0-100%)
     pub confidence_bps: u16,
     pub status: SignalStatus,
@@ -112,7 +810,9 @@ pub struct Signal {
     /// PnL in basis points (can be negative)
     pub pnl_bps: Option<i64>,
     /// Resolved value (price at expiration)
-    pub resolved_value: Option<u64>,
+    pub resolved_value: Option<Price>,
+    /// Who resolved the signal and from which price source
+    pub resolution: Option<ResolutionProvenance>,
 }
 
 /// Aggregated statistics for a strategy
@@ -128,90 +828,1501 @@ pub struct StrategyStats {
     /// Average PnL in basis points
     pub avg_pnl_bps: i32,
     pub total_pnl_bps: i64,
+    /// Current (active) followers; unfollowing decrements it
     pub followers: u64,
+    /// Resolved signals that were closed early by the strategist (included in the totals)
+    pub early_closed_signals: u64,
+    /// Total PnL of early-closed signals in basis points (included in `total_pnl_bps`)
+    pub early_closed_pnl_bps: i64,
+    /// Brier score of declared confidence against win/lose outcomes in basis points
+    /// (0 = perfectly calibrated, 10000 = always confidently wrong; pushes are excluded)
+    pub brier_score_bps: u32,
+    /// Signals auto-cancelled after going unresolved past the grace period, plus commitments
+    /// never revealed (not in the totals)
+    pub unresolved_signals: u64,
+    /// Distinct accounts that have ever followed the strategy; unfollowing leaves it unchanged
+    pub total_followers_ever: u64,
 }
 
-/// Strategy combined with its stats for leaderboard display
-#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
-pub struct StrategyWithStats {
-    pub strategy: AgentStrategy,
-    pub stats: StrategyStats,
+/// Number of confidence buckets in a strategy's calibration curve (10 percentage points each)
+pub const CALIBRATION_BUCKETS: u8 = 10;
+
+/// Realized outcomes of a strategy's signals within one declared confidence range
+#[derive(Debug, Clone, Default, Serialize, Deserialize, SimpleObject)]
+pub struct CalibrationBucket {
+    /// Inclusive lower bound of declared confidence
+    pub min_confidence_bps: u16,
+    /// Exclusive upper bound of declared confidence (inclusive for the top bucket)
+    pub max_confidence_bps: u16,
+    /// Signals resolved as a win or loss (pushes are excluded)
+    pub resolved_signals: u64,
+    pub winning_signals: u64,
+    /// Realized win rate in basis points
+    pub win_rate_bps: u32,
 }
 
-/// A follower relationship
-#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
-pub struct Follower {
-    pub strategy_id: u64,
-    pub follower: AccountOwner,
-    pub auto_copy: bool,
-    pub max_exposure_units: u64,
-    pub created_at: Timestamp,
+impl CalibrationBucket {
+    /// Bucket index for a declared confidence
+    pub fn index_for(confidence_bps: u16) -> u8 {
+        ((confidence_bps / 1000) as u8).min(CALIBRATION_BUCKETS - 1)
+    }
+
+    /// An empty bucket covering the given index
+    pub fn empty(index: u8) -> Self {
+        Self {
+            min_confidence_bps: index as u16 * 1000,
+            max_confidence_bps: (index as u16 + 1) * 1000,
+            ..Default::default()
+        }
+    }
 }
 
-/// Key for follower map (strategy_id + follower)
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, SimpleObject, InputObject)]
-#[graphql(input_name = "FollowerKeyInput")]
-pub struct FollowerKey {
-    pub strategy_id: u64,
-    pub follower: AccountOwner,
+/// Microseconds in a day, the width of a daily bucket
+pub const DAY_MICROS: u64 = 24 * 60 * 60 * 1_000_000;
+
+/// UTC day of a timestamp, counted in days since the Unix epoch
+pub fn day_of(timestamp: Timestamp) -> u32 {
+    (timestamp.micros() / DAY_MICROS) as u32
 }
 
-/// Subscription for following a strategist cross-chain
-#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
-pub struct Subscription {
-    pub id: String,
-    pub subscriber: AccountOwner,
-    pub subscriber_chain_id: String,
-    pub strategist: AccountOwner,
-    pub strategist_chain_id: String,
-    pub start_timestamp: u64,
-    pub end_timestamp: u64,
-    pub is_active: bool,
+/// When a signal published at `now` with a horizon of `horizon_secs` expires; `None` if that
+/// does not fit in a timestamp
+pub fn signal_expiry(now: Timestamp, horizon_secs: u64) -> Option<Timestamp> {
+    horizon_secs
+        .checked_mul(1_000_000)
+        .and_then(|micros| now.micros().checked_add(micros))
+        .map(Timestamp::from)
 }
 
-/// Subscription offer set by a strategist
-#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
-pub struct SubscriptionOffer {
-    pub strategist: AccountOwner,
-    pub description: Option<String>,
-    pub is_enabled: bool,
+/// Signals a strategy resolved on one UTC day, for charting
+#[derive(Debug, Clone, Default, Serialize, Deserialize, SimpleObject)]
+pub struct DailyPnl {
+    /// Days since the Unix epoch
+    pub day: u32,
+    pub resolved_signals: u64,
+    /// Net PnL of the day's resolutions in basis points
+    pub net_pnl_bps: i64,
 }
 
-// ============================================================================
-// INPUT TYPES (for GraphQL mutations)
-// ============================================================================
+impl DailyPnl {
+    /// An empty bucket for the given day
+    pub fn empty(day: u32) -> Self {
+        Self { day, ..Default::default() }
+    }
+}
 
-#[derive(Debug, Clone, Serialize, Deserialize, InputObject)]
-pub struct CreateStrategyInput {
-    pub name: String,
-    pub description: String,
-    pub market_kind: MarketKind,
+/// Outcomes of every strategy's resolved signals on one base market
+#[derive(Debug, Clone, Default, Serialize, Deserialize, SimpleObject)]
+pub struct MarketStats {
     pub base_market: String,
-    pub is_public: bool,
-    pub is_ai_controlled: bool,
+    pub resolved_signals: u64,
+    pub winning_signals: u64,
+    pub losing_signals: u64,
+    pub push_signals: u64,
+    /// Winning over resolved signals, in basis points
+    pub win_rate_bps: u32,
+    pub total_pnl_bps: i64,
+    /// Mean PnL per resolved signal, in basis points
+    pub avg_pnl_bps: i64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, InputObject)]
-pub struct PublishSignalInput {
-    pub strategy_id: u64,
-    pub direction: Direction,
-    /// Signal horizon in seconds (e.g., 300 for 5 minutes)
-    pub horizon_secs: u64,
-    /// Confidence in basis points (0-10000)
-    pub confidence_bps: u16,
-    /// Entry value/price (optional, can be set by backend)
-    pub entry_value: Option<u64>,
+impl MarketStats {
+    pub fn new(base_market: String) -> Self {
+        Self { base_market, ..Default::default() }
+    }
+
+    /// Add a resolved signal
+    pub fn record(&mut self, result: SignalResult, pnl_bps: i64) {
+        self.resolved_signals += 1;
+        match result {
+            SignalResult::Win => self.winning_signals += 1,
+            SignalResult::Lose => self.losing_signals += 1,
+            SignalResult::Push => self.push_signals += 1,
+        }
+        self.total_pnl_bps = self.total_pnl_bps.saturating_add(pnl_bps);
+        self.win_rate_bps = (self.winning_signals * 10000 / self.resolved_signals) as u32;
+        self.avg_pnl_bps = self.total_pnl_bps / self.resolved_signals as i64;
+    }
 }
 
-// ============================================================================
-// OPERATIONS
-// ============================================================================
+/// Open signals in one direction
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct DirectionCount {
+    pub direction: Direction,
+    pub signals: u64,
+}
 
-/// Operations that can be executed on the AgentHub contract
+/// Consensus of the disclosed open signals on a base market
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct MarketSentiment {
+    pub base_market: String,
+    pub open_signals: u64,
+    /// Directional signals per direction; range signals are counted separately
+    pub by_direction: Vec<DirectionCount>,
+    pub range_signals: u64,
+    /// Confidence-weighted balance of bullish over bearish calls, from -10000 (all bearish)
+    /// to 10000 (all bullish)
+    pub net_bias_bps: i32,
+    /// Distinct strategies with an open signal
+    pub strategies: u64,
+}
+
+/// A strategy's follower count over one UTC day
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct FollowerSnapshot {
+    /// Days since the Unix epoch
+    pub day: u32,
+    /// Followers at the start of the day
+    pub opening_followers: u64,
+    /// Followers at the end of the day (now, for today)
+    pub followers: u64,
+}
+
+/// Unfollows of a strategy broken down by the reason given
+#[derive(Debug, Clone, Default, Serialize, Deserialize, SimpleObject)]
+pub struct ChurnStats {
+    pub strategy_id: u64,
+    /// All unfollows; blocked followers are not counted
+    pub unfollows: u64,
+    pub too_risky: u64,
+    pub inactive: u64,
+    pub poor_performance: u64,
+    pub other: u64,
+    /// Unfollows without a reason
+    pub unspecified: u64,
+}
+
+impl ChurnStats {
+    /// Count one unfollow
+    pub fn record(&mut self, reason: Option<UnfollowReason>) {
+        self.unfollows += 1;
+        match reason {
+            Some(UnfollowReason::TooRisky) => self.too_risky += 1,
+            Some(UnfollowReason::Inactive) => self.inactive += 1,
+            Some(UnfollowReason::PoorPerformance) => self.poor_performance += 1,
+            Some(UnfollowReason::Other) => self.other += 1,
+            None => self.unspecified += 1,
+        }
+    }
+}
+
+/// Canonical content of a track-record export blob (BCS-encoded). Auditors fetch the blob by
+/// the hash recorded on-chain and decode it as this type. Each blob covers one page of the
+/// strategy's publish order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackRecordBlob {
+    pub strategy: GlobalId,
+    pub owner: AccountOwner,
+    pub exported_at: Timestamp,
+    pub stats: StrategyStats,
+    /// First publish position covered
+    pub start: u64,
+    /// Publish position after the last one covered
+    pub end: u64,
+    /// Resolved signals among the covered positions, in publish order
+    pub signals: Vec<Signal>,
+}
+
+/// Verified track record of a public strategy, returned to other applications
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerifiedStats {
+    pub strategy: GlobalId,
+    pub owner: AccountOwner,
+    pub stats: StrategyStats,
+    /// Reputation of the strategy's owner
+    pub reputation: u64,
+    /// Merkle root over the strategy's resolved signals
+    pub merkle_root: Option<CryptoHash>,
+}
+
+/// On-chain record of a published track-record blob
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct TrackRecordExport {
+    pub strategy_id: u64,
+    pub blob_hash: DataBlobHash,
+    pub signal_count: u64,
+    pub exported_at: Timestamp,
+    /// First publish position covered
+    pub start: u64,
+    /// Publish position after the last one covered
+    pub end: u64,
+}
+
+/// A retired version of a strategy with its frozen track record
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct StrategyVersion {
+    pub strategy_id: u64,
+    pub version: u32,
+    /// What changed in the version that replaced this one
+    pub change_note: String,
+    /// Model this version ran
+    pub model: Option<ModelInfo>,
+    pub started_at: Timestamp,
+    pub ended_at: Timestamp,
+    /// Stats of the signals published under this version (followers as of retirement)
+    pub stats: StrategyStats,
+}
+
+/// A badge held by a strategist
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct Badge {
+    pub kind: BadgeKind,
+    /// Strategy whose record earned the badge
+    pub strategy_id: u64,
+    pub awarded_at: Timestamp,
+}
+
+/// Summary of a strategy registered in the hub chain's global catalog
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct StrategySummary {
+    /// Cross-chain unique ID of the strategy (its chain is the host chain)
+    pub global_id: GlobalId,
+    pub owner: AccountOwner,
+    pub name: String,
+    pub description: String,
+    pub market_kind: MarketKind,
+    pub base_market: String,
+    pub is_ai_controlled: bool,
+    pub created_at: Timestamp,
+}
+
+/// A strategy's summary and latest stats, as synced to the hub chain
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct SyncedStrategy {
+    pub summary: StrategySummary,
+    pub stats: StrategyStats,
+}
+
+/// Strategy combined with its stats for leaderboard display
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct StrategyWithStats {
+    pub strategy: AgentStrategy,
+    pub stats: StrategyStats,
+}
+
+/// A strategy's results over a trailing window of days
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct WindowPerformance {
+    pub window_days: u32,
+    /// Resolved signals published in the window
+    pub signals: u64,
+    /// Win rate in basis points (0-10000 = 0-100%)
+    pub win_rate_bps: u32,
+    pub total_pnl_bps: i64,
+}
+
+/// One strategy's row in a side-by-side comparison; every row has the same windows
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct StrategyComparison {
+    pub strategy_id: u64,
+    pub name: String,
+    /// Resolved signals of the current version
+    pub sample_size: u64,
+    pub win_rate_bps: u32,
+    pub avg_pnl_bps: i32,
+    pub total_pnl_bps: i64,
+    /// Largest peak-to-trough fall of cumulative PnL in basis points, in publish order
+    pub max_drawdown_bps: i64,
+    pub windows: Vec<WindowPerformance>,
+}
+
+/// Read-only copy of a public strategy from another chain, pulled with `MirrorStrategy`
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct MirroredStrategy {
+    pub strategy: AgentStrategy,
+    pub stats: StrategyStats,
+    /// Most recent publicly disclosed signals, newest first
+    pub signals: Vec<Signal>,
+    pub mirrored_at: Timestamp,
+}
+
+/// Lifetime performance aggregated over all of a strategist's strategies
+#[derive(Debug, Clone, Default, Serialize, Deserialize, SimpleObject)]
+pub struct LifetimePerformance {
+    pub total_signals: u64,
+    pub winning_signals: u64,
+    pub losing_signals: u64,
+    pub push_signals: u64,
+    /// Win rate in basis points (0-10000 = 0-100%)
+    pub win_rate_bps: u32,
+    pub total_pnl_bps: i64,
+    pub followers: u64,
+}
+
+/// Everything needed to render a strategist's profile
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct StrategistOverview {
+    pub strategist: Strategist,
+    pub strategies: Vec<StrategyWithStats>,
+    pub subscription_offer: Option<SubscriptionOffer>,
+    /// Number of active subscribers
+    pub subscriber_count: u64,
+    pub lifetime: LifetimePerformance,
+    /// Total tips received
+    pub lifetime_tips: Amount,
+}
+
+/// A resolver's timestamped price for a signal's market near its expiry, kept for audit
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct PriceObservation {
+    pub resolver: AccountOwner,
+    pub value: Price,
+    pub observed_at: Timestamp,
+}
+
+/// Time-weighted average of observations sorted by time: each value holds until the next
+/// observation is taken. `None` unless the observations span a positive interval.
+pub fn time_weighted_average(observations: &[PriceObservation]) -> Option<Price> {
+    let (first, last) = (observations.first()?, observations.last()?);
+    let span = last.observed_at.delta_since(first.observed_at).as_micros() as u128;
+    if span == 0 {
+        return None;
+    }
+    let weighted = observations.windows(2).fold(0u128, |sum, pair| {
+        let held = pair[1].observed_at.delta_since(pair[0].observed_at).as_micros() as u128;
+        sum.saturating_add(pair[0].value.mantissa().saturating_mul(held))
+    });
+    Some(Price(weighted / span))
+}
+
+/// Refundable anti-spam deposit paid on strategist registration, held on the strategist's chain
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct RegistrationDeposit {
+    pub owner: AccountOwner,
+    /// Fungible token application the deposit was paid in
+    pub token: ApplicationId,
+    pub amount: Amount,
+    pub deposited_at: Timestamp,
+    /// Signals the strategist has resolved since registering
+    pub resolved_signals: u32,
+    pub refunded: bool,
+}
+
+/// A tip paid to a strategist, recorded on the strategist's chain
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct Tip {
+    pub tipper: AccountOwner,
+    pub tipper_chain_id: ChainId,
+    pub strategist: AccountOwner,
+    pub amount: Amount,
+    pub timestamp: Timestamp,
+}
+
+/// Running totals maintained by the contract so analytics don't need scans
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, SimpleObject)]
+pub struct GlobalCounters {
+    pub total_strategists: u64,
+    pub total_strategies: u64,
+    pub total_signals: u64,
+    pub open_signals: u64,
+    pub resolved_signals: u64,
+    pub winning_signals: u64,
+    pub cancelled_signals: u64,
+    /// Current follows of strategies hosted on this chain
+    pub active_follows: u64,
+    /// Active subscriptions held by accounts on this chain
+    pub active_subscriptions: u64,
+}
+
+/// Platform-wide analytics
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct PlatformStats {
+    pub total_strategists: u64,
+    pub total_strategies: u64,
+    pub total_signals: u64,
+    pub resolved_signals: u64,
+    /// Aggregate win rate over all resolved signals in basis points
+    pub win_rate_bps: u32,
+    pub active_subscriptions: u64,
+}
+
+/// A ranked strategy in a hub leaderboard snapshot
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct LeaderboardEntry {
+    /// 1-based rank
+    pub rank: u32,
+    pub strategy: StrategySummary,
+    pub stats: StrategyStats,
+}
+
+/// A strategy ranked across chains by the hub, with the chain that hosts it
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct GlobalTopStrategy {
+    /// 1-based rank
+    pub rank: u32,
+    /// Chain to send follow and subscribe operations to
+    pub chain_id: ChainId,
+    pub strategy: StrategySummary,
+    pub stats: StrategyStats,
+}
+
+/// Top-N leaderboard for one market kind, materialized by the hub chain
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct HubLeaderboard {
+    pub epoch: u64,
+    pub market_kind: MarketKind,
+    pub taken_at: Timestamp,
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+/// A follower relationship
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct Follower {
+    pub strategy_id: u64,
+    pub follower: AccountOwner,
+    /// Chain the follower lives on (differs from the strategy chain for cross-chain follows)
+    pub chain_id: ChainId,
+    pub auto_copy: bool,
+    pub max_exposure_units: u64,
+    pub created_at: Timestamp,
+    /// Limits applied when auto-copying this strategy's signals
+    pub copy_settings: CopySettings,
+}
+
+/// Risk limits an auto-copy follower sets on copying a strategy (0 = no limit)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, SimpleObject, InputObject)]
+#[graphql(input_name = "CopySettingsInput")]
+pub struct CopySettings {
+    /// Most units copied into a single signal (`max_exposure_units` applies as well)
+    pub max_units_per_signal: u64,
+    /// Stop copying for the rest of the UTC day once copied signals lost this many units
+    pub daily_loss_limit_units: u64,
+    /// Skip signals declared with a lower confidence, in basis points
+    pub min_confidence_bps: u16,
+    /// Paper-trade: copies are tracked in a simulated P&L ledger and move no tokens
+    pub simulation: bool,
+}
+
+/// Why a signal was not copied for an auto-copy follower
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum CopySkipReason {
+    /// Declared confidence below the follower's minimum
+    BelowMinConfidence,
+    /// The follower's daily loss limit was already reached
+    DailyLossLimit,
+    /// The copy would take the follower's open exposure past their cap
+    ExposureCap,
+}
+
+/// A follower's copy-trading results summed over every strategy they copied
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct CopierStats {
+    pub follower: AccountOwner,
+    pub copied_signals: u64,
+    pub wins: u64,
+    pub losses: u64,
+    /// Net result of settled copies, in exposure units
+    pub realized_pnl_units: i64,
+    /// Wins over wins and losses, in basis points
+    pub hit_rate_bps: u32,
+}
+
+impl CopierStats {
+    pub fn new(follower: AccountOwner) -> Self {
+        Self {
+            follower,
+            copied_signals: 0,
+            wins: 0,
+            losses: 0,
+            realized_pnl_units: 0,
+            hit_rate_bps: 0,
+        }
+    }
+
+    /// Move the totals from one state of a per-strategy record to the next
+    pub fn apply(&mut self, before: &FollowerStats, after: &FollowerStats) {
+        self.copied_signals = self.copied_signals + after.copied_signals - before.copied_signals;
+        self.wins = self.wins + after.wins - before.wins;
+        self.losses = self.losses + after.losses - before.losses;
+        self.realized_pnl_units = self.realized_pnl_units
+            .saturating_add(after.realized_pnl_units)
+            .saturating_sub(before.realized_pnl_units);
+        let settled = self.wins + self.losses;
+        self.hit_rate_bps = (self.wins * 10000).checked_div(settled).unwrap_or(0) as u32;
+    }
+}
+
+/// An account's open exposure across its positions on this chain
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct FollowerExposure {
+    pub owner: AccountOwner,
+    /// Units held in open, non-simulated positions
+    pub open_units: u64,
+    /// Most open units the account allows itself (0 = no cap)
+    pub cap_units: u64,
+}
+
+impl FollowerExposure {
+    /// Whether `units` more fit under the cap
+    pub fn allows(&self, units: u64) -> bool {
+        self.cap_units == 0 || self.open_units.saturating_add(units) <= self.cap_units
+    }
+}
+
+/// A signal skipped by an auto-copy follower's risk limits
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct CopySkip {
+    pub signal_id: u64,
+    pub reason: CopySkipReason,
+    pub timestamp: Timestamp,
+}
+
+/// Lifecycle of a position
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum PositionStatus {
+    /// Following a signal that is still open
+    Open,
+    /// Settled at the signal's resolution
+    Settled,
+    /// Closed without a result because the signal was cancelled
+    Closed,
+}
+
+/// Exposure an account holds on a signal, opened by copy-trading or directly
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct Position {
+    pub id: u64,
+    pub signal_id: u64,
+    pub strategy_id: u64,
+    pub owner: AccountOwner,
+    pub size_units: u64,
+    /// The signal's entry value when the position was opened
+    pub open_value: Option<Price>,
+    /// The signal's resolved value once settled
+    pub close_value: Option<Price>,
+    pub status: PositionStatus,
+    /// Virtual position of a follower in simulation mode
+    pub simulated: bool,
+    /// Result in units, once settled
+    pub pnl_units: Option<i64>,
+    pub opened_at: Timestamp,
+    pub closed_at: Option<Timestamp>,
+}
+
+impl Position {
+    /// Settle at the signal's resolved value and result
+    pub fn settle(&mut self, close_value: Option<Price>, pnl_bps: i64, now: Timestamp) {
+        self.status = PositionStatus::Settled;
+        self.close_value = close_value;
+        self.pnl_units = Some((self.size_units as i128 * pnl_bps as i128 / 10000) as i64);
+        self.closed_at = Some(now);
+    }
+
+    /// Close without a result
+    pub fn close(&mut self, now: Timestamp) {
+        self.status = PositionStatus::Closed;
+        self.closed_at = Some(now);
+    }
+}
+
+/// Realized copy-trading results of a follower on a strategy
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct FollowerStats {
+    pub strategy_id: u64,
+    pub follower: AccountOwner,
+    pub copied_signals: u64,
+    pub wins: u64,
+    pub losses: u64,
+    /// Net result of settled copies, in exposure units
+    pub realized_pnl_units: i64,
+    /// Units held in copies of signals still open
+    pub current_exposure_units: u64,
+}
+
+impl FollowerStats {
+    pub fn new(strategy_id: u64, follower: AccountOwner) -> Self {
+        Self {
+            strategy_id,
+            follower,
+            copied_signals: 0,
+            wins: 0,
+            losses: 0,
+            realized_pnl_units: 0,
+            current_exposure_units: 0,
+        }
+    }
+
+    /// Add a copy of `units` on a newly published signal
+    pub fn open(&mut self, units: u64) {
+        self.copied_signals += 1;
+        self.current_exposure_units = self.current_exposure_units.saturating_add(units);
+    }
+
+    /// Settle a copy of `units` on a signal resolved at `pnl_bps`
+    pub fn settle(&mut self, units: u64, pnl_bps: i64) {
+        self.release(units);
+        if pnl_bps > 0 {
+            self.wins += 1;
+        } else if pnl_bps < 0 {
+            self.losses += 1;
+        }
+        let pnl_units = (units as i128 * pnl_bps as i128 / 10000) as i64;
+        self.realized_pnl_units = self.realized_pnl_units.saturating_add(pnl_units);
+    }
+
+    /// Drop a copy of `units` on a signal that closed without a result
+    pub fn release(&mut self, units: u64) {
+        self.current_exposure_units = self.current_exposure_units.saturating_sub(units);
+    }
+}
+
+/// Paper-trading results of a follower on a strategy
+#[derive(Debug, Clone, Default, Serialize, Deserialize, SimpleObject)]
+pub struct SimulatedPnl {
+    pub copied_signals: u64,
+    pub resolved_signals: u64,
+    pub wins: u64,
+    pub losses: u64,
+    /// Net result of the virtual copies, in exposure units
+    pub net_pnl_units: i64,
+}
+
+impl SimulatedPnl {
+    /// Add a resolved virtual copy of `units` at `pnl_bps`
+    pub fn record(&mut self, units: u64, pnl_bps: i64) {
+        self.resolved_signals += 1;
+        if pnl_bps > 0 {
+            self.wins += 1;
+        } else if pnl_bps < 0 {
+            self.losses += 1;
+        }
+        let pnl_units = (units as i128 * pnl_bps as i128 / 10000) as i64;
+        self.net_pnl_units = self.net_pnl_units.saturating_add(pnl_units);
+    }
+}
+
+/// A follow of a strategy hosted on another chain, recorded on the follower's chain
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct RemoteFollow {
+    pub strategy: GlobalId,
+    pub strategy_name: String,
+    pub strategist: AccountOwner,
+    pub follower: AccountOwner,
+    pub auto_copy: bool,
+    pub max_exposure_units: u64,
+    pub created_at: Timestamp,
+}
+
+/// Cross-chain request that expects a reply from the target chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum RequestKind {
+    Subscribe,
+    Follow,
+    Mirror,
+}
+
+/// Delivery status of a cross-chain request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum RequestStatus {
+    /// Sent; no reply yet
+    Pending,
+    /// The target chain replied
+    Confirmed,
+    /// The target chain rejected the message and it bounced back
+    Failed,
+    /// No reply within the timeout; a late reply still confirms it
+    TimedOut,
+}
+
+/// A request sent from this chain to another, tracked until it is answered or fails
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct OutboundRequest {
+    pub key: String,
+    pub kind: RequestKind,
+    pub requester: AccountOwner,
+    pub target_chain: ChainId,
+    /// Strategy followed or mirrored
+    pub strategy: Option<GlobalId>,
+    /// Strategist subscribed to
+    pub strategist: Option<AccountOwner>,
+    pub status: RequestStatus,
+    pub sent_at: Timestamp,
+    pub updated_at: Option<Timestamp>,
+}
+
+impl OutboundRequest {
+    /// Key of a subscription request
+    pub fn subscribe_key(subscriber: &AccountOwner, strategist: &AccountOwner) -> String {
+        format!("subscribe-{subscriber}-{strategist}")
+    }
+
+    /// Key of a follow request
+    pub fn follow_key(follower: &AccountOwner, strategy: GlobalId) -> String {
+        format!("follow-{follower}-{}:{}", strategy.chain_id, strategy.local_id)
+    }
+
+    /// Key of a mirror request
+    pub fn mirror_key(strategy: GlobalId) -> String {
+        format!("mirror-{}:{}", strategy.chain_id, strategy.local_id)
+    }
+}
+
+/// What a follower wants to be notified about. Signals from followed strategies and subscribed
+/// strategists are stored either way; these only decide which ones raise a notification.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, SimpleObject, InputObject)]
+#[graphql(input_name = "NotificationPreferencesInput")]
+pub struct NotificationPreferences {
+    /// Notify when a signal is published
+    pub on_publish: bool,
+    /// Notify when a signal is resolved or cancelled
+    pub on_resolution: bool,
+    /// Only notify about signals with at least this confidence in basis points
+    pub min_confidence_bps: u16,
+    /// Only notify about signals on these markets (all markets if empty)
+    pub markets: Vec<String>,
+}
+
+impl Default for NotificationPreferences {
+    fn default() -> Self {
+        Self {
+            on_publish: true,
+            on_resolution: true,
+            min_confidence_bps: 0,
+            markets: Vec::new(),
+        }
+    }
+}
+
+impl NotificationPreferences {
+    /// Whether a signal on `market`, in its current status, should raise a notification
+    pub fn wants(&self, signal: &Signal, market: &str) -> bool {
+        let status_wanted = match signal.status {
+            SignalStatus::Open => self.on_publish,
+            SignalStatus::Resolved | SignalStatus::Cancelled => self.on_resolution,
+        };
+        status_wanted
+            && signal.confidence_bps >= self.min_confidence_bps
+            && (self.markets.is_empty() || self.markets.iter().any(|m| m.eq_ignore_ascii_case(market)))
+    }
+}
+
+/// Signal publishing limits applied per strategist (0 = unlimited)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct RateLimitConfig {
+    pub max_signals_per_hour: u32,
+    pub max_signals_per_day: u32,
+}
+
+/// How directional signals in a market are scored
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, SimpleObject, InputObject)]
+#[graphql(input_name = "ResolutionRulesInput")]
+pub struct ResolutionRules {
+    /// Moves smaller than this (in basis points, either way) score as a push
+    pub push_band_bps: u32,
+    /// Favourable moves smaller than this (in basis points) score as a loss
+    pub min_win_move_bps: u32,
+    /// Result of a signal resolved exactly at its entry value
+    pub tie: TieHandling,
+}
+
+/// A market in the admin-curated registry
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject, InputObject)]
+#[graphql(input_name = "MarketInput")]
+pub struct Market {
+    /// Canonical symbol (e.g., "BTC-USD"), stored uppercase
+    pub symbol: String,
+    pub market_kind: MarketKind,
+    /// Decimals of signal values in this market
+    pub decimals: u8,
+    /// Price oracle for this market, overriding the application-wide one
+    pub oracle: Option<ApplicationId>,
+    /// Inactive markets keep their history but accept no new strategies
+    pub is_active: bool,
+    /// Resolution rules for this market, overriding those of its market kind
+    pub rules: Option<ResolutionRules>,
+}
+
+impl Market {
+    /// Canonical form of a market symbol used as the registry key
+    pub fn normalize_symbol(symbol: &str) -> String {
+        symbol.trim().to_uppercase()
+    }
+}
+
+/// A role held by an account
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, SimpleObject)]
+pub struct RoleAssignment {
+    pub owner: AccountOwner,
+    pub role: Role,
+}
+
+/// Key for follower map (strategy_id + follower)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, SimpleObject, InputObject)]
+#[graphql(input_name = "FollowerKeyInput")]
+pub struct FollowerKey {
+    pub strategy_id: u64,
+    pub follower: AccountOwner,
+}
+
+/// Subscription for following a strategist cross-chain
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct Subscription {
+    pub id: String,
+    pub subscriber: AccountOwner,
+    pub subscriber_chain_id: ChainId,
+    pub strategist: AccountOwner,
+    pub strategist_chain_id: ChainId,
+    pub start_timestamp: Timestamp,
+    pub end_timestamp: Timestamp,
+    pub is_active: bool,
+    /// Free trial period: access lapses at `end_timestamp` unless it is converted to a paid one
+    pub is_trial: bool,
+}
+
+impl Subscription {
+    /// Whether the subscription currently gives access to the strategist's signals; paid
+    /// and trial periods both lapse at `end_timestamp`
+    pub fn grants_access(&self, now: Timestamp) -> bool {
+        self.is_active && now < self.end_timestamp
+    }
+}
+
+/// Key of a display name in the name index: trimmed, lowercased, inner whitespace collapsed
+pub fn normalize_name(name: &str) -> String {
+    name.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+/// Most tags a strategy can carry
+pub const MAX_STRATEGY_TAGS: usize = 8;
+
+/// Longest tag, in characters
+pub const MAX_TAG_LEN: usize = 32;
+
+/// Normalize tags like display names and drop duplicates, keeping their order. `None` if
+/// there are too many, or one is empty or too long.
+pub fn normalize_tags(tags: &[String]) -> Option<Vec<String>> {
+    let mut normalized: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = normalize_name(tag);
+        if tag.is_empty() || tag.chars().count() > MAX_TAG_LEN {
+            return None;
+        }
+        if !normalized.contains(&tag) {
+            normalized.push(tag);
+        }
+    }
+    (normalized.len() <= MAX_STRATEGY_TAGS).then_some(normalized)
+}
+
+/// Number of public strategies carrying a tag
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct TagCount {
+    pub tag: String,
+    pub strategies: u64,
+}
+
+/// Deterministic subscription ID for a (subscriber, strategist) pair
+pub fn subscription_id(subscriber: &AccountOwner, strategist: &AccountOwner) -> String {
+    format!("sub-{subscriber}-{strategist}")
+}
+
+/// Subscription offer set by a strategist
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct SubscriptionOffer {
+    pub strategist: AccountOwner,
+    pub description: Option<String>,
+    /// Price per subscription period, paid into escrow
+    pub price: Amount,
+    /// Fungible token application the price is paid in (native tokens if unset)
+    pub token: Option<ApplicationId>,
+    /// Length of one subscription period in days (0 means the 30-day default)
+    pub duration_days: u32,
+    /// Free days granted to first-time subscribers before payment is required (0 = no trial)
+    pub trial_days: u32,
+    pub is_enabled: bool,
+}
+
+/// Subscription period used by offers that never set a duration
+pub const DEFAULT_SUBSCRIPTION_DAYS: u32 = 30;
+
+/// Longest subscription period a strategist can offer
+pub const MAX_SUBSCRIPTION_DAYS: u32 = 365;
+
+/// Longest free trial a strategist can offer
+pub const MAX_TRIAL_DAYS: u32 = 30;
+
+impl SubscriptionOffer {
+    /// A free, disabled offer with default terms
+    pub fn disabled(strategist: AccountOwner) -> Self {
+        SubscriptionOffer {
+            strategist,
+            description: None,
+            price: Amount::ZERO,
+            token: None,
+            duration_days: 0,
+            trial_days: 0,
+            is_enabled: false,
+        }
+    }
+
+    /// Configured period length in days, falling back to the default
+    pub fn period_days(&self) -> u32 {
+        if self.duration_days == 0 {
+            DEFAULT_SUBSCRIPTION_DAYS
+        } else {
+            self.duration_days
+        }
+    }
+
+    /// Configured period length in microseconds
+    pub fn period_micros(&self) -> u64 {
+        u64::from(self.period_days()) * 24 * 60 * 60 * 1_000_000
+    }
+
+    /// Trial length in microseconds (zero when no trial is offered)
+    pub fn trial_micros(&self) -> u64 {
+        u64::from(self.trial_days) * 24 * 60 * 60 * 1_000_000
+    }
+}
+
+/// Subscription payment held in escrow on the strategist's chain and released pro-rata
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct SubscriptionEscrow {
+    pub subscription_id: String,
+    pub subscriber: AccountOwner,
+    pub subscriber_chain_id: ChainId,
+    pub strategist: AccountOwner,
+    /// Total amount paid into escrow
+    pub amount: Amount,
+    /// Amount released from escrow so far (strategist payout plus protocol fee)
+    pub released: Amount,
+    /// Amount refunded to the subscriber on early cancellation
+    pub refunded: Amount,
+    pub start_timestamp: Timestamp,
+    pub end_timestamp: Timestamp,
+    /// Fungible token application the escrow is held in (native tokens if unset)
+    pub token: Option<ApplicationId>,
+}
+
+impl SubscriptionEscrow {
+    /// Portion of the escrow earned by the strategist at `now` (linear over the period)
+    pub fn vested(&self, now: Timestamp) -> Amount {
+        let duration = self.end_timestamp.delta_since(self.start_timestamp).as_micros();
+        let elapsed = now.delta_since(self.start_timestamp).as_micros().min(duration);
+        if duration == 0 {
+            return self.amount;
+        }
+        let vested = self.amount.to_attos() / duration as u128 * elapsed as u128
+            + self.amount.to_attos() % duration as u128 * elapsed as u128 / duration as u128;
+        Amount::from_attos(vested)
+    }
+
+    /// Amount still held in escrow
+    pub fn remaining(&self) -> Amount {
+        self.amount.saturating_sub(self.released).saturating_sub(self.refunded)
+    }
+}
+
+/// Protocol parameter change carried by a governance proposal (unset fields stay unchanged)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, SimpleObject, InputObject)]
+#[graphql(input_name = "ParameterChangeInput")]
+pub struct ParameterChange {
+    pub protocol_fee_bps: Option<u16>,
+    pub max_signals_per_hour: Option<u32>,
+    pub max_signals_per_day: Option<u32>,
+}
+
+impl ParameterChange {
+    pub fn is_empty(&self) -> bool {
+        self.protocol_fee_bps.is_none()
+            && self.max_signals_per_hour.is_none()
+            && self.max_signals_per_day.is_none()
+    }
+}
+
+/// A governance proposal to change protocol parameters
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: AccountOwner,
+    pub description: String,
+    pub change: ParameterChange,
+    /// Voting weight in favour
+    pub votes_for: u64,
+    /// Voting weight against
+    pub votes_against: u64,
+    pub created_at: Timestamp,
+    pub voting_ends_at: Timestamp,
+    /// Earliest time a passed proposal is applied
+    pub executable_at: Timestamp,
+    pub status: ProposalStatus,
+}
+
+/// A time-boxed trading competition with a prize pool
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct Tournament {
+    pub id: u64,
+    pub name: String,
+    /// Only strategies of this market kind compete (any if unset)
+    pub market_kind: Option<MarketKind>,
+    /// Only strategies on this base market compete (any if unset)
+    pub base_market: Option<String>,
+    pub start_timestamp: Timestamp,
+    pub end_timestamp: Timestamp,
+    /// Prize pool held in the application's account until settlement
+    pub prize_pool: Amount,
+    pub settled: bool,
+    pub created_at: Timestamp,
+}
+
+impl Tournament {
+    /// Whether a strategy competes in this tournament
+    pub fn accepts(&self, strategy: &AgentStrategy) -> bool {
+        self.market_kind.as_ref().is_none_or(|kind| *kind == strategy.market_kind)
+            && self.base_market.as_ref().is_none_or(|market| *market == strategy.base_market)
+    }
+}
+
+/// A strategy's score in a tournament
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct TournamentEntry {
+    pub tournament_id: u64,
+    pub strategy_id: u64,
+    pub owner: AccountOwner,
+    /// Resolved signals published during the tournament window
+    pub resolved_signals: u64,
+    pub winning_signals: u64,
+    /// Sum of PnL of those signals in basis points
+    pub score_bps: i64,
+    /// Prize paid out at settlement
+    pub prize: Amount,
+}
+
+/// One entry of the append-only change feed, for incremental off-chain indexing
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct ChangeRecord {
+    /// Position in the feed, starting at 1
+    pub sequence: u64,
+    pub entity: ChangeEntity,
+    /// Entity key: numeric ID, owner, subscription ID, market symbol, or `strategy_id:follower`
+    pub entity_id: String,
+    pub change: ChangeKind,
+    pub timestamp: Timestamp,
+}
+
+/// An incoming message or stream event whose handler failed on storage, kept instead of
+/// aborting the block. Its writes were rolled back; an admin can replay it or refund what it
+/// paid.
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct DeadLetter {
+    /// Position in the dead-letter log, starting at 1
+    pub sequence: u64,
+    /// Chain the message or event came from, if known
+    pub origin: Option<ChainId>,
+    /// Wire tag of the failed message variant (`None` for stream events)
+    pub message_tag: Option<u32>,
+    /// Index of the failed event in the origin's signal stream (`None` for messages)
+    pub event_index: Option<u32>,
+    /// Account that signed the message, if any
+    pub signer: Option<AccountOwner>,
+    /// The BCS-encoded `Message`, or `AgentHubEvent` for stream events
+    pub payload: Vec<u8>,
+    /// Whether the message had bounced back from its target chain
+    pub bounced: bool,
+    pub error: String,
+    pub received_at: Timestamp,
+    pub status: DeadLetterStatus,
+}
+
+/// What has been done about a dead letter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Enum)]
+pub enum DeadLetterStatus {
+    #[default]
+    Pending,
+    /// Handled successfully on replay
+    Replayed,
+    /// The payment it carried was returned to the sender instead
+    Refunded,
+}
+
+// ============================================================================
+// INPUT TYPES (for GraphQL mutations)
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize, InputObject)]
+pub struct CreateStrategyInput {
+    pub name: String,
+    pub description: String,
+    pub market_kind: MarketKind,
+    pub base_market: String,
+    pub is_public: bool,
+    pub is_ai_controlled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, InputObject)]
+pub struct PublishSignalInput {
+    pub strategy_id: u64,
+    pub direction: Direction,
+    /// Signal horizon in seconds (e.g., 300 for 5 minutes)
+    pub horizon_secs: u64,
+    /// Confidence in basis points (0-10000)
+    pub confidence_bps: u16,
+    /// Entry value/price (required unless a price oracle is configured)
+    pub entry_value: Option<Price>,
+    /// Value at which the signal may be resolved early as a win
+    pub target_value: Option<Price>,
+    /// Value at which the signal may be resolved early as a loss
+    pub stop_value: Option<Price>,
+    /// Predict the resolved value lands inside this range instead of moving in `direction`
+    pub range: Option<PriceRange>,
+}
+
+// ============================================================================
+// OPERATIONS
+// ============================================================================
+
+/// Operations that can be executed on the AgentHub contract. BCS encodes variants by
+/// position, which `tag` spells out: the first twelve keep the first release's tags and
+/// layouts, as `Legacy` forms where the operation has gained fields since, and new operations
+/// are only ever appended, never reordered or removed.
 #[derive(Debug, Clone, Serialize, Deserialize, GraphQLMutationRoot)]
 pub enum Operation {
-    /// Register as a strategist
-    RegisterStrategist { display_name: String },
+    /// `RegisterStrategist` as the first release encoded it
+    LegacyRegisterStrategist { display_name: String },
+    
+    /// `CreateAgentStrategy` as the first release encoded it
+    LegacyCreateAgentStrategy {
+        name: String,
+        description: String,
+        market_kind: MarketKind,
+        base_market: String,
+        is_public: bool,
+        is_ai_controlled: bool,
+    },
+    
+    /// `PublishSignal` as the first release encoded it, with the entry value in cents
+    LegacyPublishSignal {
+        strategy_id: u64,
+        direction: Direction,
+        horizon_secs: u64,
+        confidence_bps: u16,
+        entry_value: Option<u64>,
+    },
+    
+    /// `ResolveSignal` as the first release encoded it, with the resolved value in cents
+    LegacyResolveSignal { signal_id: u64, resolved_value: u64 },
+    
+    /// Cancel an open signal
+    CancelSignal { signal_id: u64 },
+    
+    /// Follow a strategy
+    FollowStrategy {
+        strategy_id: u64,
+        auto_copy: bool,
+        max_exposure_units: u64,
+    },
+    
+    /// `UnfollowStrategy` as the first release encoded it
+    LegacyUnfollowStrategy { strategy_id: u64 },
+    
+    /// Update strategy stats (internal, called after signal resolution)
+    UpdateStats { strategy_id: u64 },
+    
+    /// `EnableSubscription` as the first release encoded it
+    LegacyEnableSubscription { description: Option<String> },
+    
+    /// Disable subscription for this strategist
+    DisableSubscription,
+    
+    /// `SubscribeToStrategist` as the first release encoded it
+    LegacySubscribeToStrategist {
+        strategist: AccountOwner,
+        strategist_chain_id: String,
+    },
+    
+    /// Unsubscribe from a strategist
+    UnsubscribeFromStrategist {
+        strategist: AccountOwner,
+    },
+    
+    /// Start a new version of your strategy (e.g., new model or prompt): current stats are
+    /// frozen under the old version and a fresh track record begins
+    BumpStrategyVersion {
+        strategy_id: u64,
+        change_note: String,
+        /// Model of the new version (unset keeps the current one)
+        model: Option<ModelInfo>,
+    },
+    
+    /// Create a new strategy from a public (or your own) strategy on this chain, keeping
+    /// its market and settings and recording it as the source
+    ForkStrategy {
+        source_strategy_id: u64,
+        name: String,
+    },
+    
+    /// Commit to a hidden signal, fixing its entry time, entry value and horizon now;
+    /// `commitment_hash` is the hash of the `CommittedCall` revealed later. A strategy has at
+    /// most one outstanding commitment, and one never revealed counts as unresolved.
+    CommitSignal {
+        strategy_id: u64,
+        commitment_hash: CryptoHash,
+        horizon_secs: u64,
+        /// Entry value; required unless a price oracle is configured
+        entry_value: Option<Price>,
+    },
+    
+    /// Reveal a committed signal within the reveal window, publishing it as of its
+    /// commit time
+    RevealSignal {
+        signal_id: u64,
+        direction: Direction,
+        confidence_bps: u16,
+        salt: String,
+    },
+    
+    /// Register (or rotate) the key your strategy's AI agent signs attestations with
+    SetAgentKey {
+        strategy_id: u64,
+        agent_key: Ed25519PublicKey,
+    },
+    
+    /// Set the entry value of a legacy open signal published without one (oracle only)
+    BackfillEntryValue {
+        signal_id: u64,
+        entry_value: Price,
+    },
+    
+    /// Close one of your open signals before expiry, scored against the price oracle's current
+    /// value; without an oracle the close is scored as a push
+    CloseSignalEarly {
+        signal_id: u64,
+        current_value: Price,
+    },
+    
+    /// Follow a strategy hosted on another chain (cross-chain follow)
+    FollowRemoteStrategy {
+        strategy_id: u64,
+        strategy_chain_id: String,
+        auto_copy: bool,
+        max_exposure_units: u64,
+    },
+    
+    /// Unfollow a strategy hosted on another chain
+    UnfollowRemoteStrategy {
+        strategy_id: u64,
+        strategy_chain_id: String,
+        reason: Option<UnfollowReason>,
+    },
+    
+    /// Pull a public strategy's record from its host chain into this chain's mirror
+    MirrorStrategy {
+        strategy_id: u64,
+        strategy_chain_id: String,
+    },
+    
+    /// Cancel an active subscription and refund the unused escrowed payment
+    CancelSubscriptionEarly {
+        strategist: AccountOwner,
+    },
+    
+    /// Release the vested part of a subscription escrow to the strategist
+    ReleaseEscrow {
+        subscription_id: String,
+    },
+    
+    /// Grant a role to an account (admin only), on this chain or on `target_chain_id`
+    GrantRole {
+        owner: AccountOwner,
+        role: Role,
+        target_chain_id: Option<String>,
+    },
+    
+    /// Revoke an account's role (admin only), on this chain or on `target_chain_id`
+    RevokeRole {
+        owner: AccountOwner,
+        target_chain_id: Option<String>,
+    },
+    
+    /// Add or update a market in the registry (admin only), on this chain or on
+    /// `target_chain_id`
+    RegisterMarket {
+        market: Market,
+        target_chain_id: Option<String>,
+    },
+    
+    /// Activate or deactivate a registered market (admin only), on this chain or on
+    /// `target_chain_id`
+    SetMarketActive {
+        symbol: String,
+        is_active: bool,
+        target_chain_id: Option<String>,
+    },
+    
+    /// Set (or with `None`, clear) the default resolution rules of a market kind
+    /// (admin only), on this chain or on `target_chain_id`
+    SetResolutionRules {
+        market_kind: MarketKind,
+        rules: Option<ResolutionRules>,
+        target_chain_id: Option<String>,
+    },
+    
+    /// Propose a protocol parameter change (strategists and followers; hub chain only)
+    CreateProposal {
+        description: String,
+        change: ParameterChange,
+    },
+    
+    /// Vote on an active proposal, weighted by the voter's reputation (hub chain only)
+    Vote {
+        proposal_id: u64,
+        support: bool,
+    },
+    
+    /// Create a tournament funded from the caller's account (admin only)
+    CreateTournament {
+        name: String,
+        market_kind: Option<MarketKind>,
+        base_market: Option<String>,
+        start_timestamp: Timestamp,
+        end_timestamp: Timestamp,
+        prize_pool: Amount,
+    },
+    
+    /// Distribute a finished tournament's prize pool to its top strategies (admin only)
+    SettleTournament { tournament_id: u64 },
+    
+    /// Withdraw accumulated protocol fees from the treasury (admin only)
+    WithdrawTreasury {
+        amount: Amount,
+        to: Account,
+    },
+    
+    /// Set per-strategist signal publishing limits (admin only, 0 = unlimited)
+    SetRateLimits {
+        max_signals_per_hour: u32,
+        max_signals_per_day: u32,
+    },
+    
+    /// Publish a page of your strategy's resolved-signal history as a data blob and record
+    /// its hash. A page covers up to 500 signals in publish order from `start` (default 0).
+    ExportTrackRecord { strategy_id: u64, start: Option<u64> },
+    
+    /// Read a public strategy's verified stats; callable by other applications without a signer
+    GetVerifiedStats { strategy_id: u64 },
+    
+    /// Send a strategist a tip in native tokens
+    TipStrategist {
+        strategist: AccountOwner,
+        strategist_chain_id: String,
+        amount: Amount,
+    },
+    
+    /// Set the performance fee charged on your auto-copy followers' profits (max 50%)
+    SetPerformanceFee { strategy_id: u64, fee_bps: u16 },
+    
+    /// Deposit an allowance that performance fees on a followed strategy are paid from
+    FundPerformanceFees {
+        strategy_id: u64,
+        strategy_chain_id: String,
+        amount: Amount,
+    },
+    
+    /// Withdraw the performance fees accrued to you on this chain
+    ClaimPerformanceFees,
+    
+    /// Set the price, payment token and period length of your subscription offer
+    /// (a new offer starts disabled until `EnableSubscription`)
+    SetSubscriptionPrice {
+        amount: Amount,
+        token: Option<ApplicationId>,
+        duration_days: u32,
+    },
+    
+    /// Offer first-time subscribers a free trial of `trial_days` (0 disables it)
+    SetSubscriptionTrial { trial_days: u32 },
+    
+    /// Pay for a subscription on behalf of another account; the subscription is confirmed
+    /// to the recipient's chain
+    GiftSubscription {
+        recipient: AccountOwner,
+        recipient_chain_id: String,
+        strategist: AccountOwner,
+        strategist_chain_id: String,
+        payment: Amount,
+        token: Option<ApplicationId>,
+    },
+    
+    /// Stop a strategy from publishing new signals and tell its audience why
+    PauseStrategy { strategy_id: u64, reason: Option<String> },
+    
+    /// Let a paused strategy publish signals again
+    ResumeStrategy { strategy_id: u64 },
+    
+    /// Cap the number of followers of a strategy (`None` removes the cap); freed slots go to
+    /// the waitlist in request order
+    SetMaxFollowers { strategy_id: u64, max_followers: Option<u64> },
+    
+    /// Allow an account to follow and see the signals of your private strategy
+    InviteFollower { strategy_id: u64, invitee: AccountOwner },
+    
+    /// Withdraw an invitation to a private strategy
+    RevokeInvite { strategy_id: u64, invitee: AccountOwner },
+    
+    /// Remove a follower from your strategy and stop them from following it again
+    BlockFollower { strategy_id: u64, follower: AccountOwner },
+    
+    /// Let a blocked account follow your strategy again
+    UnblockFollower { strategy_id: u64, follower: AccountOwner },
+    
+    /// Mark a strategist as verified or not (moderators only)
+    SetStrategistVerified { strategist: AccountOwner, verified: bool },
+    
+    /// Change your display name (unique on this chain, ignoring case)
+    UpdateDisplayName { display_name: String },
+    
+    /// Submit a timestamped price observation near a signal's expiry (oracles only)
+    SubmitPriceObservation { signal_id: u64, value: Price, observed_at: Timestamp },
+    
+    /// Resolve an expired signal at the time-weighted average of its observations (oracles only)
+    ResolveSignalWithTwap { signal_id: u64 },
+    
+    /// Register (or rotate, or with `None` remove) the key your resolutions are signed with
+    /// (oracles only)
+    SetResolverKey { resolver_key: Option<Ed25519PublicKey> },
+    
+    /// Choose which signals from your follows and subscriptions raise notifications
+    UpdateNotificationPreferences { preferences: NotificationPreferences },
+    
+    /// Set the risk limits for auto-copying a strategy you follow, here or on another chain
+    UpdateCopySettings {
+        strategy_id: u64,
+        strategy_chain_id: String,
+        settings: CopySettings,
+    },
+    
+    /// Open a position on an open signal; it settles when the signal resolves
+    OpenPosition { signal_id: u64, size_units: u64 },
+    
+    /// Cap your total open exposure on a chain's strategies, here or on another chain (0 = no cap)
+    SetExposureCap {
+        strategy_chain_id: String,
+        cap_units: u64,
+    },
+    
+    /// Replace your strategy's discovery tags
+    SetStrategyTags { strategy_id: u64, tags: Vec<String> },
+    
+    /// Refund your unused performance-fee allowance on a strategy you no longer follow
+    WithdrawFeeAllowance {
+        strategy_id: u64,
+        strategy_chain_id: String,
+    },
+    
+    /// Send an executed proposal's parameter change from the hub chain to another chain
+    /// (admins only)
+    PushParameterChange {
+        proposal_id: u64,
+        target_chain_id: String,
+    },
+    
+    /// Register as a strategist, optionally naming the strategist who referred you
+    RegisterStrategist {
+        display_name: String,
+        referrer: Option<AccountOwner>,
+        /// Most the caller agrees to lock as the registration deposit, when one is required
+        deposit: Option<Amount>,
+    },
     
     /// Create a new agent strategy
     CreateAgentStrategy {
@@ -221,6 +2332,10 @@ pub enum Operation {
         base_market: String,
         is_public: bool,
         is_ai_controlled: bool,
+        /// Optional push band in basis points, widening the market's (fixed once created)
+        push_band_bps: Option<u32>,
+        /// Model behind the strategy, if AI-controlled
+        model: Option<ModelInfo>,
     },
     
     /// Publish a new trading signal
@@ -229,81 +2344,579 @@ pub enum Operation {
         direction: Direction,
         horizon_secs: u64,
         confidence_bps: u16,
-        entry_value: Option<u64>,
+        /// Entry price/value; required and must be non-zero unless a price oracle is
+        /// configured, in which case the oracle's price is used instead
+        entry_value: Option<Price>,
+        /// Optional take-profit level allowing resolution before expiry
+        target_value: Option<Price>,
+        /// Optional stop-loss level allowing resolution before expiry
+        stop_value: Option<Price>,
+        /// Optional range the resolved value must land in to win, replacing the
+        /// directional outcome
+        range: Option<PriceRange>,
+        /// Optional attestation signed by the strategy's registered agent key
+        attestation: Option<AttestationInput>,
+        /// Who may see the signal while it is open (default: public)
+        visibility: Option<SignalVisibility>,
+    },
+    
+    /// Resolve an open signal with the final value
+    ResolveSignal {
+        signal_id: u64,
+        resolved_value: Price,
+        /// Signature by the resolver's registered key; required once a key is registered
+        proof: Option<ResolutionProofInput>,
+    },
+    
+    /// Unfollow a strategy, optionally saying why
+    UnfollowStrategy { strategy_id: u64, reason: Option<UnfollowReason> },
+    
+    /// Enable subscription for this strategist (allow others to subscribe)
+    EnableSubscription {
+        description: Option<String>,
+        price: Option<Amount>,
+    },
+    
+    /// Subscribe to a strategist (cross-chain subscription), paying into escrow in the
+    /// offer's token (native tokens if `token` is unset)
+    SubscribeToStrategist {
+        strategist: AccountOwner,
+        strategist_chain_id: String,
+        payment: Option<Amount>,
+        token: Option<ApplicationId>,
+    },
+    
+    /// Never sent: an encoding this build cannot read, which `AgentHubAbi` decodes as this
+    /// so the block still goes through and the operation is answered with an error
+    Unknown { tag: u32 },
+    
+    /// Run a dead letter's handler again, e.g. after the storage failure has passed (admins
+    /// only)
+    ReplayDeadLetter { sequence: u64 },
+    
+    /// Return the payment a dead letter carried to its sender instead of replaying it
+    /// (admins only)
+    RefundDeadLetter { sequence: u64 },
+}
+
+impl Operation {
+    /// Stable wire tag of this variant (its BCS position)
+    pub fn tag(&self) -> u32 {
+        match self {
+            Operation::LegacyRegisterStrategist { .. } => 0,
+            Operation::LegacyCreateAgentStrategy { .. } => 1,
+            Operation::LegacyPublishSignal { .. } => 2,
+            Operation::LegacyResolveSignal { .. } => 3,
+            Operation::CancelSignal { .. } => 4,
+            Operation::FollowStrategy { .. } => 5,
+            Operation::LegacyUnfollowStrategy { .. } => 6,
+            Operation::UpdateStats { .. } => 7,
+            Operation::LegacyEnableSubscription { .. } => 8,
+            Operation::DisableSubscription => 9,
+            Operation::LegacySubscribeToStrategist { .. } => 10,
+            Operation::UnsubscribeFromStrategist { .. } => 11,
+            Operation::BumpStrategyVersion { .. } => 12,
+            Operation::ForkStrategy { .. } => 13,
+            Operation::CommitSignal { .. } => 14,
+            Operation::RevealSignal { .. } => 15,
+            Operation::SetAgentKey { .. } => 16,
+            Operation::BackfillEntryValue { .. } => 17,
+            Operation::CloseSignalEarly { .. } => 18,
+            Operation::FollowRemoteStrategy { .. } => 19,
+            Operation::UnfollowRemoteStrategy { .. } => 20,
+            Operation::MirrorStrategy { .. } => 21,
+            Operation::CancelSubscriptionEarly { .. } => 22,
+            Operation::ReleaseEscrow { .. } => 23,
+            Operation::GrantRole { .. } => 24,
+            Operation::RevokeRole { .. } => 25,
+            Operation::RegisterMarket { .. } => 26,
+            Operation::SetMarketActive { .. } => 27,
+            Operation::SetResolutionRules { .. } => 28,
+            Operation::CreateProposal { .. } => 29,
+            Operation::Vote { .. } => 30,
+            Operation::CreateTournament { .. } => 31,
+            Operation::SettleTournament { .. } => 32,
+            Operation::WithdrawTreasury { .. } => 33,
+            Operation::SetRateLimits { .. } => 34,
+            Operation::ExportTrackRecord { .. } => 35,
+            Operation::GetVerifiedStats { .. } => 36,
+            Operation::TipStrategist { .. } => 37,
+            Operation::SetPerformanceFee { .. } => 38,
+            Operation::FundPerformanceFees { .. } => 39,
+            Operation::ClaimPerformanceFees => 40,
+            Operation::SetSubscriptionPrice { .. } => 41,
+            Operation::SetSubscriptionTrial { .. } => 42,
+            Operation::GiftSubscription { .. } => 43,
+            Operation::PauseStrategy { .. } => 44,
+            Operation::ResumeStrategy { .. } => 45,
+            Operation::SetMaxFollowers { .. } => 46,
+            Operation::InviteFollower { .. } => 47,
+            Operation::RevokeInvite { .. } => 48,
+            Operation::BlockFollower { .. } => 49,
+            Operation::UnblockFollower { .. } => 50,
+            Operation::SetStrategistVerified { .. } => 51,
+            Operation::UpdateDisplayName { .. } => 52,
+            Operation::SubmitPriceObservation { .. } => 53,
+            Operation::ResolveSignalWithTwap { .. } => 54,
+            Operation::SetResolverKey { .. } => 55,
+            Operation::UpdateNotificationPreferences { .. } => 56,
+            Operation::UpdateCopySettings { .. } => 57,
+            Operation::OpenPosition { .. } => 58,
+            Operation::SetExposureCap { .. } => 59,
+            Operation::SetStrategyTags { .. } => 60,
+            Operation::WithdrawFeeAllowance { .. } => 61,
+            Operation::PushParameterChange { .. } => 62,
+            Operation::RegisterStrategist { .. } => 63,
+            Operation::CreateAgentStrategy { .. } => 64,
+            Operation::PublishSignal { .. } => 65,
+            Operation::ResolveSignal { .. } => 66,
+            Operation::UnfollowStrategy { .. } => 67,
+            Operation::EnableSubscription { .. } => 68,
+            Operation::SubscribeToStrategist { .. } => 69,
+            Operation::Unknown { .. } => 70,
+            Operation::ReplayDeadLetter { .. } => 71,
+            Operation::RefundDeadLetter { .. } => 72,
+        }
+    }
+
+    /// The current form of a first-release operation; other operations are returned as they are
+    pub fn upgrade(self) -> Operation {
+        match self {
+            Operation::LegacyRegisterStrategist { display_name } => Operation::RegisterStrategist {
+                display_name,
+                referrer: None,
+                deposit: None,
+            },
+            Operation::LegacyCreateAgentStrategy {
+                name,
+                description,
+                market_kind,
+                base_market,
+                is_public,
+                is_ai_controlled,
+            } => Operation::CreateAgentStrategy {
+                name,
+                description,
+                market_kind,
+                base_market,
+                is_public,
+                is_ai_controlled,
+                push_band_bps: None,
+                model: None,
+            },
+            Operation::LegacyPublishSignal {
+                strategy_id,
+                direction,
+                horizon_secs,
+                confidence_bps,
+                entry_value,
+            } => Operation::PublishSignal {
+                strategy_id,
+                direction,
+                horizon_secs,
+                confidence_bps,
+                entry_value: entry_value.map(|value| Price(value.into())),
+                target_value: None,
+                stop_value: None,
+                range: None,
+                attestation: None,
+                visibility: None,
+            },
+            Operation::LegacyResolveSignal { signal_id, resolved_value } => Operation::ResolveSignal {
+                signal_id,
+                resolved_value: Price(resolved_value.into()),
+                proof: None,
+            },
+            Operation::LegacyUnfollowStrategy { strategy_id } => {
+                Operation::UnfollowStrategy { strategy_id, reason: None }
+            }
+            Operation::LegacyEnableSubscription { description } => {
+                Operation::EnableSubscription { description, price: None }
+            }
+            Operation::LegacySubscribeToStrategist { strategist, strategist_chain_id } => {
+                Operation::SubscribeToStrategist {
+                    strategist,
+                    strategist_chain_id,
+                    payment: None,
+                    token: None,
+                }
+            }
+            operation => operation,
+        }
+    }
+}
+
+/// Messages that can be sent between chains, wrapped in a `WireMessage::Envelope` on the wire.
+/// BCS encodes variants by position, which `tag` spells out: append new ones at the end, never
+/// reorder or remove.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Message {
+    /// Sync signal data across chains
+    SignalResolved {
+        signal_id: u64,
+        strategy_id: u64,
+        result: SignalResult,
+        pnl_bps: i64,
+    },
+    /// Subscription payment/request from subscriber to strategist
+    SubscriptionRequest {
+        subscriber: AccountOwner,
+        subscriber_chain_id: ChainId,
+        strategist: AccountOwner,
+        timestamp: Timestamp,
+        /// Amount transferred to the application's escrow account alongside this request
+        payment: Amount,
+        /// Fungible token application the payment was made in (native tokens if unset)
+        token: Option<ApplicationId>,
     },
-    
-    /// Resolve an open signal with the final value
-    ResolveSignal {
-        signal_id: u64,
-        resolved_value: u64,
+    /// Role change sent by an admin to another chain (`None` revokes)
+    SetRole {
+        owner: AccountOwner,
+        role: Option<Role>,
     },
-    
-    /// Cancel an open signal
-    CancelSignal { signal_id: u64 },
-    
-    /// Follow a strategy
-    FollowStrategy {
+    /// Market registration sent by an admin to another chain
+    SetMarket { market: Market },
+    /// Market (de)activation sent by an admin to another chain
+    SetMarketActive { symbol: String, is_active: bool },
+    /// Market kind resolution rules sent by an admin to another chain
+    SetResolutionRules { market_kind: MarketKind, rules: Option<ResolutionRules> },
+    /// Early cancellation from subscriber to strategist; unused escrow is refunded
+    CancelSubscriptionRequest {
+        subscriber: AccountOwner,
+        strategist: AccountOwner,
+    },
+    /// Subscription confirmation from strategist to subscriber
+    SubscriptionConfirmed {
+        subscription_id: String,
+        subscriber: AccountOwner,
+        strategist: AccountOwner,
+        strategist_chain_id: ChainId,
+        start_timestamp: Timestamp,
+        end_timestamp: Timestamp,
+        is_trial: bool,
+    },
+    /// Follow request from follower's chain to the strategy's chain
+    FollowRequest {
         strategy_id: u64,
+        follower: AccountOwner,
+        follower_chain_id: ChainId,
         auto_copy: bool,
         max_exposure_units: u64,
     },
-    
-    /// Unfollow a strategy
-    UnfollowStrategy { strategy_id: u64 },
-    
-    /// Update strategy stats (internal, called after signal resolution)
-    UpdateStats { strategy_id: u64 },
-    
-    /// Enable subscription for this strategist (allow others to subscribe)
-    EnableSubscription {
-        description: Option<String>,
+    /// Follow confirmation from the strategy's chain back to the follower's chain
+    FollowConfirmed {
+        strategy: GlobalId,
+        strategy_name: String,
+        strategist: AccountOwner,
+        follower: AccountOwner,
+        auto_copy: bool,
+        max_exposure_units: u64,
     },
-    
-    /// Disable subscription for this strategist
-    DisableSubscription,
-    
-    /// Subscribe to a strategist (cross-chain subscription)
-    SubscribeToStrategist {
+    /// Unfollow request from follower's chain to the strategy's chain
+    UnfollowRequest {
+        strategy_id: u64,
+        follower: AccountOwner,
+        reason: Option<UnfollowReason>,
+    },
+    /// Register a newly created public strategy in the hub chain's catalog
+    RegisterStrategyOnHub { summary: StrategySummary },
+    /// Mirror request from a chain to the strategy's host chain
+    MirrorRequest { strategy_id: u64 },
+    /// Strategy snapshot from the host chain back to the requesting chain
+    MirrorSnapshot {
+        strategy: Box<AgentStrategy>,
+        stats: StrategyStats,
+        signals: Vec<Signal>,
+    },
+    /// Tip paid from the tipper's chain, recorded on the strategist's chain
+    TipSent {
+        tipper: AccountOwner,
         strategist: AccountOwner,
-        strategist_chain_id: String,
+        amount: Amount,
     },
-    
-    /// Unsubscribe from a strategist
-    UnsubscribeFromStrategist {
+    /// Performance-fee allowance paid into escrow on the strategy's chain
+    PerformanceFeesFunded {
+        strategy_id: u64,
+        follower: AccountOwner,
+        amount: Amount,
+    },
+    /// Subscription paid for by `gifter` on behalf of `recipient`; refunds go to the gifter
+    GiftSubscriptionRequest {
+        gifter: AccountOwner,
+        gifter_chain_id: ChainId,
+        recipient: AccountOwner,
+        recipient_chain_id: ChainId,
+        strategist: AccountOwner,
+        timestamp: Timestamp,
+        payment: Amount,
+        token: Option<ApplicationId>,
+    },
+    /// Follow removed by the strategist, sent from the strategy's chain to the follower's chain
+    FollowRevoked {
+        strategy: GlobalId,
+        follower: AccountOwner,
+    },
+    /// Copy settings from a follower's chain for their follow on the strategy's chain
+    CopySettingsUpdate {
+        strategy_id: u64,
+        follower: AccountOwner,
+        settings: CopySettings,
+    },
+    /// A follower's exposure cap from their chain for the strategies' chain
+    ExposureCapUpdate {
+        follower: AccountOwner,
+        cap_units: u64,
+    },
+    /// Batch of public strategies whose stats changed since the last sync, with their
+    /// current summaries, from a strategist chain to the hub chain
+    StatsSync { strategy_summaries: Vec<SyncedStrategy> },
+    /// Refund request for a former follower's unused performance-fee allowance, from their
+    /// chain to the strategy's chain
+    FeeAllowanceWithdrawal {
+        strategy_id: u64,
+        follower: AccountOwner,
+    },
+    /// Undisclosed signal sent directly to a subscriber's chain instead of the public stream
+    RestrictedSignal {
+        signal: Box<Signal>,
         strategist: AccountOwner,
+        base_market: String,
     },
+    /// Executed governance change sent by an admin from the hub chain to another chain
+    ParameterChangeApplied { proposal_id: u64, change: ParameterChange },
+}
+
+impl Message {
+    /// Stable wire tag of this variant (its BCS position)
+    pub fn tag(&self) -> u32 {
+        match self {
+            Message::SignalResolved { .. } => 0,
+            Message::SubscriptionRequest { .. } => 1,
+            Message::SetRole { .. } => 2,
+            Message::SetMarket { .. } => 3,
+            Message::SetMarketActive { .. } => 4,
+            Message::SetResolutionRules { .. } => 5,
+            Message::CancelSubscriptionRequest { .. } => 6,
+            Message::SubscriptionConfirmed { .. } => 7,
+            Message::FollowRequest { .. } => 8,
+            Message::FollowConfirmed { .. } => 9,
+            Message::UnfollowRequest { .. } => 10,
+            Message::RegisterStrategyOnHub { .. } => 11,
+            Message::MirrorRequest { .. } => 12,
+            Message::MirrorSnapshot { .. } => 13,
+            Message::TipSent { .. } => 14,
+            Message::PerformanceFeesFunded { .. } => 15,
+            Message::GiftSubscriptionRequest { .. } => 16,
+            Message::FollowRevoked { .. } => 17,
+            Message::CopySettingsUpdate { .. } => 18,
+            Message::ExposureCapUpdate { .. } => 19,
+            Message::StatsSync { .. } => 20,
+            Message::FeeAllowanceWithdrawal { .. } => 21,
+            Message::RestrictedSignal { .. } => 22,
+            Message::ParameterChangeApplied { .. } => 23,
+        }
+    }
 }
 
-/// Messages that can be sent between chains
+/// Wire format version stamped on outgoing envelopes; bump when an existing variant's fields
+/// change, and decode the old layout in `MessageEnvelope::open`
+pub const MESSAGE_WIRE_VERSION: u16 = 1;
+
+/// What chains actually exchange. The first release sent its messages bare, so its four
+/// variants keep their positions and layouts here; every later message travels in an
+/// `Envelope`. Never change the first-release variants.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum Message {
-    /// Sync signal data across chains
+pub enum WireMessage {
+    /// First-release stats sync after a resolution
     SignalResolved {
         signal_id: u64,
         strategy_id: u64,
         result: SignalResult,
         pnl_bps: i64,
     },
-    /// Subscription payment/request from subscriber to strategist
+    /// First-release subscription request, unpaid
     SubscriptionRequest {
         subscriber: AccountOwner,
         subscriber_chain_id: String,
         strategist: AccountOwner,
+        /// In microseconds
         timestamp: u64,
     },
-    /// Subscription confirmation from strategist to subscriber
+    /// First-release subscription confirmation, which did not name the subscriber
     SubscriptionConfirmed {
         subscription_id: String,
         strategist: AccountOwner,
         strategist_chain_id: String,
+        /// In microseconds
         end_timestamp: u64,
     },
-    /// Signal broadcast to subscribers
+    /// First-release signal broadcast to a subscriber's chain
     SignalBroadcast {
-        signal: Signal,
+        signal: FirstReleaseSignal,
         strategy_name: String,
         strategist: AccountOwner,
     },
+    Envelope(MessageEnvelope),
+}
+
+/// The encoded `Message` travels as opaque bytes next to its tag, so a chain running an older
+/// build can still decode the envelope of a message it does not understand instead of failing
+/// the whole block.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageEnvelope {
+    pub version: u16,
+    pub tag: u32,
+    pub payload: Vec<u8>,
+}
+
+/// A message opened from the wire
+#[derive(Debug, Clone)]
+pub enum ReceivedMessage {
+    Known(Box<Message>),
+    /// Sent by a build with a newer wire version or a variant this one lacks, or a
+    /// first-release message that cannot be carried over (wire version 0)
+    Unknown { version: u16, tag: u32 },
+}
+
+impl WireMessage {
+    /// Decode the carried message, carrying first-release ones over to their current
+    /// variants. Those lack fields the current ones have: `origin` stands in for the sender's
+    /// chain, `signer` for the subscriber of a confirmation and `now` for when it started.
+    pub fn open(
+        self,
+        origin: Option<ChainId>,
+        signer: Option<AccountOwner>,
+        now: Timestamp,
+    ) -> ReceivedMessage {
+        let (tag, message) = match self {
+            WireMessage::Envelope(envelope) => return envelope.open(),
+            WireMessage::SignalResolved { signal_id, strategy_id, result, pnl_bps } => {
+                (0, Some(Message::SignalResolved { signal_id, strategy_id, result, pnl_bps }))
+            }
+            WireMessage::SubscriptionRequest {
+                subscriber,
+                subscriber_chain_id,
+                strategist,
+                timestamp,
+            } => (1, subscriber_chain_id.parse().ok().map(|subscriber_chain_id| {
+                Message::SubscriptionRequest {
+                    subscriber,
+                    subscriber_chain_id,
+                    strategist,
+                    timestamp: Timestamp::from(timestamp),
+                    payment: Amount::ZERO,
+                    token: None,
+                }
+            })),
+            WireMessage::SubscriptionConfirmed {
+                strategist,
+                strategist_chain_id,
+                end_timestamp,
+                ..
+            } => (2, match (signer, strategist_chain_id.parse()) {
+                (Some(subscriber), Ok(strategist_chain_id)) => Some(Message::SubscriptionConfirmed {
+                    subscription_id: subscription_id(&subscriber, &strategist),
+                    subscriber,
+                    strategist,
+                    strategist_chain_id,
+                    start_timestamp: now,
+                    end_timestamp: Timestamp::from(end_timestamp),
+                    is_trial: false,
+                }),
+                _ => None,
+            }),
+            WireMessage::SignalBroadcast { signal, strategist, .. } => {
+                (3, origin.map(|chain_id| Message::RestrictedSignal {
+                    signal: Box::new(signal.upgrade(chain_id, 1)),
+                    strategist,
+                    base_market: String::new(),
+                }))
+            }
+        };
+        match message {
+            Some(message) => ReceivedMessage::Known(Box::new(message)),
+            None => ReceivedMessage::Unknown { version: 0, tag },
+        }
+    }
+}
+
+impl MessageEnvelope {
+    /// Decode the carried message, falling back to `Unknown` when this build cannot read it
+    pub fn open(self) -> ReceivedMessage {
+        if self.version == MESSAGE_WIRE_VERSION {
+            if let Ok(message) = bcs::from_bytes::<Message>(&self.payload) {
+                if message.tag() == self.tag {
+                    return ReceivedMessage::Known(Box::new(message));
+                }
+            }
+        }
+        ReceivedMessage::Unknown { version: self.version, tag: self.tag }
+    }
+}
+
+impl From<Message> for MessageEnvelope {
+    fn from(message: Message) -> Self {
+        MessageEnvelope {
+            version: MESSAGE_WIRE_VERSION,
+            tag: message.tag(),
+            payload: bcs::to_bytes(&message).expect("Failed to serialize message"),
+        }
+    }
+}
+
+impl From<Message> for WireMessage {
+    fn from(message: Message) -> Self {
+        WireMessage::Envelope(message.into())
+    }
+}
+
+/// A signal as the first release encoded it, in stored state and in broadcasts. Never change
+/// this.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FirstReleaseSignal {
+    pub id: u64,
+    pub strategy_id: u64,
+    pub created_at: Timestamp,
+    pub expires_at: Timestamp,
+    pub direction: Direction,
+    /// In cents
+    pub entry_value: Option<u64>,
+    pub confidence_bps: u16,
+    pub status: SignalStatus,
+    pub result: Option<SignalResult>,
+    pub pnl_bps: Option<i64>,
+    pub resolved_value: Option<u64>,
+}
+
+impl FirstReleaseSignal {
+    /// The signal in the current layout, as published on `chain_id` under `strategy_version`
+    pub fn upgrade(self, chain_id: ChainId, strategy_version: u32) -> Signal {
+        Signal {
+            id: self.id,
+            global_id: GlobalId::new(chain_id, self.id),
+            strategy_id: self.strategy_id,
+            created_at: self.created_at,
+            expires_at: self.expires_at,
+            direction: self.direction,
+            entry_value: self.entry_value.map(|value| Price(value.into())),
+            price_decimals: DEFAULT_PRICE_DECIMALS,
+            entry_observed_at: None,
+            target_value: None,
+            stop_value: None,
+            strategy_version,
+            visibility: SignalVisibility::default(),
+            attestation: None,
+            closed_early: false,
+            unresolved: false,
+            range: None,
+            confidence_bps: self.confidence_bps,
+            status: self.status,
+            result: self.result,
+            pnl_bps: self.pnl_bps,
+            resolved_value: self.resolved_value.map(|value| Price(value.into())),
+            resolution: None,
+        }
+    }
 }
 
 /// Response from contract operations
@@ -312,15 +2925,62 @@ pub enum AgentHubResponse {
     Ok,
     StrategistRegistered { owner: AccountOwner },
     StrategyCreated { id: u64 },
+    StrategyVersionBumped { strategy_id: u64, version: u32 },
+    AgentKeySet { strategy_id: u64 },
+    TrackRecordExported { strategy_id: u64, blob_hash: DataBlobHash },
+    VerifiedStats(Box<VerifiedStats>),
+    TipSent { strategist: AccountOwner, amount: Amount },
+    PerformanceFeeSet { strategy_id: u64, fee_bps: u16 },
+    PerformanceFeesFunded { strategy: GlobalId, amount: Amount },
+    PerformanceFeesClaimed { amount: Amount },
+    SubscriptionPriceSet { strategist: AccountOwner, amount: Amount, duration_days: u32 },
+    SubscriptionTrialSet { strategist: AccountOwner, trial_days: u32 },
+    SubscriptionGifted { subscription_id: String },
+    StrategyPaused { strategy_id: u64 },
+    StrategyResumed { strategy_id: u64 },
+    MaxFollowersSet { strategy_id: u64, max_followers: Option<u64> },
+    FollowerInvited { strategy_id: u64, invitee: AccountOwner },
+    InviteRevoked { strategy_id: u64, invitee: AccountOwner },
+    FollowerBlocked { strategy_id: u64, follower: AccountOwner },
+    FollowerUnblocked { strategy_id: u64, follower: AccountOwner },
+    StrategistVerificationSet { strategist: AccountOwner, verified: bool },
+    DisplayNameUpdated { owner: AccountOwner },
+    PriceObserved { signal_id: u64, observations: u32 },
+    ResolverKeySet { resolver: AccountOwner },
+    NotificationPreferencesUpdated { owner: AccountOwner },
+    CopySettingsUpdated { strategy: GlobalId },
+    PositionOpened { id: u64 },
+    ExposureCapSet { chain_id: ChainId, cap_units: u64 },
+    StrategyTagsSet { strategy_id: u64, tags: Vec<String> },
+    FeeAllowanceWithdrawn { strategy: GlobalId, amount: Amount },
+    FeeAllowanceWithdrawalRequested { strategy: GlobalId },
     SignalPublished { id: u64 },
+    SignalCommitted { id: u64, reveal_deadline: Timestamp },
     SignalResolved { id: u64, result: SignalResult, pnl_bps: i64 },
     SignalCancelled { id: u64 },
     Followed { strategy_id: u64 },
     Unfollowed { strategy_id: u64 },
+    FollowRequested { strategy: GlobalId },
+    MirrorRequested { strategy: GlobalId },
     SubscriptionEnabled { strategist: AccountOwner },
     SubscriptionDisabled { strategist: AccountOwner },
     Subscribed { subscription_id: String },
     Unsubscribed { strategist: AccountOwner },
+    SubscriptionCancelledEarly { strategist: AccountOwner },
+    EscrowReleased { subscription_id: String, amount: Amount },
+    TreasuryWithdrawn { amount: Amount, to: Account },
+    RoleGranted { owner: AccountOwner, role: Role },
+    RoleRevoked { owner: AccountOwner },
+    MarketUpdated { symbol: String },
+    ResolutionRulesUpdated { market_kind: MarketKind },
+    ProposalCreated { id: u64 },
+    VoteCast { proposal_id: u64, weight: u64 },
+    ParameterChangePushed { proposal_id: u64, target_chain: ChainId },
+    TournamentCreated { id: u64 },
+    TournamentSettled { id: u64, distributed: Amount },
+    RateLimitsUpdated { limits: RateLimitConfig },
+    DeadLetterReplayed { sequence: u64 },
+    DeadLetterRefunded { sequence: u64, amount: Amount },
     Error { message: String },
 }
 
@@ -328,7 +2988,7 @@ pub enum AgentHubResponse {
 // ERRORS
 // ============================================================================
 
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Error)]
 pub enum AgentHubError {
     #[error("Strategist not registered")]
     StrategistNotRegistered,
@@ -336,6 +2996,9 @@ pub enum AgentHubError {
     #[error("Strategist already registered")]
     StrategistAlreadyRegistered,
     
+    #[error("Referrer must be another registered strategist")]
+    InvalidReferrer,
+    
     #[error("Strategy not found")]
     StrategyNotFound,
     
@@ -357,9 +3020,54 @@ pub enum AgentHubError {
     #[error("Not following")]
     NotFollowing,
     
+    #[error("Invalid chain ID")]
+    InvalidChainId,
+    
+    #[error("Push band must be at most 10000 basis points")]
+    InvalidPushBand,
+    
+    #[error("Invalid market")]
+    InvalidMarket,
+    
+    #[error("Market not found")]
+    MarketNotFound,
+    
+    #[error("Market is not listed, inactive, or of a different kind")]
+    MarketNotListed,
+    
     #[error("Invalid confidence value")]
     InvalidConfidence,
     
+    #[error("Entry value is required and must be non-zero")]
+    InvalidEntryValue,
+    
+    #[error("Target must be on the winning side of entry and stop on the losing side")]
+    InvalidExitLevels,
+    
+    #[error("Range low must be below its high")]
+    InvalidRange,
+    
+    #[error("Attestation does not verify against the strategy's agent key")]
+    InvalidAttestation,
+    
+    #[error("Signal has not expired and no target or stop was reached")]
+    TooEarlyToResolve,
+    
+    #[error("Signal commitment not found")]
+    CommitmentNotFound,
+    
+    #[error("Revealed call does not match the commitment")]
+    CommitmentMismatch,
+    
+    #[error("Reveal window has closed")]
+    RevealWindowClosed,
+    
+    #[error("Signal horizon out of allowed bounds")]
+    InvalidHorizon,
+    
+    #[error("Too many open signals for this strategy")]
+    TooManyOpenSignals,
+    
     #[error("Subscription not enabled")]
     SubscriptionNotEnabled,
     
@@ -369,10 +3077,142 @@ pub enum AgentHubError {
     #[error("Not subscribed")]
     NotSubscribed,
     
+    #[error("Escrow not found")]
+    EscrowNotFound,
+    
+    #[error("Insufficient treasury balance")]
+    InsufficientTreasury,
+    
+    #[error("Signal publishing rate limit exceeded")]
+    RateLimited,
+    
+    #[error("Proposal not found")]
+    ProposalNotFound,
+    
+    #[error("Invalid proposal")]
+    InvalidProposal,
+    
+    #[error("Voting is closed for this proposal")]
+    VotingClosed,
+    
+    #[error("Already voted on this proposal")]
+    AlreadyVoted,
+    
+    #[error("Only strategists and followers can take part in governance")]
+    NotEligibleToVote,
+    
+    #[error("Governance only runs on the hub chain")]
+    NotHubChain,
+    
+    #[error("Proposal has not been executed")]
+    ProposalNotExecuted,
+    
+    #[error("Tournament not found")]
+    TournamentNotFound,
+    
+    #[error("Invalid tournament window")]
+    InvalidTournament,
+    
+    #[error("Tournament has not ended yet")]
+    TournamentNotEnded,
+    
+    #[error("Tournament already settled")]
+    TournamentAlreadySettled,
+    
+    #[error("Tips must be non-zero and paid to someone else")]
+    InvalidTip,
+    
+    #[error("Performance fee cannot exceed 5000 basis points")]
+    InvalidPerformanceFee,
+    
+    #[error("No performance fees to claim")]
+    NoPerformanceFees,
+    
+    #[error("Subscription period must be between 1 and 365 days")]
+    InvalidSubscriptionDuration,
+    
+    #[error("Free trials cannot exceed 30 days")]
+    InvalidTrialPeriod,
+    
+    #[error("Gifted subscriptions must be paid for and given to someone else")]
+    InvalidGift,
+    
+    #[error("Strategy is paused")]
+    StrategyPaused,
+    
+    #[error("Strategy has reached its follower limit; follow request added to the waitlist")]
+    StrategyFull,
+    
+    #[error("Strategy is private and invite-only")]
+    NotInvited,
+    
+    #[error("Blocked from following this strategy")]
+    BlockedByStrategist,
+    
+    #[error("Registration deposit is below the required amount")]
+    InsufficientDeposit,
+    
+    #[error("Display name is already taken")]
+    NameTaken,
+    
+    #[error("Display name cannot be empty")]
+    InvalidDisplayName,
+    
+    #[error("Resolution grace period has passed")]
+    ResolutionWindowClosed,
+    
+    #[error("Price observation is outside the signal's expiry window or already recorded")]
+    InvalidObservation,
+    
+    #[error("Not enough price observations for a time-weighted resolution")]
+    InsufficientObservations,
+    
+    #[error("Resolution proof is missing or does not verify against the resolver's key")]
+    InvalidResolutionProof,
+    
+    #[error("Position size must be positive")]
+    InvalidPositionSize,
+    
+    #[error("Position would exceed your exposure cap")]
+    ExposureCapExceeded,
+    
+    #[error("Tags must be non-empty, at most 32 characters and at most 8 per strategy")]
+    InvalidTags,
+    
+    #[error("Unfollow the strategy before withdrawing its fee allowance")]
+    StillFollowing,
+    
+    #[error("No unused performance-fee allowance to withdraw")]
+    NoFeeAllowance,
+    
+    #[error("Reveal the strategy's outstanding commitment or let it lapse first")]
+    CommitmentPending,
+    
+    #[error("Unknown operation (tag {0}); this build cannot read it")]
+    UnknownOperation(u32),
+    
+    #[error("State upgrade in progress; retry in a later block")]
+    MigrationInProgress,
+    
+    #[error("Dead letter not found")]
+    DeadLetterNotFound,
+    
+    #[error("Dead letter was already replayed or refunded")]
+    DeadLetterSettled,
+    
+    #[error("Dead letter carried no payment to refund")]
+    NothingToRefund,
+    
     #[error("Internal error: {0}")]
     Internal(String),
 }
 
+impl From<ViewError> for AgentHubError {
+    fn from(error: ViewError) -> Self {
+        AgentHubError::Internal(error.to_string())
+    }
+}
+
 impl From<AgentHubError> for AgentHubResponse {
     fn from(error: AgentHubError) -> Self {
         AgentHubResponse::Error {
@@ -381,6 +3221,44 @@ impl From<AgentHubError> for AgentHubResponse {
     }
 }
 
+// ============================================================================
+// PARAMETERS
+// ============================================================================
+
+/// Application configuration, fixed when the application is created
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct AgentHubParameters {
+    /// Shortest allowed signal horizon in seconds
+    pub min_horizon_secs: u64,
+    /// Longest allowed signal horizon in seconds
+    pub max_horizon_secs: u64,
+    /// Maximum number of simultaneously open signals per strategy (0 = unlimited)
+    pub max_open_signals_per_strategy: u64,
+    /// Protocol fee taken from payments, in basis points
+    pub protocol_fee_bps: u16,
+    /// Bootstrap admin account, an admin on every chain (e.g. tuning rate limits)
+    pub admin: AccountOwner,
+    /// Price-oracle application providing canonical entry prices (strategist-supplied if unset)
+    #[serde(default)]
+    pub price_oracle: Option<ApplicationId>,
+    /// Prediction application that mirrors `PredictionApp` signals as bets for auto-copy followers
+    #[serde(default)]
+    pub prediction_app: Option<ApplicationId>,
+    /// Fungible token application registration deposits are paid in (no deposit if unset)
+    #[serde(default)]
+    pub deposit_token: Option<ApplicationId>,
+    /// Refundable deposit locked when registering as a strategist
+    #[serde(default)]
+    pub registration_deposit: Amount,
+    /// Resolved signals after which a strategist's registration deposit is refunded
+    #[serde(default)]
+    pub deposit_refund_signals: u32,
+    /// Seconds after expiry an open signal may still be resolved before it is auto-cancelled
+    /// as unresolved (0 = never auto-cancelled)
+    #[serde(default)]
+    pub resolution_grace_secs: u64,
+}
+
 // ============================================================================
 // INITIALIZATION
 // ============================================================================
@@ -389,6 +3267,9 @@ impl From<AgentHubError> for AgentHubResponse {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InstantiationArgument {
     pub hub_chain_id: String,
+    /// Initial role holders on the creator chain (the configured admin is always an admin)
+    #[serde(default)]
+    pub roles: Vec<RoleAssignment>,
 }
 
 // ============================================================================
@@ -407,6 +3288,16 @@ pub enum AgentHubEvent {
         direction: Direction,
         confidence_bps: u16,
     },
+    /// Emitted on the strategist's stream whenever one of their signals is published or
+    /// changes status, once it is disclosed; subscriber and follower chains store the carried
+    /// signal
+    SignalBroadcast {
+        signal: Box<Signal>,
+        strategy_name: String,
+        strategist: AccountOwner,
+        /// Base market of the signal's strategy, for notification filters
+        base_market: String,
+    },
     /// Emitted when a signal is resolved
     SignalResolved {
         strategy_id: u64,
@@ -414,6 +3305,12 @@ pub enum AgentHubEvent {
         result: SignalResult,
         pnl_bps: i64,
     },
+    /// Emitted when a strategist earns a badge
+    BadgeAwarded {
+        strategist: AccountOwner,
+        kind: BadgeKind,
+        strategy_id: u64,
+    },
     /// Emitted when a strategy gains a follower
     StrategyFollowed {
         strategy_id: u64,
@@ -436,21 +3333,305 @@ pub enum AgentHubEvent {
         subscriber: AccountOwner,
         strategist: AccountOwner,
     },
+    /// Emitted on the strategist's chain when they receive a tip
+    TipReceived {
+        tipper: AccountOwner,
+        strategist: AccountOwner,
+        amount: Amount,
+    },
+    /// Emitted on the strategist's stream when a strategy stops publishing signals
+    StrategyPaused {
+        strategy: GlobalId,
+        strategist: AccountOwner,
+        reason: Option<String>,
+    },
+    /// Emitted on the strategist's stream when a paused strategy resumes
+    StrategyResumed {
+        strategy: GlobalId,
+        strategist: AccountOwner,
+    },
+    /// Emitted on the `notifications` stream of a follower's chain when a broadcast signal
+    /// matches the follower's notification preferences
+    SignalNotification {
+        recipient: AccountOwner,
+        signal: GlobalId,
+        status: SignalStatus,
+    },
 }
 
 // ============================================================================
 // ABI
 // ============================================================================
 
+/// Request sent to the configured price-oracle application
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PriceOracleRequest {
+    /// Latest price of a market (e.g., "BTC-USD"), in the market's smallest unit
+    GetPrice { market: String },
+}
+
+/// Price observation returned by the price oracle
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct PriceQuote {
+    pub price: Price,
+    pub observed_at: Timestamp,
+}
+
+/// ABI of the price-oracle application called for entry prices
+pub struct PriceOracleAbi;
+
+impl ContractAbi for PriceOracleAbi {
+    type Operation = PriceOracleRequest;
+    type Response = PriceQuote;
+}
+
+/// Request sent to the configured prediction application
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PredictionAppRequest {
+    /// Place a follower's bet mirroring a newly published signal
+    PlaceBet {
+        bettor: AccountOwner,
+        market: String,
+        signal: GlobalId,
+        direction: Direction,
+        stake_units: u64,
+        expires_at: Timestamp,
+    },
+    /// Settle every bet placed on a resolved signal
+    SettleBets { signal: GlobalId, result: SignalResult },
+    /// Refund every bet placed on a cancelled signal
+    CancelBets { signal: GlobalId },
+}
+
+/// ABI of the prediction application that auto-copied bets are placed with
+pub struct PredictionAppAbi;
+
+impl ContractAbi for PredictionAppAbi {
+    type Operation = PredictionAppRequest;
+    type Response = ();
+}
+
 /// ABI definition for the AgentHub application
 pub struct AgentHubAbi;
 
 impl ContractAbi for AgentHubAbi {
     type Operation = Operation;
     type Response = AgentHubResponse;
+
+    /// An operation this build cannot read becomes `Operation::Unknown` instead of failing
+    /// the block
+    fn deserialize_operation(operation: Vec<u8>) -> Result<Operation, String> {
+        Ok(bcs::from_bytes(&operation).unwrap_or_else(|_| Operation::Unknown {
+            tag: leading_variant_index(&operation),
+        }))
+    }
+}
+
+/// The variant index BCS writes first for an enum, as ULEB128 (0 if it is malformed)
+fn leading_variant_index(bytes: &[u8]) -> u32 {
+    let mut index = 0u32;
+    for (position, byte) in bytes.iter().take(5).enumerate() {
+        index |= u32::from(byte & 0x7f) << (7 * position);
+        if byte & 0x80 == 0 {
+            return index;
+        }
+    }
+    0
 }
 
 impl ServiceAbi for AgentHubAbi {
     type Query = Request;
     type QueryResponse = Response;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn change_bps_of_ordinary_moves() {
+        assert_eq!(Price(100).change_bps(Price(110)), 1000);
+        assert_eq!(Price(100).change_bps(Price(90)), -1000);
+        assert_eq!(Price(100).change_bps(Price(100)), 0);
+        assert_eq!(Price(1).change_bps(Price(0)), -10000);
+    }
+
+    #[test]
+    fn change_bps_from_zero_entry_is_zero() {
+        assert_eq!(Price::ZERO.change_bps(Price(1)), 0);
+        assert_eq!(Price::ZERO.change_bps(Price(u128::MAX)), 0);
+        assert_eq!(Price::ZERO.change_bps(Price::ZERO), 0);
+    }
+
+    #[test]
+    fn change_bps_at_u128_extremes() {
+        let max = Price(u128::MAX);
+        assert_eq!(max.change_bps(max), 0);
+        assert_eq!(max.change_bps(Price::ZERO), -10000);
+        assert_eq!(Price(u128::MAX - 1).change_bps(max), 0);
+        // A huge gain saturates instead of wrapping
+        assert_eq!(Price(1).change_bps(max), i64::MAX);
+        // Remainders too large to scale by 10000 take the fallback path
+        let entry = Price(u128::MAX / 3 * 2);
+        assert!((4999..=5000).contains(&entry.change_bps(max)));
+        assert!((-5000..=-4999).contains(&max.change_bps(Price(u128::MAX / 2))));
+    }
+
+    #[test]
+    fn change_bps_never_returns_i64_min() {
+        for (entry, exit) in [(1, 0), (u128::MAX, 0), (2, 1), (u128::MAX, 1)] {
+            assert!(Price(entry).change_bps(Price(exit)) > i64::MIN);
+        }
+    }
+
+    #[test]
+    fn signal_expiry_adds_horizon() {
+        let now = Timestamp::from(5_000_000);
+        assert_eq!(signal_expiry(now, 0), Some(now));
+        assert_eq!(signal_expiry(now, 60), Some(Timestamp::from(65_000_000)));
+    }
+
+    #[test]
+    fn signal_expiry_rejects_overflow() {
+        let zero = Timestamp::from(0);
+        assert_eq!(signal_expiry(zero, u64::MAX), None);
+        assert_eq!(signal_expiry(zero, u64::MAX / 1_000_000 + 1), None);
+        assert_eq!(
+            signal_expiry(zero, u64::MAX / 1_000_000),
+            Some(Timestamp::from(u64::MAX / 1_000_000 * 1_000_000)),
+        );
+        assert_eq!(signal_expiry(Timestamp::from(u64::MAX - 1), 1), None);
+        assert_eq!(signal_expiry(Timestamp::from(u64::MAX), 0), Some(Timestamp::from(u64::MAX)));
+    }
+
+    fn escrow(amount: u128, start: u64, end: u64) -> SubscriptionEscrow {
+        SubscriptionEscrow {
+            subscription_id: "sub".to_string(),
+            subscriber: AccountOwner::Address20([1; 20]),
+            subscriber_chain_id: ChainId(CryptoHash::from([1, 2, 3, 4])),
+            strategist: AccountOwner::Address20([2; 20]),
+            amount: Amount::from_attos(amount),
+            released: Amount::ZERO,
+            refunded: Amount::ZERO,
+            start_timestamp: Timestamp::from(start),
+            end_timestamp: Timestamp::from(end),
+            token: None,
+        }
+    }
+
+    #[test]
+    fn escrow_vests_linearly_over_the_period() {
+        let escrow = escrow(1_000, 100, 1_100);
+        assert_eq!(escrow.vested(Timestamp::from(0)), Amount::ZERO);
+        assert_eq!(escrow.vested(Timestamp::from(100)), Amount::ZERO);
+        assert_eq!(escrow.vested(Timestamp::from(350)), Amount::from_attos(250));
+        assert_eq!(escrow.vested(Timestamp::from(1_100)), Amount::from_attos(1_000));
+        assert_eq!(escrow.vested(Timestamp::from(5_000)), Amount::from_attos(1_000));
+    }
+
+    #[test]
+    fn escrow_vesting_rounds_down_and_does_not_overflow() {
+        let escrow = escrow(10, 0, 3);
+        assert_eq!(escrow.vested(Timestamp::from(1)), Amount::from_attos(3));
+        assert_eq!(escrow.vested(Timestamp::from(2)), Amount::from_attos(6));
+        assert_eq!(escrow.vested(Timestamp::from(3)), Amount::from_attos(10));
+
+        let escrow = self::escrow(u128::MAX, 0, u64::MAX);
+        assert_eq!(escrow.vested(Timestamp::from(u64::MAX)), Amount::MAX);
+        assert!(escrow.vested(Timestamp::from(u64::MAX / 2)) < Amount::from_attos(u128::MAX / 2));
+    }
+
+    #[test]
+    fn escrow_of_an_empty_period_is_vested_at_once() {
+        let escrow = escrow(1_000, 100, 100);
+        assert_eq!(escrow.vested(Timestamp::from(0)), Amount::from_attos(1_000));
+    }
+
+    #[test]
+    fn escrow_remaining_excludes_released_and_refunded() {
+        let mut escrow = escrow(1_000, 0, 1_000);
+        assert_eq!(escrow.remaining(), Amount::from_attos(1_000));
+        escrow.released = Amount::from_attos(300);
+        escrow.refunded = Amount::from_attos(500);
+        assert_eq!(escrow.remaining(), Amount::from_attos(200));
+        escrow.refunded = Amount::from_attos(800);
+        assert_eq!(escrow.remaining(), Amount::ZERO);
+    }
+
+    #[test]
+    fn envelope_opens_messages_of_the_current_layout() {
+        let message = Message::ExposureCapUpdate { follower: AccountOwner::CHAIN, cap_units: 5 };
+        let ReceivedMessage::Known(opened) = MessageEnvelope::from(message).open() else {
+            panic!("current message did not open");
+        };
+        assert!(matches!(*opened, Message::ExposureCapUpdate { cap_units: 5, .. }));
+    }
+
+    #[test]
+    fn envelope_of_another_wire_version_is_unknown() {
+        let message = Message::ExposureCapUpdate { follower: AccountOwner::CHAIN, cap_units: 5 };
+        let envelope = MessageEnvelope { version: MESSAGE_WIRE_VERSION + 1, ..message.into() };
+        assert!(matches!(envelope.open(), ReceivedMessage::Unknown { version: 2, tag: 19 }));
+    }
+
+    #[test]
+    fn first_release_messages_open_as_current_variants() {
+        // `SubscriptionConfirmed` as the first release sent it, bare and without the subscriber
+        let strategist_chain_id = ChainId(CryptoHash::from([1, 2, 3, 4]));
+        let mut bytes = vec![2];
+        bytes.extend(
+            bcs::to_bytes(&(
+                "sub-1-1".to_string(),
+                AccountOwner::CHAIN,
+                strategist_chain_id.to_string(),
+                2u64,
+            ))
+            .unwrap(),
+        );
+        let wire: WireMessage = bcs::from_bytes(&bytes).unwrap();
+        let subscriber = AccountOwner::Address20([9; 20]);
+        let now = Timestamp::from(1);
+        let ReceivedMessage::Known(opened) = wire.clone().open(None, Some(subscriber), now) else {
+            panic!("first-release confirmation did not open");
+        };
+        let Message::SubscriptionConfirmed { subscription_id: id, start_timestamp, is_trial, .. } =
+            *opened
+        else {
+            panic!("opened as another variant");
+        };
+        assert_eq!(id, subscription_id(&subscriber, &AccountOwner::CHAIN));
+        assert_eq!(start_timestamp, now);
+        assert!(!is_trial);
+
+        // Without a signer there is no subscriber to carry it over to
+        assert!(matches!(wire.open(None, None, now), ReceivedMessage::Unknown { version: 0, tag: 2 }));
+    }
+
+    #[test]
+    fn first_release_operations_decode_at_their_original_tags() {
+        let mut bytes = vec![0];
+        bytes.extend(bcs::to_bytes(&"alice".to_string()).unwrap());
+        let operation = AgentHubAbi::deserialize_operation(bytes).unwrap();
+        assert!(matches!(operation, Operation::LegacyRegisterStrategist { .. }));
+        assert!(matches!(
+            operation.upgrade(),
+            Operation::RegisterStrategist { referrer: None, deposit: None, .. }
+        ));
+
+        for operation in [
+            Operation::UnfollowStrategy { strategy_id: 3, reason: None },
+            Operation::RefundDeadLetter { sequence: 1 },
+        ] {
+            assert_eq!(bcs::to_bytes(&operation).unwrap()[0] as u32, operation.tag());
+        }
+    }
+
+    #[test]
+    fn unreadable_operations_decode_as_unknown() {
+        // A variant index past the last one, in two-byte ULEB128
+        let operation = AgentHubAbi::deserialize_operation(vec![0xc8, 0x01, 7]).unwrap();
+        assert!(matches!(operation, Operation::Unknown { tag: 200 }));
+        let operation = AgentHubAbi::deserialize_operation(Vec::new()).unwrap();
+        assert!(matches!(operation, Operation::Unknown { tag: 0 }));
+    }
+}
@@ -0,0 +1,561 @@
+// State schema migrations for AgentHub
+
+use agent_hub::{
+    merkle_append, normalize_name, subscription_id, AgentStrategy, CopySettings,
+    FirstReleaseSignal, Follower, FollowerKey, GlobalId, MarketKind, MarketStats, SignalLeaf,
+    SignalResult, SignalStatus, Strategist, StrategyStats, Subscription, SubscriptionOffer,
+};
+use linera_sdk::{
+    linera_base_types::{AccountOwner, ChainId, Timestamp},
+    views::{linera_views, MapView, RegisterView, RootView, View, ViewError, ViewStorageContext},
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::state::{
+    AgentHubState, CreationKey, FollowOrderKey, LeaderboardKey, MigrationPhase, MigrationProgress,
+    ResolutionKey, ReverseId,
+};
+
+/// Schema version written by this build. Bump it and add a step to `migrate_batch` whenever
+/// the stored layout changes in a way old data cannot be read as.
+pub const CURRENT_SCHEMA_VERSION: u32 = 1;
+
+/// Upgrade the next batch of first-release records to the current layout (0 -> 1). The first
+/// release's records predate most fields of their types, and BCS cannot fill in missing
+/// fields, so they are decoded through the frozen types below and written back in the current
+/// ones, with the indexes they imply. A chain may hold more than one block can convert, so the
+/// work is spread over as many blocks as it takes. Returns whether the state is current.
+pub async fn migrate_batch(state: &mut AgentHubState, chain_id: ChainId) -> Result<bool, ViewError> {
+    const BATCH_SIZE: usize = 100;
+
+    if *state.schema_version.get() >= CURRENT_SCHEMA_VERSION {
+        return Ok(true);
+    }
+    let legacy = LegacyAgentHubState::load(state.context().clone()).await?;
+    let mut progress = state.migration_progress.get().clone();
+
+    for _ in 0..BATCH_SIZE {
+        let phase = progress.phase;
+        let converted = match phase {
+            MigrationPhase::Strategists => {
+                if !progress.queued {
+                    queue_keys(state, &legacy.strategists).await?;
+                }
+                match next_queued::<AccountOwner>(state).await? {
+                    Some(owner) => convert_strategist(state, &legacy, owner).await?,
+                    None => false,
+                }
+            }
+            MigrationPhase::Strategies => {
+                let id = progress.converted + 1;
+                let more = id < *state.next_strategy_id.get();
+                if more {
+                    convert_strategy(state, &legacy, id, chain_id).await?;
+                }
+                more
+            }
+            MigrationPhase::Followers => {
+                if !progress.queued {
+                    queue_keys(state, &legacy.followers).await?;
+                }
+                match next_queued::<FollowerKey>(state).await? {
+                    Some(key) => convert_follower(state, &legacy, key, chain_id).await?,
+                    None => false,
+                }
+            }
+            MigrationPhase::StrategyStats => {
+                let id = progress.converted + 1;
+                let more = id < *state.next_strategy_id.get();
+                if more {
+                    convert_strategy_stats(state, &legacy, id).await?;
+                }
+                more
+            }
+            MigrationPhase::Signals => {
+                let id = progress.converted + 1;
+                let more = id < *state.next_signal_id.get();
+                if more {
+                    convert_signal(state, &legacy, id, chain_id).await?;
+                }
+                more
+            }
+            MigrationPhase::SubscriptionOffers => {
+                if !progress.queued {
+                    queue_keys(state, &legacy.subscription_offers).await?;
+                }
+                match next_queued::<AccountOwner>(state).await? {
+                    Some(strategist) => convert_subscription_offer(state, &legacy, strategist).await?,
+                    None => false,
+                }
+            }
+            MigrationPhase::Subscriptions => {
+                if !progress.queued {
+                    // Their lists name the sequential IDs, which are re-keyed below
+                    state.subscriptions_by_subscriber.clear();
+                    queue_keys(state, &legacy.subscriptions).await?;
+                }
+                match next_queued::<String>(state).await? {
+                    Some(id) => convert_subscription(state, &legacy, id, chain_id).await?,
+                    None => false,
+                }
+            }
+        };
+        progress.queued = true;
+
+        if converted {
+            progress.converted += 1;
+            continue;
+        }
+        let next = match phase {
+            MigrationPhase::Strategists => MigrationPhase::Strategies,
+            MigrationPhase::Strategies => MigrationPhase::Followers,
+            MigrationPhase::Followers => MigrationPhase::StrategyStats,
+            MigrationPhase::StrategyStats => MigrationPhase::Signals,
+            MigrationPhase::Signals => MigrationPhase::SubscriptionOffers,
+            MigrationPhase::SubscriptionOffers => MigrationPhase::Subscriptions,
+            MigrationPhase::Subscriptions => {
+                // Superseded by the set-backed indexes rebuilt above
+                state.legacy_signals_by_strategy.clear();
+                state.legacy_subscribers_by_strategist.clear();
+                state.migration_progress.set(MigrationProgress::default());
+                state.schema_version.set(CURRENT_SCHEMA_VERSION);
+                return Ok(true);
+            }
+        };
+        progress = MigrationProgress { phase: next, ..MigrationProgress::default() };
+    }
+    state.migration_progress.set(progress);
+    Ok(false)
+}
+
+/// Queue the keys of a first-release map for conversion. Only the keys are read, once; the
+/// records are read a batch at a time.
+async fn queue_keys<I, V>(
+    state: &mut AgentHubState,
+    map: &MapView<I, V>,
+) -> Result<(), ViewError>
+where
+    I: Serialize + DeserializeOwned + Send + Sync + 'static,
+    V: Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+{
+    for key in map.indices().await? {
+        state.migration_queue.push_back(bcs::to_bytes(&key)?);
+    }
+    Ok(())
+}
+
+/// Take the next queued key, if any are left
+async fn next_queued<I: DeserializeOwned>(state: &mut AgentHubState) -> Result<Option<I>, ViewError> {
+    let Some(bytes) = state.migration_queue.front().await? else {
+        return Ok(None);
+    };
+    state.migration_queue.delete_front();
+    Ok(Some(bcs::from_bytes(&bytes)?))
+}
+
+/// Convert a strategist. Display names became unique per chain; where names already collide,
+/// the earliest registration keeps the name.
+async fn convert_strategist(
+    state: &mut AgentHubState,
+    legacy: &LegacyAgentHubState,
+    owner: AccountOwner,
+) -> Result<bool, ViewError> {
+    let Some(strategist) = legacy.strategists.get(&owner).await? else {
+        return Ok(true);
+    };
+    let strategist = Strategist::from(strategist);
+    let key = normalize_name(&strategist.display_name);
+    if !key.is_empty() {
+        let holder = match state.name_index.get(&key).await? {
+            Some(holder) => state.strategists.get(&holder).await?,
+            None => None,
+        };
+        if holder.is_none_or(|holder| strategist.created_at < holder.created_at) {
+            state.name_index.insert(&key, owner)?;
+        }
+    }
+    state.strategists.insert(&owner, strategist)?;
+    state.global_counters.get_mut().total_strategists += 1;
+    Ok(true)
+}
+
+async fn convert_strategy(
+    state: &mut AgentHubState,
+    legacy: &LegacyAgentHubState,
+    id: u64,
+    chain_id: ChainId,
+) -> Result<(), ViewError> {
+    if let Some(strategy) = legacy.strategies.get(&id).await? {
+        state.strategies.insert(&id, strategy.upgrade(chain_id))?;
+        state.global_counters.get_mut().total_strategies += 1;
+    }
+    Ok(())
+}
+
+/// Convert a follow and index it. Stats count every account that ever followed a strategy;
+/// earlier unfollows were not kept, so the count starts from the current followers.
+async fn convert_follower(
+    state: &mut AgentHubState,
+    legacy: &LegacyAgentHubState,
+    key: FollowerKey,
+    chain_id: ChainId,
+) -> Result<bool, ViewError> {
+    let Some(follower) = legacy.followers.get(&key).await? else {
+        return Ok(true);
+    };
+    let order = FollowOrderKey { followed_at: follower.created_at, follower: key.follower };
+    state.followers_by_strategy.load_entry_mut(&key.strategy_id).await?.insert(&order)?;
+    state.follows_by_follower.load_entry_mut(&key.follower).await?.insert(&key.strategy_id)?;
+    state.past_followers.insert(&key)?;
+    let lifetime = state.lifetime_follower_count.get(&key.strategy_id).await?.unwrap_or(0);
+    state.lifetime_follower_count.insert(&key.strategy_id, lifetime + 1)?;
+    state.followers.insert(&key, follower.upgrade(chain_id))?;
+    state.global_counters.get_mut().active_follows += 1;
+    Ok(true)
+}
+
+/// Convert a strategy's stats and rank it on the leaderboard
+async fn convert_strategy_stats(
+    state: &mut AgentHubState,
+    legacy: &LegacyAgentHubState,
+    strategy_id: u64,
+) -> Result<(), ViewError> {
+    let Some(stats) = legacy.strategy_stats.get(&strategy_id).await? else {
+        return Ok(());
+    };
+    let mut stats = StrategyStats::from(stats);
+    stats.total_followers_ever = state.lifetime_follower_count.get(&strategy_id).await?.unwrap_or(0);
+    let is_public = matches!(
+        state.strategies.get(&strategy_id).await?,
+        Some(strategy) if strategy.is_public
+    );
+    if is_public && stats.total_signals > 0 {
+        let key = LeaderboardKey {
+            win_rate_bps: stats.win_rate_bps,
+            total_pnl_bps: stats.total_pnl_bps,
+            strategy_id,
+        };
+        state.leaderboard.insert(&key)?;
+        state.leaderboard_keys.insert(&strategy_id, key)?;
+    }
+    state.strategy_stats.insert(&strategy_id, stats)?;
+    Ok(())
+}
+
+/// Convert a signal and add it to every index a signal published now would be in. Signals
+/// are converted in ID order, which is publish order.
+async fn convert_signal(
+    state: &mut AgentHubState,
+    legacy: &LegacyAgentHubState,
+    signal_id: u64,
+    chain_id: ChainId,
+) -> Result<(), ViewError> {
+    let Some(signal) = legacy.signals.get(&signal_id).await? else {
+        return Ok(());
+    };
+    // Strategies had no versions before, so their signals all belong to the first
+    let signal = signal.upgrade(chain_id, 1);
+    let strategy_id = signal.strategy_id;
+
+    let counters = state.global_counters.get_mut();
+    counters.total_signals += 1;
+    match signal.status {
+        SignalStatus::Open => counters.open_signals += 1,
+        SignalStatus::Resolved => {
+            counters.resolved_signals += 1;
+            if signal.result == Some(SignalResult::Win) {
+                counters.winning_signals += 1;
+            }
+        }
+        SignalStatus::Cancelled => counters.cancelled_signals += 1,
+    }
+
+    state.signals_by_strategy.load_entry_mut(&strategy_id).await?.push(signal_id);
+    state.recent_signals_log.push(signal_id);
+    let creation = CreationKey { created_at: signal.created_at, signal_id };
+    state.signal_creation_index.insert(&creation)?;
+    state.strategy_creation_index.load_entry_mut(&strategy_id).await?.insert(&creation)?;
+
+    if signal.status == SignalStatus::Open {
+        let due = ResolutionKey { expires_at: signal.expires_at, signal_id };
+        state.pending_resolution.insert(&due)?;
+        state.resolution_schedule.insert(&due)?;
+        state.expiry_sweep.insert(&due)?;
+        let open = state.open_signal_count.get(&strategy_id).await?.unwrap_or(0);
+        state.open_signal_count.insert(&strategy_id, open + 1)?;
+    }
+
+    if let Some(leaf) = SignalLeaf::hash(&signal) {
+        let log = state.merkle_leaves.load_entry_mut(&strategy_id).await?;
+        let index = log.count() as u64;
+        log.push(leaf);
+        state.merkle_leaf_index.insert(&signal_id, index)?;
+        let mut peaks = state.merkle_peaks.get(&strategy_id).await?.unwrap_or_default();
+        merkle_append(&mut peaks, index, leaf);
+        state.merkle_peaks.insert(&strategy_id, peaks)?;
+    }
+
+    if let Some(mut strategy) = state.strategies.get(&strategy_id).await? {
+        let market = strategy.base_market.clone();
+        state.signals_by_market.load_entry_mut(&(market.clone(), signal.status)).await?
+            .insert(&ReverseId(signal_id))?;
+        if let (SignalStatus::Resolved, Some(result)) = (signal.status, signal.result) {
+            let mut stats = state.market_stats.get(&market).await?
+                .unwrap_or_else(|| MarketStats::new(market.clone()));
+            stats.record(result, signal.pnl_bps.unwrap_or(0));
+            state.market_stats.insert(&market, stats)?;
+        }
+        strategy.last_signal_at = Some(match strategy.last_signal_at {
+            Some(at) => at.max(signal.created_at),
+            None => signal.created_at,
+        });
+        state.strategies.insert(&strategy_id, strategy)?;
+    }
+
+    state.signals.insert(&signal_id, signal)?;
+    Ok(())
+}
+
+async fn convert_subscription_offer(
+    state: &mut AgentHubState,
+    legacy: &LegacyAgentHubState,
+    strategist: AccountOwner,
+) -> Result<bool, ViewError> {
+    if let Some(offer) = legacy.subscription_offers.get(&strategist).await? {
+        state.subscription_offers.insert(&strategist, offer.into())?;
+    }
+    Ok(true)
+}
+
+/// Convert a subscription and index it. Subscription IDs were sequential; they are now
+/// derived from the (subscriber, strategist) pair, so the record is re-keyed, keeping the
+/// later-ending one where a pair has several.
+async fn convert_subscription(
+    state: &mut AgentHubState,
+    legacy: &LegacyAgentHubState,
+    old_id: String,
+    chain_id: ChainId,
+) -> Result<bool, ViewError> {
+    let Some(subscription) = legacy.subscriptions.get(&old_id).await? else {
+        return Ok(true);
+    };
+    state.subscriptions.remove(&old_id)?;
+    let Some(subscription) = subscription.upgrade() else {
+        return Ok(true);
+    };
+    let id = subscription.id.clone();
+    let counted = |subscription: &Subscription| {
+        subscription.subscriber_chain_id == chain_id && subscription.is_active
+    };
+    match state.subscriptions.get(&id).await? {
+        Some(kept) if kept.end_timestamp >= subscription.end_timestamp => return Ok(true),
+        Some(kept) if counted(&kept) => state.global_counters.get_mut().active_subscriptions -= 1,
+        _ => {}
+    }
+
+    if subscription.subscriber_chain_id == chain_id {
+        let mut ids = state.subscriptions_by_subscriber.get(&subscription.subscriber).await?
+            .unwrap_or_default();
+        if !ids.contains(&id) {
+            ids.push(id.clone());
+            state.subscriptions_by_subscriber.insert(&subscription.subscriber, ids)?;
+        }
+        state.local_subscribers.load_entry_mut(&subscription.strategist).await?
+            .insert(&subscription.subscriber)?;
+    }
+    if counted(&subscription) {
+        state.global_counters.get_mut().active_subscriptions += 1;
+    }
+    if subscription.strategist_chain_id == chain_id {
+        state.subscribers_by_strategist.load_entry_mut(&subscription.strategist).await?.insert(&id)?;
+    }
+    state.subscriptions.insert(&id, subscription)?;
+    Ok(true)
+}
+
+// ============================================================================
+// FIRST-RELEASE LAYOUT
+// ============================================================================
+
+/// The views of the first release up to the last one converted, in its field order so each
+/// resolves to the same storage key. Loaded over the live state for each batch, only read, never
+/// saved: it sees the records as stored, not the batch's pending conversions.
+#[derive(RootView)]
+#[view(context = ViewStorageContext)]
+struct LegacyAgentHubState {
+    hub_chain_id: RegisterView<Option<ChainId>>,
+    strategists: MapView<AccountOwner, LegacyStrategist>,
+    strategies: MapView<u64, LegacyAgentStrategy>,
+    signals: MapView<u64, FirstReleaseSignal>,
+    signals_by_strategy: MapView<u64, Vec<u64>>,
+    strategy_stats: MapView<u64, LegacyStrategyStats>,
+    followers: MapView<FollowerKey, LegacyFollower>,
+    follower_count: MapView<u64, u64>,
+    next_strategy_id: RegisterView<u64>,
+    next_signal_id: RegisterView<u64>,
+    subscription_offers: MapView<AccountOwner, LegacySubscriptionOffer>,
+    subscriptions: MapView<String, LegacySubscription>,
+}
+
+// Record types as the first release encoded them. Never change these.
+
+#[derive(Clone, Serialize, Deserialize)]
+struct LegacyStrategist {
+    owner: AccountOwner,
+    display_name: String,
+    created_at: Timestamp,
+}
+
+impl From<LegacyStrategist> for Strategist {
+    fn from(legacy: LegacyStrategist) -> Self {
+        Strategist {
+            owner: legacy.owner,
+            display_name: legacy.display_name,
+            referrer: None,
+            created_at: legacy.created_at,
+            verified: false,
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct LegacyAgentStrategy {
+    id: u64,
+    owner: AccountOwner,
+    name: String,
+    description: String,
+    market_kind: MarketKind,
+    base_market: String,
+    is_public: bool,
+    is_ai_controlled: bool,
+    created_at: Timestamp,
+}
+
+impl LegacyAgentStrategy {
+    fn upgrade(self, chain_id: ChainId) -> AgentStrategy {
+        AgentStrategy {
+            id: self.id,
+            global_id: GlobalId::new(chain_id, self.id),
+            owner: self.owner,
+            name: self.name,
+            description: self.description,
+            market_kind: self.market_kind,
+            base_market: self.base_market,
+            is_public: self.is_public,
+            is_ai_controlled: self.is_ai_controlled,
+            push_band_bps: 0,
+            forked_from: None,
+            model: None,
+            agent_key: None,
+            version: 1,
+            version_started_at: self.created_at,
+            performance_fee_bps: 0,
+            is_paused: false,
+            pause_reason: None,
+            max_followers: None,
+            tags: Vec::new(),
+            last_signal_at: None,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct LegacyStrategyStats {
+    strategy_id: u64,
+    total_signals: u64,
+    winning_signals: u64,
+    losing_signals: u64,
+    push_signals: u64,
+    win_rate_bps: u32,
+    avg_pnl_bps: i32,
+    total_pnl_bps: i64,
+    followers: u64,
+}
+
+impl From<LegacyStrategyStats> for StrategyStats {
+    fn from(legacy: LegacyStrategyStats) -> Self {
+        StrategyStats {
+            strategy_id: legacy.strategy_id,
+            total_signals: legacy.total_signals,
+            winning_signals: legacy.winning_signals,
+            losing_signals: legacy.losing_signals,
+            push_signals: legacy.push_signals,
+            win_rate_bps: legacy.win_rate_bps,
+            avg_pnl_bps: legacy.avg_pnl_bps,
+            total_pnl_bps: legacy.total_pnl_bps,
+            followers: legacy.followers,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct LegacyFollower {
+    strategy_id: u64,
+    follower: AccountOwner,
+    auto_copy: bool,
+    max_exposure_units: u64,
+    created_at: Timestamp,
+}
+
+impl LegacyFollower {
+    fn upgrade(self, chain_id: ChainId) -> Follower {
+        Follower {
+            strategy_id: self.strategy_id,
+            follower: self.follower,
+            chain_id,
+            auto_copy: self.auto_copy,
+            max_exposure_units: self.max_exposure_units,
+            created_at: self.created_at,
+            copy_settings: CopySettings::default(),
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct LegacySubscription {
+    id: String,
+    subscriber: AccountOwner,
+    subscriber_chain_id: String,
+    strategist: AccountOwner,
+    strategist_chain_id: String,
+    /// In microseconds
+    start_timestamp: u64,
+    end_timestamp: u64,
+    is_active: bool,
+}
+
+impl LegacySubscription {
+    /// The subscription under its derived ID, or `None` if a chain ID does not parse
+    fn upgrade(self) -> Option<Subscription> {
+        Some(Subscription {
+            id: subscription_id(&self.subscriber, &self.strategist),
+            subscriber: self.subscriber,
+            subscriber_chain_id: self.subscriber_chain_id.parse().ok()?,
+            strategist: self.strategist,
+            strategist_chain_id: self.strategist_chain_id.parse().ok()?,
+            start_timestamp: Timestamp::from(self.start_timestamp),
+            end_timestamp: Timestamp::from(self.end_timestamp),
+            is_active: self.is_active,
+            is_trial: false,
+        })
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct LegacySubscriptionOffer {
+    strategist: AccountOwner,
+    description: Option<String>,
+    is_enabled: bool,
+}
+
+impl From<LegacySubscriptionOffer> for SubscriptionOffer {
+    fn from(legacy: LegacySubscriptionOffer) -> Self {
+        SubscriptionOffer {
+            description: legacy.description,
+            is_enabled: legacy.is_enabled,
+            ..SubscriptionOffer::disabled(legacy.strategist)
+        }
+    }
+}
@@ -2,21 +2,35 @@
 
 mod state;
 
-use std::sync::Arc;
+use std::{collections::BTreeSet, sync::Arc};
 
 use agent_hub::{
-    AgentHubAbi, AgentStrategy, MarketKind, Operation, Signal, SignalStatus,
-    StrategyStats, StrategyWithStats, Subscription, SubscriptionOffer,
+    day_of, merkle_parent, merkle_root, normalize_name, subscription_id, AgentHubAbi,
+    AgentHubParameters, AgentStrategy, Badge, CALIBRATION_BUCKETS, CalibrationBucket, ChangeRecord,
+    ChurnStats, CopierStats, CopySkip, DAY_MICROS, DailyPnl, DeadLetter, Direction, DirectionCount,
+    Follower, FollowerExposure, FollowerKey, FollowerSnapshot, FollowerStats, GlobalCounters,
+    GlobalId, GlobalTopStrategy, HubLeaderboard, LifetimePerformance, Market, MarketKind,
+    MarketSentiment, MarketStats, MerkleStep, MirroredStrategy, NotificationPreferences, Operation,
+    OutboundRequest, PendingSignal, PlatformStats, Position, PositionStatus, PriceObservation,
+    Proposal, RateLimitConfig, RegistrationDeposit, RemoteFollow, RequestStatus, ResolutionRules,
+    Role, RoleAssignment, Signal, SignalCommitment, SignalInclusionProof, SignalResult,
+    SignalStatus, SimulatedPnl, Strategist, StrategistOverview, StrategyComparison, StrategySort,
+    StrategyStats, StrategySummary, StrategyVersion, StrategyWithStats, Subscription,
+    SubscriptionEscrow, SubscriptionOffer, TagCount, Tip, Tournament, TournamentEntry,
+    TrackRecordExport, WindowPerformance,
 };
-use async_graphql::{EmptySubscription, Object, Schema};
+use async_graphql::{EmptySubscription, ErrorExtensions, Object, Schema};
 use linera_sdk::{
     graphql::GraphQLMutationRoot as _,
-    linera_base_types::{AccountOwner, WithServiceAbi},
+    linera_base_types::{
+        AccountOwner, Amount, ChainId, CryptoHash, Ed25519PublicKey, TimeDelta, Timestamp,
+        WithServiceAbi,
+    },
     views::View,
     Service, ServiceRuntime,
 };
 
-use self::state::AgentHubState;
+use self::state::{AgentHubState, CreationKey, FollowOrderKey, ReverseId};
 
 /// The AgentHub service for GraphQL queries.
 #[derive(Clone)]
@@ -31,7 +45,7 @@ impl WithServiceAbi for AgentHubService {
 }
 
 impl Service for AgentHubService {
-    type Parameters = ();
+    type Parameters = AgentHubParameters;
 
     async fn new(runtime: ServiceRuntime<Self>) -> Self {
         AgentHubService {
@@ -48,6 +62,7 @@ impl Service for AgentHubService {
         let schema = Schema::build(
             QueryRoot {
                 state: Arc::new(state),
+                runtime: self.runtime.clone(),
             },
             Operation::mutation_root(self.runtime.clone()),
             EmptySubscription,
@@ -57,26 +72,182 @@ impl Service for AgentHubService {
     }
 }
 
+// ============================================================================
+// INPUT PARSING
+// ============================================================================
+
+/// Parse an account owner argument, reporting the offending field instead of
+/// silently returning an empty result
+fn parse_owner(field: &str, value: &str) -> async_graphql::Result<AccountOwner> {
+    value.parse().map_err(|_| {
+        async_graphql::Error::new(format!("Invalid account owner for `{field}`: {value:?}"))
+            .extend_with(|_, extensions| {
+                extensions.set("code", "INVALID_ACCOUNT_OWNER");
+                extensions.set("field", field);
+            })
+    })
+}
+
 // ============================================================================
 // QUERY ROOT
 // ============================================================================
 
 struct QueryRoot {
     state: Arc<AgentHubState>,
+    runtime: Arc<ServiceRuntime<AgentHubService>>,
+}
+
+impl QueryRoot {
+    /// Whether restricted `signal` belongs in listings for `viewer`: disclosed signals are
+    /// listed for everyone, restricted ones only for the strategist and their active
+    /// subscribers. This is a presentation filter, not access control: `viewer` is whatever
+    /// address the caller passes, and restricted signals are stored in the chain's state, so
+    /// anyone who syncs the chain can read them.
+    async fn can_view_signal(
+        &self,
+        viewer: Option<&AccountOwner>,
+        signal: &Signal,
+        strategist: &AccountOwner,
+        now: Timestamp,
+    ) -> bool {
+        if signal.is_disclosed(now) {
+            return true;
+        }
+        let Some(viewer) = viewer else {
+            return false;
+        };
+        if viewer == strategist {
+            return true;
+        }
+        self.state.subscriptions.get(&subscription_id(viewer, strategist)).await
+            .ok().flatten()
+            .is_some_and(|sub| sub.grants_access(now))
+    }
+
+    /// Owner of a local strategy, if it exists and its signals are listed for `viewer`:
+    /// private strategies only list them for their owner and invited accounts (an unverified
+    /// filter, like `can_view_signal`)
+    async fn strategy_owner(&self, strategy_id: u64, viewer: Option<&AccountOwner>) -> Option<AccountOwner> {
+        let strategy = self.state.strategies.get(&strategy_id).await.ok().flatten()?;
+        if strategy.is_public || viewer == Some(&strategy.owner) {
+            return Some(strategy.owner);
+        }
+        let viewer = viewer?;
+        let invitees = self.state.strategy_invites.get(&strategy_id).await
+            .ok().flatten().unwrap_or_default();
+        invitees.contains(viewer).then_some(strategy.owner)
+    }
+
+    /// Whether a local strategy's signals may appear in listings without a viewer
+    async fn is_listed(&self, strategy_id: u64) -> bool {
+        self.strategy_owner(strategy_id, None).await.is_some()
+    }
+
+    /// Resolver listing of an open local signal, withholding the call unless it is public
+    async fn pending_signal(&self, signal: Signal, now: Timestamp) -> PendingSignal {
+        let base_market = self.state.strategies.get(&signal.strategy_id).await
+            .ok().flatten()
+            .map(|strategy| strategy.base_market)
+            .unwrap_or_default();
+        let shown = signal.is_disclosed(now) && self.is_listed(signal.strategy_id).await;
+        PendingSignal {
+            id: signal.id,
+            strategy_id: signal.strategy_id,
+            base_market,
+            expires_at: signal.expires_at,
+            signal: shown.then_some(signal),
+        }
+    }
+
+    /// IDs of a market's signals with `status`, newest first
+    async fn market_signal_ids(&self, base_market: &str, status: SignalStatus) -> Vec<u64> {
+        let key = (base_market.to_string(), status);
+        let Ok(Some(ids)) = self.state.signals_by_market.try_load_entry(&key).await else {
+            return Vec::new();
+        };
+        ids.indices().await.unwrap_or_default().into_iter().map(|id| id.0).collect()
+    }
+
+    /// Strategist whose stream delivered a received signal
+    async fn received_strategist(&self, id: &GlobalId) -> Option<AccountOwner> {
+        self.state.received_signal_strategists.get(id).await.ok().flatten()
+    }
+
+    /// Local strategies an account follows
+    async fn followed_strategy_ids(&self, owner: &AccountOwner) -> Vec<u64> {
+        match self.state.follows_by_follower.try_load_entry(owner).await {
+            Ok(Some(ids)) => ids.indices().await.unwrap_or_default(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// IDs of the subscriptions to a strategist
+    async fn strategist_subscription_ids(&self, strategist: &AccountOwner) -> Vec<String> {
+        match self.state.subscribers_by_strategist.try_load_entry(strategist).await {
+            Ok(Some(ids)) => ids.indices().await.unwrap_or_default(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Global IDs of the signals received from a strategist's stream
+    async fn received_signal_ids(&self, strategist: &AccountOwner) -> Vec<GlobalId> {
+        match self.state.received_signals_by_strategist.try_load_entry(strategist).await {
+            Ok(Some(ids)) => ids.indices().await.unwrap_or_default(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// IDs of signals created in `[from, to)`, oldest first, from a strategy's creation-time
+    /// index or, without a strategy, the chain-wide one. Open bounds default to all time.
+    async fn signal_ids_created_between(
+        &self,
+        strategy_id: Option<u64>,
+        from: Option<Timestamp>,
+        to: Option<Timestamp>,
+    ) -> Vec<u64> {
+        let from = from.unwrap_or(Timestamp::from(0));
+        let to = to.unwrap_or(Timestamp::from(u64::MAX));
+        let mut ids = Vec::new();
+        let collect = |key: CreationKey| {
+            if key.created_at >= to {
+                return Ok(false);
+            }
+            if key.created_at >= from {
+                ids.push(key.signal_id);
+            }
+            Ok(true)
+        };
+        let _ = match strategy_id {
+            Some(strategy_id) => match self.state.strategy_creation_index.try_load_entry(&strategy_id).await {
+                Ok(Some(index)) => index.for_each_index_while(collect).await,
+                _ => Ok(()),
+            },
+            None => self.state.signal_creation_index.for_each_index_while(collect).await,
+        };
+        ids
+    }
 }
 
 #[Object]
 impl QueryRoot {
     /// Get all public strategies with optional filtering
+    #[allow(clippy::too_many_arguments)]
     async fn public_strategies(
         &self,
         market_kind: Option<MarketKind>,
         base_market: Option<String>,
+        model_name: Option<String>,
+        sort: Option<StrategySort>,
         limit: Option<i32>,
         offset: Option<i32>,
+        verified_only: Option<bool>,
+        active_within_hours: Option<u64>,
     ) -> Vec<AgentStrategy> {
         let limit = limit.unwrap_or(50) as usize;
         let offset = offset.unwrap_or(0) as usize;
+        let active_since = active_within_hours.map(|hours| {
+            Timestamp::from(self.runtime.system_time().micros().saturating_sub(hours.saturating_mul(3_600_000_000)))
+        });
         
         let mut strategies = Vec::new();
         let mut count = 0u64;
@@ -104,11 +275,57 @@ impl QueryRoot {
                     }
                 }
                 
+                // Filter by model name if specified
+                if let Some(ref name) = model_name {
+                    if strategy.model.as_ref().is_none_or(|model| &model.model_name != name) {
+                        continue;
+                    }
+                }
+                
+                // Filter to strategies that signaled recently if requested
+                if let Some(since) = active_since {
+                    if strategy.last_signal_at.is_none_or(|at| at < since) {
+                        continue;
+                    }
+                }
+                
+                // Filter to moderator-verified strategists if requested
+                if verified_only.unwrap_or(false) {
+                    let verified = matches!(
+                        self.state.strategists.get(&strategy.owner).await,
+                        Ok(Some(strategist)) if strategist.verified
+                    );
+                    if !verified {
+                        continue;
+                    }
+                }
+                
                 strategies.push(strategy);
             } else {
                 break;
             }
         }
+
+        // Apply sort (IDs are assigned in creation order; the rest come from maintained stats)
+        match sort {
+            None => {}
+            Some(StrategySort::Newest) => strategies.reverse(),
+            Some(sort) => {
+                let mut keyed = Vec::with_capacity(strategies.len());
+                for strategy in strategies {
+                    let stats = self.state.strategy_stats.get(&strategy.id).await
+                        .ok().flatten().unwrap_or_default();
+                    let key = match sort {
+                        StrategySort::MostFollowers => stats.followers,
+                        StrategySort::BestWinRate => stats.win_rate_bps as u64,
+                        _ => stats.total_signals,
+                    };
+                    keyed.push((key, strategy));
+                }
+                keyed.sort_by_key(|(key, _)| std::cmp::Reverse(*key));
+                strategies = keyed.into_iter().map(|(_, strategy)| strategy).collect();
+            }
+        }
         
         // Apply pagination
         strategies.into_iter().skip(offset).take(limit).collect()
@@ -119,35 +336,262 @@ impl QueryRoot {
         self.state.strategies.get(&id).await.ok().flatten()
     }
 
-    /// Get signals for a strategy
+    /// Get the strategies forked from a strategy on this chain
+    async fn strategy_forks(&self, strategy_id: u64) -> Vec<AgentStrategy> {
+        let fork_ids = self.state.forks_by_strategy.get(&strategy_id).await
+            .ok().flatten().unwrap_or_default();
+        let mut forks = Vec::new();
+        for id in fork_ids {
+            if let Ok(Some(strategy)) = self.state.strategies.get(&id).await {
+                forks.push(strategy);
+            }
+        }
+        forks
+    }
+
+    /// Get a strategy's fork ancestry, nearest source first. Ancestors hosted on other
+    /// chains are listed by ID but not followed further.
+    async fn fork_lineage(&self, strategy_id: u64) -> Vec<GlobalId> {
+        let chain_id = self.runtime.chain_id();
+        let mut lineage = Vec::new();
+        let mut current = self.state.strategies.get(&strategy_id).await.ok().flatten();
+        while let Some(source) = current.and_then(|strategy| strategy.forked_from) {
+            if lineage.contains(&source) {
+                break;
+            }
+            lineage.push(source);
+            current = if source.chain_id == chain_id {
+                self.state.strategies.get(&source.local_id).await.ok().flatten()
+            } else {
+                None
+            };
+        }
+        lineage
+    }
+
+    /// Get a committed signal awaiting reveal
+    async fn signal_commitment(&self, signal_id: u64) -> Option<SignalCommitment> {
+        self.state.signal_commitments.get(&signal_id).await.ok().flatten()
+    }
+
+    /// Check a signal's attestation: true only if one is attached and its signature
+    /// covers the signal as published
+    async fn verify_attestation(&self, signal_id: u64) -> bool {
+        let Ok(Some(signal)) = self.state.signals.get(&signal_id).await else {
+            return false;
+        };
+        signal.attestation.is_some_and(|attestation| attestation.verify(&signal))
+    }
+
+    /// Get signals for a strategy. Subscriber-only signals are included when `viewer` is the
+    /// strategist or one of their active subscribers; private strategies return nothing
+    /// unless `viewer` is the owner or invited. `viewer` is not authenticated, so this only
+    /// filters the listing (see `can_view_signal`). `from`/`to` restrict it to signals created
+    /// in that time range.
+    #[allow(clippy::too_many_arguments)]
     async fn strategy_signals(
         &self,
         strategy_id: u64,
         limit: Option<i32>,
         offset: Option<i32>,
-    ) -> Vec<Signal> {
+        viewer: Option<String>,
+        from: Option<Timestamp>,
+        to: Option<Timestamp>,
+    ) -> async_graphql::Result<Vec<Signal>> {
+        let viewer = viewer.map(|viewer| parse_owner("viewer", &viewer)).transpose()?;
+        let Some(strategist) = self.strategy_owner(strategy_id, viewer.as_ref()).await else {
+            return Ok(Vec::new());
+        };
         let limit = limit.unwrap_or(50) as usize;
         let offset = offset.unwrap_or(0) as usize;
         
-        let signal_ids = self.state.signals_by_strategy.get(&strategy_id).await
-            .ok().flatten().unwrap_or_default();
+        // Newest first: read the requested window from the tail of the log, or of the
+        // creation-time index when a time range is given
+        let signal_ids = if from.is_some() || to.is_some() {
+            let ids = self.signal_ids_created_between(Some(strategy_id), from, to).await;
+            let end = ids.len().saturating_sub(offset);
+            ids[end.saturating_sub(limit)..end].to_vec()
+        } else {
+            let count = self.state.strategy_signal_count(strategy_id).await.unwrap_or(0);
+            let end = count.saturating_sub(offset);
+            self.state
+                .strategy_signal_ids(strategy_id, end.saturating_sub(limit)..end)
+                .await
+                .unwrap_or_default()
+        };
         
+        let now = self.runtime.system_time();
         let mut signals = Vec::new();
-        for signal_id in signal_ids {
+        for signal_id in signal_ids.into_iter().rev() {
             if let Ok(Some(signal)) = self.state.signals.get(&signal_id).await {
-                signals.push(signal);
+                if self.can_view_signal(viewer.as_ref(), &signal, &strategist, now).await {
+                    signals.push(signal);
+                }
             }
         }
         
-        // Sort by created_at DESC (newest first)
-        signals.sort_by(|a, b| b.created_at.cmp(&a.created_at));
-        
-        signals.into_iter().skip(offset).take(limit).collect()
+        Ok(signals)
     }
 
-    /// Get a single signal by ID
-    async fn signal(&self, id: u64) -> Option<Signal> {
-        self.state.signals.get(&id).await.ok().flatten()
+    /// Get a single signal by ID (left out for non-subscriber viewers while restricted; see
+    /// `can_view_signal`)
+    async fn signal(&self, id: u64, viewer: Option<String>) -> async_graphql::Result<Option<Signal>> {
+        let viewer = viewer.map(|viewer| parse_owner("viewer", &viewer)).transpose()?;
+        let Ok(Some(signal)) = self.state.signals.get(&id).await else {
+            return Ok(None);
+        };
+        let Some(strategist) = self.strategy_owner(signal.strategy_id, viewer.as_ref()).await else {
+            return Ok(None);
+        };
+        let now = self.runtime.system_time();
+        let visible = self.can_view_signal(viewer.as_ref(), &signal, &strategist, now).await;
+        Ok(visible.then_some(signal))
+    }
+
+    /// Get a signal by its cross-chain global ID (local or received via broadcast). Restricted
+    /// received signals are shown when `viewer` subscribes to their strategist, an unverified
+    /// filter (see `can_view_signal`).
+    async fn signal_by_global_id(
+        &self,
+        id: GlobalId,
+        viewer: Option<String>,
+    ) -> async_graphql::Result<Option<Signal>> {
+        let viewer = viewer.map(|viewer| parse_owner("viewer", &viewer)).transpose()?;
+        let now = self.runtime.system_time();
+        if id.chain_id == self.runtime.chain_id() {
+            let Ok(Some(signal)) = self.state.signals.get(&id.local_id).await else {
+                return Ok(None);
+            };
+            let Some(strategist) = self.strategy_owner(signal.strategy_id, viewer.as_ref()).await else {
+                return Ok(None);
+            };
+            let visible = self.can_view_signal(viewer.as_ref(), &signal, &strategist, now).await;
+            return Ok(visible.then_some(signal));
+        }
+
+        let Ok(Some(signal)) = self.state.received_signals.get(&id).await else {
+            return Ok(None);
+        };
+        if signal.is_disclosed(now) {
+            return Ok(Some(signal));
+        }
+        let Some(strategist) = self.received_strategist(&id).await else {
+            return Ok(None);
+        };
+        let visible = self.can_view_signal(viewer.as_ref(), &signal, &strategist, now).await;
+        Ok(visible.then_some(signal))
+    }
+
+    /// Get read-only copies of strategies mirrored from other chains
+    async fn mirrored_strategies(&self) -> Vec<MirroredStrategy> {
+        self.state.mirrored_strategies.index_values().await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(_, mirror)| mirror)
+            .collect()
+    }
+
+    /// Get a mirrored strategy by its global ID
+    async fn mirrored_strategy(&self, id: GlobalId) -> Option<MirroredStrategy> {
+        self.state.mirrored_strategies.get(&id).await.ok().flatten()
+    }
+
+    /// Get signals received from strategies on other chains. Restricted ones are included
+    /// only for a `viewer` subscribed to their strategist, an unverified filter (see
+    /// `can_view_signal`).
+    async fn received_signals(
+        &self,
+        limit: Option<i32>,
+        viewer: Option<String>,
+    ) -> async_graphql::Result<Vec<Signal>> {
+        let limit = limit.unwrap_or(50) as usize;
+        let viewer = viewer.map(|viewer| parse_owner("viewer", &viewer)).transpose()?;
+        let now = self.runtime.system_time();
+
+        let mut signals = Vec::new();
+        for strategist in self.state.received_signals_by_strategist.indices().await.unwrap_or_default() {
+            for id in self.received_signal_ids(&strategist).await {
+                if let Ok(Some(signal)) = self.state.received_signals.get(&id).await {
+                    if self.can_view_signal(viewer.as_ref(), &signal, &strategist, now).await {
+                        signals.push(signal);
+                    }
+                }
+            }
+        }
+
+        // Sort by created_at DESC
+        signals.sort_by_key(|s| std::cmp::Reverse(s.created_at));
+
+        Ok(signals.into_iter().take(limit).collect())
+    }
+
+    /// Get a personalized feed of signals from every strategy a user follows and every
+    /// strategist they subscribe to, newest first. `owner` is not authenticated; restricted
+    /// signals are filtered as in `can_view_signal`.
+    async fn my_feed(&self, owner: String, limit: Option<i32>) -> async_graphql::Result<Vec<Signal>> {
+        let limit = limit.unwrap_or(50) as usize;
+        let owner_account = parse_owner("owner", &owner)?;
+
+        let now = self.runtime.system_time();
+        let mut signals = Vec::new();
+
+        // Local follows (subscriber-only signals need an active subscription)
+        for strategy_id in self.followed_strategy_ids(&owner_account).await {
+            let Some(strategist) = self.strategy_owner(strategy_id, Some(&owner_account)).await else {
+                continue;
+            };
+            let count = self.state.strategy_signal_count(strategy_id).await.unwrap_or(0);
+            let signal_ids = self.state.strategy_signal_ids(strategy_id, 0..count).await.unwrap_or_default();
+            for signal_id in signal_ids {
+                if let Ok(Some(signal)) = self.state.signals.get(&signal_id).await {
+                    if self.can_view_signal(Some(&owner_account), &signal, &strategist, now).await {
+                        signals.push(signal);
+                    }
+                }
+            }
+        }
+
+        // Remote follows: only the followed strategies of each strategist
+        let remote_follows = self.state.remote_follows.get(&owner_account).await
+            .ok().flatten().unwrap_or_default();
+        for follow in remote_follows {
+            for id in self.received_signal_ids(&follow.strategist).await {
+                if let Ok(Some(signal)) = self.state.received_signals.get(&id).await {
+                    if id.chain_id == follow.strategy.chain_id
+                        && signal.strategy_id == follow.strategy.local_id
+                        && self.can_view_signal(Some(&owner_account), &signal, &follow.strategist, now).await
+                    {
+                        signals.push(signal);
+                    }
+                }
+            }
+        }
+
+        // Subscriptions: everything the strategist publishes
+        let sub_ids = self.state.subscriptions_by_subscriber.get(&owner_account).await
+            .ok().flatten().unwrap_or_default();
+        for sub_id in sub_ids {
+            let Ok(Some(sub)) = self.state.subscriptions.get(&sub_id).await else {
+                continue;
+            };
+            if !sub.grants_access(now) {
+                continue;
+            }
+            for id in self.received_signal_ids(&sub.strategist).await {
+                if let Ok(Some(signal)) = self.state.received_signals.get(&id).await {
+                    signals.push(signal);
+                }
+            }
+        }
+
+        // Follows and subscriptions can overlap
+        signals.sort_by_key(|s| s.global_id);
+        signals.dedup_by_key(|s| s.global_id);
+
+        // Sort by created_at DESC
+        signals.sort_by_key(|s| std::cmp::Reverse(s.created_at));
+
+        Ok(signals.into_iter().take(limit).collect())
     }
 
     /// Get strategy statistics
@@ -155,43 +599,457 @@ impl QueryRoot {
         self.state.strategy_stats.get(&strategy_id).await.ok().flatten()
     }
 
+    /// Get a strategy's realized win rate per declared confidence range, lowest first
+    async fn calibration(&self, strategy_id: u64) -> Vec<CalibrationBucket> {
+        let mut buckets = Vec::new();
+        for index in 0..CALIBRATION_BUCKETS {
+            let bucket = self.state.calibration.get(&(strategy_id, index)).await
+                .ok().flatten().unwrap_or_else(|| CalibrationBucket::empty(index));
+            buckets.push(bucket);
+        }
+        buckets
+    }
+
+    /// Get a strategy's resolutions per day over the last `days` days (today included), oldest
+    /// first, with empty days filled in
+    async fn daily_pnl(&self, strategy_id: u64, days: u32) -> Vec<DailyPnl> {
+        const MAX_DAYS: u32 = 366;
+
+        let today = day_of(self.runtime.system_time());
+        let first = today.saturating_sub(days.clamp(1, MAX_DAYS) - 1);
+        let mut buckets = Vec::new();
+        for day in first..=today {
+            let bucket = self.state.daily_pnl.get(&(strategy_id, day)).await
+                .ok().flatten().unwrap_or_else(|| DailyPnl::empty(day));
+            buckets.push(bucket);
+        }
+        buckets
+    }
+
+    /// Get a strategy's follower count per day over the last `days` days (today included),
+    /// oldest first, with days without changes filled in
+    async fn follower_history(&self, strategy_id: u64, days: u32) -> Vec<FollowerSnapshot> {
+        const MAX_DAYS: u32 = 366;
+
+        let today = day_of(self.runtime.system_time());
+        let first = today.saturating_sub(days.clamp(1, MAX_DAYS) - 1);
+        // Walk back from the current count: a day without a snapshot ended where the next began
+        let mut closing = self.state.follower_count.get(&strategy_id).await
+            .ok().flatten().unwrap_or(0);
+        let mut history = Vec::new();
+        for day in (first..=today).rev() {
+            let snapshot = self.state.follower_history.get(&(strategy_id, day)).await
+                .ok().flatten()
+                .unwrap_or(FollowerSnapshot { day, opening_followers: closing, followers: closing });
+            closing = snapshot.opening_followers;
+            history.push(snapshot);
+        }
+        history.reverse();
+        history
+    }
+
+    /// Get why followers left a strategy, for its strategist
+    async fn churn_stats(&self, strategy_id: u64) -> ChurnStats {
+        self.state.churn_stats.get(&strategy_id).await
+            .ok().flatten()
+            .unwrap_or(ChurnStats { strategy_id, ..Default::default() })
+    }
+
+    /// Get the retired versions of a strategy with their frozen stats, oldest first
+    async fn strategy_versions(&self, strategy_id: u64) -> Vec<StrategyVersion> {
+        let current = match self.state.strategies.get(&strategy_id).await {
+            Ok(Some(strategy)) => strategy.version,
+            _ => return Vec::new(),
+        };
+        let mut versions = Vec::new();
+        for version in 0..current {
+            if let Ok(Some(retired)) = self.state.strategy_versions.get(&(strategy_id, version)).await {
+                versions.push(retired);
+            }
+        }
+        versions
+    }
+
+    /// Current Merkle root over a strategy's resolved signals
+    async fn merkle_root(&self, strategy_id: u64) -> Option<CryptoHash> {
+        let peaks = self.state.merkle_peaks.get(&strategy_id).await.ok().flatten()?;
+        merkle_root(&peaks)
+    }
+
+    /// Proof that a resolved signal is included in its strategy's current Merkle root
+    async fn signal_inclusion_proof(&self, signal_id: u64) -> Option<SignalInclusionProof> {
+        let signal = self.state.signals.get(&signal_id).await.ok().flatten()?;
+        let leaf_index = self.state.merkle_leaf_index.get(&signal_id).await.ok().flatten()?;
+        let strategy_id = signal.strategy_id;
+        let peaks = self.state.merkle_peaks.get(&strategy_id).await.ok().flatten()?;
+        let log = self.state.merkle_leaves.try_load_entry(&strategy_id).await.ok().flatten()?;
+        let leaf_count = log.count() as u64;
+        let leaves = log.read(0..log.count()).await.ok()?;
+
+        // Peaks are perfect subtrees, one per set bit of the leaf count, largest first
+        let mut start = 0u64;
+        let mut peak_index = 0usize;
+        let mut peak_size = 0u64;
+        for bit in (0..u64::BITS).rev() {
+            let size = 1u64 << bit;
+            if leaf_count & size == 0 {
+                continue;
+            }
+            if leaf_index < start + size {
+                peak_size = size;
+                break;
+            }
+            start += size;
+            peak_index += 1;
+        }
+        if peak_size == 0 {
+            return None;
+        }
+
+        // Path up to the root of the leaf's peak
+        let mut level = leaves.get(start as usize..(start + peak_size) as usize)?.to_vec();
+        let mut position = (leaf_index - start) as usize;
+        let mut path = Vec::new();
+        while level.len() > 1 {
+            let sibling = position ^ 1;
+            path.push(MerkleStep { hash: level[sibling], is_left: sibling < position });
+            level = level.chunks(2).map(|pair| merkle_parent(&pair[0], &pair[1])).collect();
+            position /= 2;
+        }
+
+        // Then through the bagged peaks: smaller ones on the right, larger ones on the left
+        if let Some(rest) = merkle_root(peaks.get(peak_index + 1..)?) {
+            path.push(MerkleStep { hash: rest, is_left: false });
+        }
+        for peak in peaks[..peak_index].iter().rev() {
+            path.push(MerkleStep { hash: *peak, is_left: true });
+        }
+
+        Some(SignalInclusionProof {
+            signal_id,
+            strategy_id,
+            leaf_index,
+            leaf_count,
+            leaf: *leaves.get(leaf_index as usize)?,
+            path,
+            root: merkle_root(&peaks)?,
+        })
+    }
+
+    /// Get a strategy's published track-record blobs, oldest first
+    async fn track_record_exports(&self, strategy_id: u64) -> Vec<TrackRecordExport> {
+        self.state.track_record_exports.get(&strategy_id).await
+            .ok().flatten().unwrap_or_default()
+    }
+
+    /// Get a strategy's stats for one version (the live stats for its current version)
+    async fn strategy_version_stats(&self, strategy_id: u64, version: u32) -> Option<StrategyStats> {
+        let strategy = self.state.strategies.get(&strategy_id).await.ok().flatten()?;
+        if version == strategy.version {
+            return self.state.strategy_stats.get(&strategy_id).await.ok().flatten();
+        }
+        self.state.strategy_versions.get(&(strategy_id, version)).await
+            .ok().flatten()
+            .map(|retired| retired.stats)
+    }
+
+    /// Suggest public strategies on the markets an account already follows or subscribes to,
+    /// leaving out its own and those it follows, best-reputed strategists first
+    async fn recommended_strategies(
+        &self,
+        owner: String,
+        limit: Option<i32>,
+    ) -> async_graphql::Result<Vec<StrategyWithStats>> {
+        let owner_account = parse_owner("owner", &owner)?;
+        let limit = limit.unwrap_or(10).clamp(1, 50) as usize;
+        let now = self.runtime.system_time();
+
+        let followed = self.followed_strategy_ids(&owner_account).await;
+        let sub_ids = self.state.subscriptions_by_subscriber.get(&owner_account).await
+            .ok().flatten().unwrap_or_default();
+        let mut subscribed = Vec::new();
+        for sub_id in sub_ids {
+            if let Ok(Some(sub)) = self.state.subscriptions.get(&sub_id).await {
+                if sub.grants_access(now) {
+                    subscribed.push(sub.strategist);
+                }
+            }
+        }
+
+        let mut strategies = Vec::new();
+        let mut count = 0u64;
+        while let Ok(Some(strategy)) = self.state.strategies.get(&(count + 1)).await {
+            count += 1;
+            strategies.push(strategy);
+        }
+        let markets: Vec<&String> = strategies.iter()
+            .filter(|strategy| followed.contains(&strategy.id) || subscribed.contains(&strategy.owner))
+            .map(|strategy| &strategy.base_market)
+            .collect();
+
+        let mut ranked = Vec::new();
+        for strategy in &strategies {
+            if !strategy.is_public
+                || strategy.owner == owner_account
+                || followed.contains(&strategy.id)
+                || !markets.contains(&&strategy.base_market)
+            {
+                continue;
+            }
+            let reputation = self.state.reputation.get(&strategy.owner).await
+                .ok().flatten().unwrap_or(0);
+            let stats = self.state.strategy_stats.get(&strategy.id).await
+                .ok().flatten().unwrap_or_default();
+            ranked.push((reputation, stats, strategy.clone()));
+        }
+        ranked.sort_by(|(rep_a, stats_a, a), (rep_b, stats_b, b)| {
+            rep_b.cmp(rep_a)
+                .then_with(|| stats_b.win_rate_bps.cmp(&stats_a.win_rate_bps))
+                .then_with(|| a.id.cmp(&b.id))
+        });
+
+        Ok(ranked.into_iter()
+            .take(limit)
+            .map(|(_, stats, strategy)| StrategyWithStats { strategy, stats })
+            .collect())
+    }
+
+    /// Get how well all strategies' resolved signals on a base market did
+    async fn market_stats(&self, base_market: String) -> MarketStats {
+        self.state.market_stats.get(&base_market).await
+            .ok().flatten()
+            .unwrap_or_else(|| MarketStats::new(base_market))
+    }
+
+    /// Summarize the disclosed open signals on a base market: calls per direction, their
+    /// confidence-weighted net bias and how many strategies are making them
+    async fn market_sentiment(&self, base_market: String) -> MarketSentiment {
+        let now = self.runtime.system_time();
+        let ids = self.market_signal_ids(&base_market, SignalStatus::Open).await;
+
+        let mut counts = [0u64; Direction::ALL.len()];
+        let mut range_signals = 0;
+        let (mut weighted, mut total_confidence) = (0i64, 0i64);
+        let mut strategies = BTreeSet::new();
+        for id in ids {
+            let Ok(Some(signal)) = self.state.signals.get(&id).await else {
+                continue;
+            };
+            if !signal.is_disclosed(now) || !self.is_listed(signal.strategy_id).await {
+                continue;
+            }
+            strategies.insert(signal.strategy_id);
+            if signal.range.is_some() {
+                range_signals += 1;
+                continue;
+            }
+            if let Some(index) = Direction::ALL.iter().position(|d| *d == signal.direction) {
+                counts[index] += 1;
+            }
+            let confidence = signal.confidence_bps as i64;
+            total_confidence += confidence;
+            weighted += if signal.direction.is_bullish() { confidence } else { -confidence };
+        }
+
+        MarketSentiment {
+            base_market,
+            open_signals: counts.iter().sum::<u64>() + range_signals,
+            by_direction: Direction::ALL.iter()
+                .zip(counts)
+                .filter(|(_, signals)| *signals > 0)
+                .map(|(direction, signals)| DirectionCount { direction: *direction, signals })
+                .collect(),
+            range_signals,
+            net_bias_bps: (weighted * 10000).checked_div(total_confidence).unwrap_or(0) as i32,
+            strategies: strategies.len() as u64,
+        }
+    }
+
+    /// Get public strategies carrying a tag, oldest first
+    async fn strategies_by_tag(&self, tag: String, limit: Option<i32>) -> Vec<StrategyWithStats> {
+        let limit = limit.unwrap_or(50).clamp(1, 200) as usize;
+
+        let ids = self.state.strategies_by_tag.get(&normalize_name(&tag)).await
+            .ok().flatten().unwrap_or_default();
+        let mut strategies = Vec::new();
+        for strategy_id in ids.into_iter().take(limit) {
+            if let Ok(Some(strategy)) = self.state.strategies.get(&strategy_id).await {
+                let stats = self.state.strategy_stats.get(&strategy_id).await
+                    .ok().flatten().unwrap_or_default();
+                strategies.push(StrategyWithStats { strategy, stats });
+            }
+        }
+        strategies
+    }
+
+    /// Get the tags carried by the most public strategies
+    async fn popular_tags(&self, limit: Option<i32>) -> Vec<TagCount> {
+        let limit = limit.unwrap_or(20).clamp(1, 100) as usize;
+
+        let mut tags: Vec<TagCount> = self.state.strategies_by_tag.index_values().await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(tag, ids)| TagCount { tag, strategies: ids.len() as u64 })
+            .collect();
+        tags.sort_by(|a, b| b.strategies.cmp(&a.strategies).then_with(|| a.tag.cmp(&b.tag)));
+        tags.truncate(limit);
+        tags
+    }
+
     /// Get top strategies by win rate
     async fn top_strategies(&self, limit: Option<i32>) -> Vec<StrategyWithStats> {
         let limit = limit.unwrap_or(10) as usize;
         
+        // The leaderboard index is kept sorted by win rate DESC, then by total PnL DESC
+        let mut top_ids = Vec::new();
+        let _ = self.state.leaderboard.for_each_index_while(|key| {
+            top_ids.push(key.strategy_id);
+            Ok(top_ids.len() < limit)
+        }).await;
+        top_ids.truncate(limit);
+        
         let mut strategies_with_stats = Vec::new();
-        let mut count = 0u64;
+        for strategy_id in top_ids {
+            if let Ok(Some(strategy)) = self.state.strategies.get(&strategy_id).await {
+                let stats = self.state.strategy_stats.get(&strategy_id).await
+                    .ok().flatten().unwrap_or_default();
+                strategies_with_stats.push(StrategyWithStats { strategy, stats });
+            }
+        }
         
-        // Collect all public strategies with their stats
-        loop {
-            count += 1;
-            match self.state.strategies.get(&count).await {
-                Ok(Some(strategy)) if strategy.is_public => {
-                    let stats = self.state.strategy_stats.get(&count).await
-                        .ok().flatten().unwrap_or_default();
-                    
-                    // Only include strategies with at least 1 resolved signal
-                    if stats.total_signals > 0 {
-                        strategies_with_stats.push(StrategyWithStats { strategy, stats });
+        strategies_with_stats
+    }
+
+    /// Get the followers with the best realized copy-trading results, ranked by realized PnL,
+    /// then hit rate
+    async fn top_copiers(&self, limit: Option<i32>) -> Vec<CopierStats> {
+        let limit = limit.unwrap_or(10).clamp(1, 100) as usize;
+
+        let mut top = Vec::new();
+        let _ = self.state.copier_board.for_each_index_while(|key| {
+            top.push(key.follower);
+            Ok(top.len() < limit)
+        }).await;
+
+        let mut copiers = Vec::new();
+        for follower in top {
+            if let Ok(Some(stats)) = self.state.copier_stats.get(&follower).await {
+                copiers.push(stats);
+            }
+        }
+        copiers
+    }
+
+    /// Compare listed strategies side by side, in the order requested. Unknown and private
+    /// strategies are left out.
+    async fn compare_strategies(&self, ids: Vec<u64>) -> async_graphql::Result<Vec<StrategyComparison>> {
+        const MAX_COMPARED: usize = 10;
+        const WINDOW_DAYS: [u32; 3] = [7, 30, 90];
+
+        if ids.len() > MAX_COMPARED {
+            return Err(async_graphql::Error::new(format!("At most {MAX_COMPARED} strategies can be compared"))
+                .extend_with(|_, extensions| extensions.set("code", "TOO_MANY_STRATEGIES")));
+        }
+
+        let now = self.runtime.system_time();
+        let mut comparisons = Vec::new();
+        for strategy_id in ids {
+            if !self.is_listed(strategy_id).await {
+                continue;
+            }
+            let Ok(Some(strategy)) = self.state.strategies.get(&strategy_id).await else {
+                continue;
+            };
+            let stats = self.state.strategy_stats.get(&strategy_id).await
+                .ok().flatten().unwrap_or_default();
+
+            let mut windows: Vec<WindowPerformance> = WINDOW_DAYS.iter()
+                .map(|&window_days| WindowPerformance { window_days, signals: 0, win_rate_bps: 0, total_pnl_bps: 0 })
+                .collect();
+            let mut wins = [0u64; WINDOW_DAYS.len()];
+            let (mut cumulative, mut peak, mut max_drawdown) = (0i64, 0i64, 0i64);
+
+            let count = self.state.strategy_signal_count(strategy_id).await.unwrap_or(0);
+            for signal_id in self.state.strategy_signal_ids(strategy_id, 0..count).await.unwrap_or_default() {
+                let Ok(Some(signal)) = self.state.signals.get(&signal_id).await else {
+                    continue;
+                };
+                if signal.status != SignalStatus::Resolved || signal.strategy_version != strategy.version {
+                    continue;
+                }
+                let pnl = signal.pnl_bps.unwrap_or(0);
+                cumulative = cumulative.saturating_add(pnl);
+                peak = peak.max(cumulative);
+                max_drawdown = max_drawdown.max(peak.saturating_sub(cumulative));
+
+                let age_micros = now.delta_since(signal.created_at).as_micros();
+                for (index, window) in windows.iter_mut().enumerate() {
+                    if age_micros < window.window_days as u64 * DAY_MICROS {
+                        window.signals += 1;
+                        window.total_pnl_bps = window.total_pnl_bps.saturating_add(pnl);
+                        if signal.result == Some(SignalResult::Win) {
+                            wins[index] += 1;
+                        }
                     }
                 }
-                Ok(Some(_)) => continue,
-                _ => break,
+            }
+            for (window, wins) in windows.iter_mut().zip(wins) {
+                window.win_rate_bps = (wins * 10000).checked_div(window.signals).unwrap_or(0) as u32;
+            }
+
+            comparisons.push(StrategyComparison {
+                strategy_id,
+                name: strategy.name,
+                sample_size: stats.total_signals,
+                win_rate_bps: stats.win_rate_bps,
+                avg_pnl_bps: stats.avg_pnl_bps,
+                total_pnl_bps: stats.total_pnl_bps,
+                max_drawdown_bps: max_drawdown,
+                windows,
+            });
+        }
+        Ok(comparisons)
+    }
+
+    /// Get signals on a market across all strategies, most recently published first
+    async fn signals_by_market(
+        &self,
+        base_market: String,
+        status: Option<SignalStatus>,
+        limit: Option<i32>,
+    ) -> Vec<Signal> {
+        let limit = limit.unwrap_or(50) as usize;
+        let statuses = match status {
+            Some(status) => vec![status],
+            None => vec![SignalStatus::Open, SignalStatus::Resolved, SignalStatus::Cancelled],
+        };
+        let now = self.runtime.system_time();
+
+        // Each bucket is newest first; merge their IDs and load signals only until the page is full
+        let mut ids = Vec::new();
+        for status in statuses {
+            ids.extend(self.market_signal_ids(&base_market, status).await);
+        }
+        ids.sort_unstable_by(|a, b| b.cmp(a));
+
+        let mut signals = Vec::new();
+        for id in ids {
+            if signals.len() >= limit {
+                break;
+            }
+            if let Ok(Some(signal)) = self.state.signals.get(&id).await {
+                if signal.is_disclosed(now) && self.is_listed(signal.strategy_id).await {
+                    signals.push(signal);
+                }
             }
         }
-        
-        // Sort by win rate DESC, then by total PnL DESC
-        strategies_with_stats.sort_by(|a, b| {
-            b.stats.win_rate_bps.cmp(&a.stats.win_rate_bps)
-                .then_with(|| b.stats.total_pnl_bps.cmp(&a.stats.total_pnl_bps))
-        });
-        
-        strategies_with_stats.into_iter().take(limit).collect()
+        signals
     }
 
     /// Get all open signals across all strategies
     async fn open_signals(&self, limit: Option<i32>) -> Vec<Signal> {
         let limit = limit.unwrap_or(50) as usize;
+        let now = self.runtime.system_time();
         
         let mut signals = Vec::new();
         let mut count = 0u64;
@@ -199,8 +1057,10 @@ impl QueryRoot {
         loop {
             count += 1;
             match self.state.signals.get(&count).await {
-                Ok(Some(signal)) if signal.status == SignalStatus::Open => {
-                    signals.push(signal);
+                Ok(Some(signal)) if signal.status == SignalStatus::Open && signal.is_disclosed(now) => {
+                    if self.is_listed(signal.strategy_id).await {
+                        signals.push(signal);
+                    }
                 }
                 Ok(Some(_)) => continue,
                 _ => break,
@@ -208,32 +1068,301 @@ impl QueryRoot {
         }
         
         // Sort by created_at DESC
-        signals.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        signals.sort_by_key(|s| std::cmp::Reverse(s.created_at));
         
         signals.into_iter().take(limit).collect()
     }
 
-    /// Get recent signals
-    async fn recent_signals(&self, limit: Option<i32>) -> Vec<Signal> {
+    /// Get open signals awaiting resolution, oldest-expiring first. Resolvers need every open
+    /// signal, so restricted ones are listed too, without their call.
+    async fn pending_resolution(&self, limit: Option<i32>, only_expired: Option<bool>) -> Vec<PendingSignal> {
         let limit = limit.unwrap_or(50) as usize;
-        
+        let only_expired = only_expired.unwrap_or(false);
+        let now = self.runtime.system_time();
+
+        let mut ids = Vec::new();
+        let _ = self.state.pending_resolution.for_each_index_while(|key| {
+            if ids.len() >= limit || (only_expired && key.expires_at > now) {
+                return Ok(false);
+            }
+            ids.push(key.signal_id);
+            Ok(true)
+        }).await;
+
+        let mut pending = Vec::new();
+        for id in ids {
+            if let Ok(Some(signal)) = self.state.signals.get(&id).await {
+                pending.push(self.pending_signal(signal, now).await);
+            }
+        }
+        pending
+    }
+
+    /// Get open signals expiring within the next `within_secs`, soonest first, read from the
+    /// expiry-ordered resolution schedule. Like `pending_resolution`, lists restricted signals
+    /// without their call.
+    async fn expiring_signals(&self, within_secs: u64, limit: Option<i32>) -> Vec<PendingSignal> {
+        let limit = limit.unwrap_or(50) as usize;
+        let now = self.runtime.system_time();
+        let until = now.saturating_add(TimeDelta::from_secs(within_secs));
+
+        let mut ids = Vec::new();
+        let _ = self.state.resolution_schedule.for_each_index_while(|key| {
+            if key.expires_at > until {
+                return Ok(false);
+            }
+            if key.expires_at > now {
+                ids.push(key.signal_id);
+            }
+            Ok(true)
+        }).await;
+
         let mut signals = Vec::new();
-        let mut count = 0u64;
+        for id in ids {
+            if signals.len() >= limit {
+                break;
+            }
+            if let Ok(Some(signal)) = self.state.signals.get(&id).await {
+                if signal.status == SignalStatus::Open {
+                    signals.push(self.pending_signal(signal, now).await);
+                }
+            }
+        }
+        signals
+    }
+
+    /// Get open signals already past expiry with no resolution yet, most overdue first, so
+    /// stale calls are visible and keepers know what to clean up (restricted ones without
+    /// their call)
+    async fn overdue_signals(&self, limit: Option<i32>) -> Vec<PendingSignal> {
+        let limit = limit.unwrap_or(50) as usize;
+        let now = self.runtime.system_time();
+
+        let mut ids = Vec::new();
+        let _ = self.state.pending_resolution.for_each_index_while(|key| {
+            if ids.len() >= limit || key.expires_at > now {
+                return Ok(false);
+            }
+            ids.push(key.signal_id);
+            Ok(true)
+        }).await;
+
+        let mut pending = Vec::new();
+        for id in ids {
+            if let Ok(Some(signal)) = self.state.signals.get(&id).await {
+                pending.push(self.pending_signal(signal, now).await);
+            }
+        }
+        pending
+    }
+
+    /// Get recent signals, optionally only those created between `from` and `to`
+    async fn recent_signals(
+        &self,
+        limit: Option<i32>,
+        from: Option<Timestamp>,
+        to: Option<Timestamp>,
+    ) -> Vec<Signal> {
+        let limit = limit.unwrap_or(50) as usize;
         
-        loop {
-            count += 1;
-            match self.state.signals.get(&count).await {
-                Ok(Some(signal)) => {
+        // Read only the tail of the publish log, or of the creation-time index when a time
+        // range is given, newest first
+        let ids = if from.is_some() || to.is_some() {
+            let ids = self.signal_ids_created_between(None, from, to).await;
+            ids[ids.len().saturating_sub(limit)..].to_vec()
+        } else {
+            let count = self.state.recent_signals_log.count();
+            self.state.recent_signals_log
+                .read(count.saturating_sub(limit)..count).await
+                .unwrap_or_default()
+        };
+        
+        let now = self.runtime.system_time();
+        let mut signals = Vec::new();
+        for id in ids.into_iter().rev() {
+            if let Ok(Some(signal)) = self.state.signals.get(&id).await {
+                if signal.is_disclosed(now) && self.is_listed(signal.strategy_id).await {
                     signals.push(signal);
                 }
-                _ => break,
             }
         }
         
-        // Sort by created_at DESC
-        signals.sort_by(|a, b| b.created_at.cmp(&a.created_at));
-        
-        signals.into_iter().take(limit).collect()
+        signals
+    }
+
+    /// Change-feed records after `sequence` (0 = from the start), oldest first
+    async fn changes_since(&self, sequence: u64, limit: Option<i32>) -> Vec<ChangeRecord> {
+        let limit = limit.unwrap_or(100) as usize;
+        let count = self.state.change_log.count();
+        let start = (sequence as usize).min(count);
+        self.state.change_log
+            .read(start..count.min(start.saturating_add(limit))).await
+            .unwrap_or_default()
+    }
+
+    /// Get a strategy's followers in follow order, starting after the `after` cursor
+    async fn strategy_followers(
+        &self,
+        strategy_id: u64,
+        after: Option<AccountOwner>,
+        first: Option<i32>,
+    ) -> Vec<Follower> {
+        let first = first.unwrap_or(50) as usize;
+        let Ok(Some(index)) = self.state.followers_by_strategy.try_load_entry(&strategy_id).await else {
+            return Vec::new();
+        };
+
+        // The cursor's follow time locates it in the index
+        let mut cursor = match after {
+            Some(follower) => match self.state.followers.get(&FollowerKey { strategy_id, follower }).await {
+                Ok(Some(record)) => Some(FollowOrderKey { followed_at: record.created_at, follower }),
+                _ => return Vec::new(),
+            },
+            None => None,
+        };
+        let mut owners = Vec::new();
+        let _ = index.for_each_index_while(|entry| {
+            if cursor.is_some() {
+                if cursor == Some(entry) {
+                    cursor = None;
+                }
+                return Ok(true);
+            }
+            if owners.len() >= first {
+                return Ok(false);
+            }
+            owners.push(entry.follower);
+            Ok(true)
+        }).await;
+
+        let mut followers = Vec::new();
+        for follower in owners {
+            let key = FollowerKey { strategy_id, follower };
+            if let Ok(Some(record)) = self.state.followers.get(&key).await {
+                followers.push(record);
+            }
+        }
+        followers
+    }
+
+    /// Get the accounts blocked from following a strategy
+    async fn blocked_followers(&self, strategy_id: u64) -> Vec<AccountOwner> {
+        self.state.blocked_followers.get(&strategy_id).await
+            .ok().flatten().unwrap_or_default()
+    }
+
+    /// Get the accounts invited to a private strategy
+    async fn strategy_invites(&self, strategy_id: u64) -> Vec<AccountOwner> {
+        self.state.strategy_invites.get(&strategy_id).await
+            .ok().flatten().unwrap_or_default()
+    }
+
+    /// Get the follow requests waiting for a slot on a full strategy, oldest first
+    async fn follower_waitlist(&self, strategy_id: u64) -> Vec<Follower> {
+        self.state.follower_waitlist.get(&strategy_id).await
+            .ok().flatten().unwrap_or_default()
+    }
+
+    /// Get the key a resolver signs resolutions with, if registered
+    async fn resolver_key(&self, resolver: String) -> async_graphql::Result<Option<Ed25519PublicKey>> {
+        let resolver = parse_owner("resolver", &resolver)?;
+        Ok(self.state.resolver_keys.get(&resolver).await.ok().flatten())
+    }
+
+    /// Get the price observations submitted for a signal's time-weighted resolution
+    async fn price_observations(&self, signal_id: u64) -> Vec<PriceObservation> {
+        self.state.price_observations.get(&signal_id).await
+            .ok().flatten().unwrap_or_default()
+    }
+
+    /// Get an account's open exposure across its positions on this chain, and its cap
+    async fn my_exposure(&self, owner: String) -> async_graphql::Result<FollowerExposure> {
+        let owner_account = parse_owner("owner", &owner)?;
+
+        Ok(FollowerExposure {
+            owner: owner_account,
+            open_units: self.state.open_exposure.get(&owner_account).await
+                .ok().flatten().unwrap_or(0),
+            cap_units: self.state.exposure_caps.get(&owner_account).await
+                .ok().flatten().unwrap_or(0),
+        })
+    }
+
+    /// Get a position by ID
+    async fn position(&self, id: u64) -> Option<Position> {
+        self.state.positions.get(&id).await.ok().flatten()
+    }
+
+    /// Get an account's positions, newest first, optionally only those in `status`
+    async fn my_positions(
+        &self,
+        owner: String,
+        status: Option<PositionStatus>,
+        limit: Option<i32>,
+    ) -> async_graphql::Result<Vec<Position>> {
+        let owner_account = parse_owner("owner", &owner)?;
+        let limit = limit.unwrap_or(50).clamp(1, 200) as usize;
+
+        let ids = match self.state.positions_by_owner.try_load_entry(&owner_account).await {
+            Ok(Some(ids)) => ids.indices().await.unwrap_or_default(),
+            _ => Vec::new(),
+        };
+        let mut positions = Vec::new();
+        for ReverseId(id) in ids {
+            if positions.len() >= limit {
+                break;
+            }
+            let Ok(Some(position)) = self.state.positions.get(&id).await else {
+                continue;
+            };
+            if status.is_none_or(|status| position.status == status) {
+                positions.push(position);
+            }
+        }
+        Ok(positions)
+    }
+
+    /// Get a follower's realized copy-trading results on every strategy they copied
+    async fn my_copy_stats(&self, owner: String) -> async_graphql::Result<Vec<FollowerStats>> {
+        let owner_account = parse_owner("owner", &owner)?;
+
+        let strategy_ids = self.state.copy_stats_by_follower.get(&owner_account).await
+            .ok().flatten().unwrap_or_default();
+        let mut stats = Vec::new();
+        for strategy_id in strategy_ids {
+            let key = FollowerKey { strategy_id, follower: owner_account };
+            if let Ok(Some(entry)) = self.state.follower_stats.get(&key).await {
+                stats.push(entry);
+            }
+        }
+        Ok(stats)
+    }
+
+    /// Get the paper-trading results of a follower in simulation mode on a strategy
+    async fn simulated_pnl(&self, strategy_id: u64, follower: String) -> async_graphql::Result<SimulatedPnl> {
+        let follower = parse_owner("follower", &follower)?;
+        let key = FollowerKey { strategy_id, follower };
+        Ok(self.state.simulated_pnl.get(&key).await.ok().flatten().unwrap_or_default())
+    }
+
+    /// Get the signals an auto-copy follower's risk limits skipped recently, oldest first
+    async fn copy_skips(&self, strategy_id: u64, follower: String) -> async_graphql::Result<Vec<CopySkip>> {
+        let follower = parse_owner("follower", &follower)?;
+        let key = FollowerKey { strategy_id, follower };
+        Ok(self.state.copy_skips.get(&key).await.ok().flatten().unwrap_or_default())
+    }
+
+    /// Get an account's notification preferences (everything, if never set)
+    async fn notification_preferences(&self, owner: String) -> async_graphql::Result<NotificationPreferences> {
+        let owner = parse_owner("owner", &owner)?;
+        Ok(self.state.notification_preferences.get(&owner).await
+            .ok().flatten().unwrap_or_default())
+    }
+
+    /// Get a strategist's registration deposit and its refund progress
+    async fn registration_deposit(&self, owner: String) -> async_graphql::Result<Option<RegistrationDeposit>> {
+        let owner = parse_owner("owner", &owner)?;
+        Ok(self.state.registration_deposits.get(&owner).await.ok().flatten())
     }
 
     /// Check if a user is following a strategy
@@ -241,26 +1370,64 @@ impl QueryRoot {
         &self,
         strategy_id: u64,
         follower: String,
-    ) -> bool {
+    ) -> async_graphql::Result<bool> {
         // Parse follower address
-        let follower_owner: AccountOwner = match follower.parse() {
-            Ok(o) => o,
-            Err(_) => return false,
-        };
+        let follower_owner = parse_owner("follower", &follower)?;
         
         let key = agent_hub::FollowerKey {
             strategy_id,
             follower: follower_owner,
         };
-        self.state.followers.contains_key(&key).await.unwrap_or(false)
+        Ok(self.state.followers.contains_key(&key).await.unwrap_or(false))
+    }
+
+    /// Get strategies on other chains followed by a user from this chain
+    async fn my_remote_follows(&self, follower: String) -> async_graphql::Result<Vec<RemoteFollow>> {
+        let follower_owner = parse_owner("follower", &follower)?;
+
+        Ok(self.state.remote_follows.get(&follower_owner).await
+            .ok().flatten().unwrap_or_default())
+    }
+
+    /// Get the requests an account sent from this chain to others, newest first, optionally
+    /// only those in `status`
+    async fn outbound_requests(
+        &self,
+        requester: String,
+        status: Option<RequestStatus>,
+        limit: Option<i32>,
+    ) -> async_graphql::Result<Vec<OutboundRequest>> {
+        let requester_account = parse_owner("requester", &requester)?;
+        let limit = limit.unwrap_or(50).clamp(1, 200) as usize;
+
+        let mut requests: Vec<OutboundRequest> = self.state.outbound_requests.index_values().await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(_, request)| request)
+            .filter(|request| request.requester == requester_account)
+            .filter(|request| status.is_none_or(|status| request.status == status))
+            .collect();
+        requests.sort_by_key(|request| std::cmp::Reverse(request.sent_at));
+        requests.truncate(limit);
+        Ok(requests)
+    }
+
+    /// Incoming messages whose handlers failed on storage, newest first
+    async fn dead_letters(&self, limit: Option<i32>) -> Vec<DeadLetter> {
+        let limit = limit.unwrap_or(50).clamp(1, 200) as usize;
+        let count = *self.state.dead_letter_count.get();
+        let mut letters = Vec::new();
+        for sequence in (1..=count).rev().take(limit) {
+            if let Ok(Some(letter)) = self.state.dead_letters.get(&sequence).await {
+                letters.push(letter);
+            }
+        }
+        letters
     }
 
     /// Get strategies owned by a specific user
-    async fn my_strategies(&self, owner: String) -> Vec<AgentStrategy> {
-        let owner_account: AccountOwner = match owner.parse() {
-            Ok(o) => o,
-            Err(_) => return Vec::new(),
-        };
+    async fn my_strategies(&self, owner: String) -> async_graphql::Result<Vec<AgentStrategy>> {
+        let owner_account = parse_owner("owner", &owner)?;
         
         let mut strategies = Vec::new();
         let mut count = 0u64;
@@ -276,17 +1443,355 @@ impl QueryRoot {
             }
         }
         
-        strategies
+        Ok(strategies)
     }
 
-    /// Check if a user is registered as a strategist
-    async fn is_strategist(&self, owner: String) -> bool {
-        let owner_account: AccountOwner = match owner.parse() {
-            Ok(o) => o,
-            Err(_) => return false,
+    /// Get a strategist's profile: record, strategies with stats, offer, subscribers and lifetime performance
+    async fn strategist_overview(&self, owner: String) -> async_graphql::Result<Option<StrategistOverview>> {
+        let owner_account = parse_owner("owner", &owner)?;
+        let Some(strategist) = self.state.strategists.get(&owner_account).await.ok().flatten() else {
+            return Ok(None);
         };
+
+        let mut strategies = Vec::new();
+        let mut lifetime = LifetimePerformance::default();
+        let mut count = 0u64;
+
+        loop {
+            count += 1;
+            match self.state.strategies.get(&count).await {
+                Ok(Some(strategy)) if strategy.owner == owner_account => {
+                    let stats = self.state.strategy_stats.get(&count).await
+                        .ok().flatten().unwrap_or_default();
+                    lifetime.total_signals += stats.total_signals;
+                    lifetime.winning_signals += stats.winning_signals;
+                    lifetime.losing_signals += stats.losing_signals;
+                    lifetime.push_signals += stats.push_signals;
+                    lifetime.total_pnl_bps += stats.total_pnl_bps;
+                    lifetime.followers += stats.followers;
+                    strategies.push(StrategyWithStats { strategy, stats });
+                }
+                Ok(Some(_)) => continue,
+                _ => break,
+            }
+        }
+        lifetime.win_rate_bps = (lifetime.winning_signals * 10000)
+            .checked_div(lifetime.total_signals)
+            .unwrap_or(0) as u32;
+
+        let subscription_offer = self.state.subscription_offers.get(&owner_account).await
+            .ok().flatten();
+
+        let sub_ids = self.strategist_subscription_ids(&owner_account).await;
+        let mut subscriber_count = 0u64;
+        for sub_id in sub_ids {
+            if let Ok(Some(sub)) = self.state.subscriptions.get(&sub_id).await {
+                if sub.is_active {
+                    subscriber_count += 1;
+                }
+            }
+        }
+
+        let lifetime_tips = self.state.tips_received.get(&owner_account).await
+            .ok().flatten().unwrap_or_default();
+
+        Ok(Some(StrategistOverview {
+            strategist,
+            strategies,
+            subscription_offer,
+            subscriber_count,
+            lifetime,
+            lifetime_tips,
+        }))
+    }
+
+    /// Get the performance fees accrued to a strategist and not yet claimed
+    async fn performance_fees(&self, owner: String) -> async_graphql::Result<Amount> {
+        let owner_account = parse_owner("owner", &owner)?;
+        Ok(self.state.performance_fees.get(&owner_account).await
+            .ok().flatten().unwrap_or_default())
+    }
+
+    /// Get a follower's remaining performance-fee allowance for a strategy
+    async fn fee_allowance(&self, strategy_id: u64, follower: String) -> async_graphql::Result<Amount> {
+        let follower = parse_owner("follower", &follower)?;
+        let key = FollowerKey { strategy_id, follower };
+        Ok(self.state.fee_allowances.get(&key).await.ok().flatten().unwrap_or_default())
+    }
+
+    /// Get tips a strategist received on this chain, newest first
+    async fn strategist_tips(&self, owner: String, limit: Option<i32>) -> async_graphql::Result<Vec<Tip>> {
+        let limit = limit.unwrap_or(50) as usize;
+        let owner_account = parse_owner("owner", &owner)?;
+        let tips = self.state.tips_by_strategist.get(&owner_account).await
+            .ok().flatten().unwrap_or_default();
+        Ok(tips.into_iter().rev().take(limit).collect())
+    }
+
+    /// Look up a strategist by display name (matched like registration: ignoring case and
+    /// extra whitespace)
+    async fn strategist_by_name(&self, name: String) -> Option<Strategist> {
+        let owner = self.state.name_index.get(&normalize_name(&name)).await.ok().flatten()?;
+        self.state.strategists.get(&owner).await.ok().flatten()
+    }
+
+    /// Check if a user is registered as a strategist
+    async fn is_strategist(&self, owner: String) -> async_graphql::Result<bool> {
+        let owner_account = parse_owner("owner", &owner)?;
         
-        self.state.strategists.contains_key(&owner_account).await.unwrap_or(false)
+        Ok(self.state.strategists.contains_key(&owner_account).await.unwrap_or(false))
+    }
+
+    /// Get the application configuration
+    async fn config(&self) -> AgentHubParameters {
+        self.runtime.application_parameters()
+    }
+
+    /// Get the raw running totals maintained by the contract
+    async fn global_counters(&self) -> GlobalCounters {
+        *self.state.global_counters.get()
+    }
+
+    /// Get platform-wide analytics from the maintained counters
+    async fn platform_stats(&self) -> PlatformStats {
+        let counters = *self.state.global_counters.get();
+        PlatformStats {
+            total_strategists: counters.total_strategists,
+            total_strategies: counters.total_strategies,
+            total_signals: counters.total_signals,
+            resolved_signals: counters.resolved_signals,
+            win_rate_bps: (counters.winning_signals * 10000)
+                .checked_div(counters.resolved_signals)
+                .unwrap_or(0) as u32,
+            active_subscriptions: counters.active_subscriptions,
+        }
+    }
+
+    /// Get the protocol fee currently in force (governance override or parameter)
+    async fn protocol_fee_bps(&self) -> u16 {
+        self.state.protocol_fee_bps.get()
+            .unwrap_or_else(|| self.runtime.application_parameters().protocol_fee_bps)
+    }
+
+    /// Get a governance proposal by ID
+    async fn proposal(&self, id: u64) -> Option<Proposal> {
+        self.state.proposals.get(&id).await.ok().flatten()
+    }
+
+    /// Get governance proposals, newest first
+    async fn proposals(&self, limit: Option<i32>) -> Vec<Proposal> {
+        let limit = limit.unwrap_or(20) as usize;
+        let mut proposals = Vec::new();
+        let mut id = *self.state.next_proposal_id.get();
+        while id > 0 && proposals.len() < limit {
+            if let Ok(Some(proposal)) = self.state.proposals.get(&id).await {
+                proposals.push(proposal);
+            }
+            id -= 1;
+        }
+        proposals
+    }
+
+    /// Get an account's governance reputation
+    async fn reputation(&self, owner: AccountOwner) -> u64 {
+        self.state.reputation.get(&owner).await.ok().flatten().unwrap_or(0)
+    }
+
+    /// Get a tournament by ID
+    async fn tournament(&self, id: u64) -> Option<Tournament> {
+        self.state.tournaments.get(&id).await.ok().flatten()
+    }
+
+    /// Get tournaments, newest first
+    async fn tournaments(&self, limit: Option<i32>) -> Vec<Tournament> {
+        let limit = limit.unwrap_or(20) as usize;
+        let mut tournaments = Vec::new();
+        let mut id = *self.state.next_tournament_id.get();
+        while id > 0 && tournaments.len() < limit {
+            if let Ok(Some(tournament)) = self.state.tournaments.get(&id).await {
+                tournaments.push(tournament);
+            }
+            id -= 1;
+        }
+        tournaments
+    }
+
+    /// Get a tournament's leaderboard, ranked by score
+    async fn tournament_leaderboard(&self, tournament_id: u64, limit: Option<i32>) -> Vec<TournamentEntry> {
+        let limit = limit.unwrap_or(10) as usize;
+        let mut leaders = Vec::new();
+        if let Ok(Some(participants)) = self.state.tournament_participants.try_load_entry(&tournament_id).await {
+            let _ = participants.for_each_index_while(|rank| {
+                if leaders.len() >= limit {
+                    return Ok(false);
+                }
+                leaders.push(rank.strategy_id);
+                Ok(true)
+            }).await;
+        }
+        let mut entries = Vec::new();
+        for strategy_id in leaders {
+            if let Ok(Some(entry)) = self.state.tournament_entries.get(&(tournament_id, strategy_id)).await {
+                entries.push(entry);
+            }
+        }
+        entries
+    }
+
+    /// Get the strategists referred by an account
+    async fn referrals_of(&self, owner: AccountOwner) -> Vec<Strategist> {
+        let referees = self.state.referrals.get(&owner).await
+            .ok().flatten().unwrap_or_default();
+        let mut strategists = Vec::new();
+        for referee in referees {
+            if let Ok(Some(strategist)) = self.state.strategists.get(&referee).await {
+                strategists.push(strategist);
+            }
+        }
+        strategists
+    }
+
+    /// Get the protocol fees paid out to a referrer
+    async fn referral_earnings(&self, owner: AccountOwner) -> Amount {
+        self.state.referral_earnings.get(&owner).await.ok().flatten().unwrap_or_default()
+    }
+
+    /// Get the badges earned by a strategist
+    async fn badges(&self, owner: AccountOwner) -> Vec<Badge> {
+        self.state.badges.get(&owner).await.ok().flatten().unwrap_or_default()
+    }
+
+    /// Get the role held by an account on this chain
+    async fn role_of(&self, owner: AccountOwner) -> Option<Role> {
+        self.state.roles.get(&owner).await.ok().flatten()
+    }
+
+    /// Get the markets registered on this chain, optionally only active ones
+    async fn markets(&self, active_only: Option<bool>) -> Vec<Market> {
+        let active_only = active_only.unwrap_or(false);
+        self.state.markets.index_values().await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(_, market)| market)
+            .filter(|market| !active_only || market.is_active)
+            .collect()
+    }
+
+    /// Get a registered market by symbol (case-insensitive)
+    async fn market(&self, symbol: String) -> Option<Market> {
+        self.state.markets.get(&Market::normalize_symbol(&symbol)).await.ok().flatten()
+    }
+
+    /// Get the resolution rules that apply to a market: its own, else its kind's, else
+    /// the defaults
+    async fn resolution_rules(&self, base_market: String, market_kind: MarketKind) -> ResolutionRules {
+        let market = self.state.markets.get(&Market::normalize_symbol(&base_market)).await
+            .ok().flatten();
+        if let Some(rules) = market.and_then(|market| market.rules) {
+            return rules;
+        }
+        self.state.kind_resolution_rules.get(&market_kind).await.ok().flatten().unwrap_or_default()
+    }
+
+    /// Get all role assignments on this chain
+    async fn roles(&self) -> Vec<RoleAssignment> {
+        self.state.roles.index_values().await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(owner, role)| RoleAssignment { owner, role })
+            .collect()
+    }
+
+    /// Get the protocol fees collected on this chain
+    async fn treasury_balance(&self) -> Amount {
+        *self.state.treasury_balance.get()
+    }
+
+    /// Get the current signal publishing rate limits
+    async fn rate_limits(&self) -> RateLimitConfig {
+        *self.state.rate_limits.get()
+    }
+
+    // =========================================================================
+    // Hub Queries
+    // =========================================================================
+
+    /// Get strategies from the hub's global catalog (only populated on the hub chain)
+    async fn hub_strategies(
+        &self,
+        market_kind: Option<MarketKind>,
+        base_market: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> Vec<StrategySummary> {
+        let limit = limit.unwrap_or(50) as usize;
+        let offset = offset.unwrap_or(0) as usize;
+
+        let entries = self.state.hub_strategies.index_values().await.unwrap_or_default();
+
+        let mut strategies: Vec<StrategySummary> = entries
+            .into_iter()
+            .map(|(_, summary)| summary)
+            .filter(|s| market_kind.as_ref().is_none_or(|mk| s.market_kind == *mk))
+            .filter(|s| base_market.as_ref().is_none_or(|bm| &s.base_market == bm))
+            .collect();
+
+        // Newest first
+        strategies.sort_by_key(|s| std::cmp::Reverse(s.created_at));
+
+        strategies.into_iter().skip(offset).take(limit).collect()
+    }
+
+    /// Get the best strategies across all chains from the hub's synced catalog and stats,
+    /// ranked by win rate, then total PnL (only populated on the hub chain)
+    async fn global_top_strategies(
+        &self,
+        market_kind: Option<MarketKind>,
+        limit: Option<i32>,
+    ) -> Vec<GlobalTopStrategy> {
+        let limit = limit.unwrap_or(10).clamp(1, 100) as usize;
+
+        let mut ranked = Vec::new();
+        for (global_id, stats) in self.state.hub_stats.index_values().await.unwrap_or_default() {
+            if stats.total_signals == 0 {
+                continue;
+            }
+            let Ok(Some(summary)) = self.state.hub_strategies.get(&global_id).await else {
+                continue;
+            };
+            if market_kind.as_ref().is_none_or(|mk| summary.market_kind == *mk) {
+                ranked.push((summary, stats));
+            }
+        }
+        ranked.sort_by(|(_, a), (_, b)| {
+            b.win_rate_bps.cmp(&a.win_rate_bps)
+                .then_with(|| b.total_pnl_bps.cmp(&a.total_pnl_bps))
+        });
+
+        ranked.into_iter()
+            .take(limit)
+            .enumerate()
+            .map(|(index, (strategy, stats))| GlobalTopStrategy {
+                rank: index as u32 + 1,
+                chain_id: strategy.global_id.chain_id,
+                strategy,
+                stats,
+            })
+            .collect()
+    }
+
+    /// Get the chain hosting a strategist's strategies, to subscribe or tip there (only
+    /// populated on the hub chain)
+    async fn where_is(&self, strategist: String) -> async_graphql::Result<Option<ChainId>> {
+        let strategist_account = parse_owner("strategist", &strategist)?;
+
+        Ok(self.state.strategist_chains.get(&strategist_account).await.ok().flatten())
+    }
+
+    /// Get a hub leaderboard snapshot for a market kind (latest epoch if unset)
+    async fn hub_leaderboard(&self, epoch: Option<u64>, market_kind: MarketKind) -> Option<HubLeaderboard> {
+        let epoch = epoch.unwrap_or(*self.state.hub_leaderboard_epoch.get());
+        self.state.hub_leaderboards.get(&(epoch, market_kind)).await.ok().flatten()
     }
 
     // =========================================================================
@@ -294,13 +1799,12 @@ impl QueryRoot {
     // =========================================================================
 
     /// Get subscription offer for a strategist
-    async fn subscription_offer(&self, strategist: String) -> Option<SubscriptionOffer> {
-        let strategist_account: AccountOwner = match strategist.parse() {
-            Ok(o) => o,
-            Err(_) => return None,
-        };
+    async fn subscription_offer(&self, strategist: String) -> async_graphql::Result<Option<SubscriptionOffer>> {
+        let strategist_account = parse_owner("strategist", &strategist)?;
         
-        self.state.subscription_offers.get(&strategist_account).await.ok().flatten()
+        // Report the period actually applied to new subscriptions
+        Ok(self.state.subscription_offers.get(&strategist_account).await.ok().flatten()
+            .map(|offer| SubscriptionOffer { duration_days: offer.period_days(), ..offer }))
     }
 
     /// Get all strategists with active subscription offers
@@ -314,7 +1818,7 @@ impl QueryRoot {
         for strategist in strategist_iter.drain(..).take(limit * 2) {
             if let Ok(Some(offer)) = self.state.subscription_offers.get(&strategist).await {
                 if offer.is_enabled {
-                    offers.push(offer);
+                    offers.push(SubscriptionOffer { duration_days: offer.period_days(), ..offer });
                 }
             }
         }
@@ -323,11 +1827,8 @@ impl QueryRoot {
     }
 
     /// Get subscriptions for a subscriber
-    async fn my_subscriptions(&self, subscriber: String) -> Vec<Subscription> {
-        let subscriber_account: AccountOwner = match subscriber.parse() {
-            Ok(o) => o,
-            Err(_) => return Vec::new(),
-        };
+    async fn my_subscriptions(&self, subscriber: String) -> async_graphql::Result<Vec<Subscription>> {
+        let subscriber_account = parse_owner("subscriber", &subscriber)?;
         
         let sub_ids = self.state.subscriptions_by_subscriber.get(&subscriber_account).await
             .ok().flatten().unwrap_or_default();
@@ -339,18 +1840,14 @@ impl QueryRoot {
             }
         }
         
-        subscriptions
+        Ok(subscriptions)
     }
 
     /// Get subscribers for a strategist
-    async fn subscribers_of(&self, strategist: String) -> Vec<Subscription> {
-        let strategist_account: AccountOwner = match strategist.parse() {
-            Ok(o) => o,
-            Err(_) => return Vec::new(),
-        };
+    async fn subscribers_of(&self, strategist: String) -> async_graphql::Result<Vec<Subscription>> {
+        let strategist_account = parse_owner("strategist", &strategist)?;
         
-        let sub_ids = self.state.subscribers_by_strategist.get(&strategist_account).await
-            .ok().flatten().unwrap_or_default();
+        let sub_ids = self.strategist_subscription_ids(&strategist_account).await;
         
         let mut subscriptions = Vec::new();
         for sub_id in sub_ids {
@@ -361,32 +1858,41 @@ impl QueryRoot {
             }
         }
         
-        subscriptions
+        Ok(subscriptions)
+    }
+
+    /// Get the escrowed payment of a subscription (on the strategist's chain)
+    async fn subscription_escrow(&self, subscription_id: String) -> Option<SubscriptionEscrow> {
+        self.state.escrows.get(&subscription_id).await.ok().flatten()
     }
 
     /// Check if a user is subscribed to a strategist
-    async fn is_subscribed(&self, subscriber: String, strategist: String) -> bool {
-        let subscriber_account: AccountOwner = match subscriber.parse() {
-            Ok(o) => o,
-            Err(_) => return false,
-        };
+    async fn is_subscribed(&self, subscriber: String, strategist: String) -> async_graphql::Result<bool> {
+        let subscriber_account = parse_owner("subscriber", &subscriber)?;
         
-        let strategist_account: AccountOwner = match strategist.parse() {
-            Ok(o) => o,
-            Err(_) => return false,
-        };
+        let strategist_account = parse_owner("strategist", &strategist)?;
         
         let sub_ids = self.state.subscriptions_by_subscriber.get(&subscriber_account).await
             .ok().flatten().unwrap_or_default();
+        let now = self.runtime.system_time();
         
         for sub_id in sub_ids {
             if let Ok(Some(sub)) = self.state.subscriptions.get(&sub_id).await {
-                if sub.strategist == strategist_account && sub.is_active {
-                    return true;
+                if sub.strategist == strategist_account && sub.grants_access(now) {
+                    return Ok(true);
                 }
             }
         }
         
-        false
+        Ok(false)
+    }
+
+    /// Whether a subscriber has already used their free trial with a strategist
+    /// (on the strategist's chain)
+    async fn trial_claimed(&self, subscriber: String, strategist: String) -> async_graphql::Result<bool> {
+        let subscriber_account = parse_owner("subscriber", &subscriber)?;
+        let strategist_account = parse_owner("strategist", &strategist)?;
+        let id = subscription_id(&subscriber_account, &strategist_account);
+        Ok(self.state.trials_claimed.contains_key(&id).await.unwrap_or(false))
     }
 }
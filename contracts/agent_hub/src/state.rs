@@ -1,18 +1,250 @@
 // State management for AgentHub
 
+use std::ops::Range;
+
 use agent_hub::{
-    AgentStrategy, Follower, FollowerKey, Signal, StrategyStats, Strategist,
-    Subscription, SubscriptionOffer,
+    AgentStrategy, Badge, CalibrationBucket, ChangeRecord, ChurnStats, CopierStats,
+    CopySkip, DailyPnl, DeadLetter, Follower, FollowerKey, FollowerSnapshot, FollowerStats,
+    GlobalCounters, GlobalId, HubLeaderboard, Market, MarketKind, MarketStats, MirroredStrategy,
+    NotificationPreferences, OutboundRequest, Position, PriceObservation, Proposal, RateLimitConfig,
+    RegistrationDeposit, RemoteFollow, ResolutionRules, Role, Signal, SignalCommitment,
+    SignalStatus, SimulatedPnl, Strategist, StrategyStats, StrategySummary, StrategyVersion,
+    Subscription, SubscriptionEscrow, SubscriptionOffer, Tip, Tournament, TournamentEntry,
+    TrackRecordExport,
 };
 use linera_sdk::{
-    linera_base_types::{AccountOwner, ChainId},
-    views::{linera_views, MapView, RegisterView, RootView, ViewStorageContext},
+    linera_base_types::{AccountOwner, Amount, ChainId, CryptoHash, Ed25519PublicKey, Timestamp},
+    views::{
+        linera_views, CollectionView, CustomSerialize, CustomSetView, LogView, MapView, QueueView,
+        RegisterView, RootView, SetView, ViewError, ViewStorageContext,
+    },
 };
+use serde::{Deserialize, Serialize};
+
+/// Leaderboard position of a strategy. Its custom serialization sorts by win rate DESC,
+/// then total PnL DESC, then strategy ID, so a range read yields the top strategies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeaderboardKey {
+    pub win_rate_bps: u32,
+    pub total_pnl_bps: i64,
+    pub strategy_id: u64,
+}
+
+impl CustomSerialize for LeaderboardKey {
+    fn to_custom_bytes(&self) -> Result<Vec<u8>, ViewError> {
+        // Offsetting the sign bit orders i64 as u64; inverting turns ascending into descending
+        let pnl = !((self.total_pnl_bps as u64) ^ (1 << 63));
+        let mut bytes = Vec::with_capacity(20);
+        bytes.extend_from_slice(&(!self.win_rate_bps).to_be_bytes());
+        bytes.extend_from_slice(&pnl.to_be_bytes());
+        bytes.extend_from_slice(&self.strategy_id.to_be_bytes());
+        Ok(bytes)
+    }
+
+    fn from_custom_bytes(bytes: &[u8]) -> Result<Self, ViewError> {
+        let bytes: [u8; 20] = bytes.try_into().map_err(|_| ViewError::InconsistentEntries)?;
+        let win_rate = u32::from_be_bytes(bytes[0..4].try_into().expect("4 bytes"));
+        let pnl = u64::from_be_bytes(bytes[4..12].try_into().expect("8 bytes"));
+        let strategy_id = u64::from_be_bytes(bytes[12..20].try_into().expect("8 bytes"));
+        Ok(LeaderboardKey {
+            win_rate_bps: !win_rate,
+            total_pnl_bps: (!pnl ^ (1 << 63)) as i64,
+            strategy_id,
+        })
+    }
+}
+
+/// Position of a follower in the sorted copier leaderboard. Its custom serialization sorts by
+/// realized PnL, then hit rate, both descending.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CopierKey {
+    pub realized_pnl_units: i64,
+    pub hit_rate_bps: u32,
+    pub follower: AccountOwner,
+}
+
+impl CustomSerialize for CopierKey {
+    fn to_custom_bytes(&self) -> Result<Vec<u8>, ViewError> {
+        let pnl = !((self.realized_pnl_units as u64) ^ (1 << 63));
+        let mut bytes = Vec::with_capacity(12);
+        bytes.extend_from_slice(&pnl.to_be_bytes());
+        bytes.extend_from_slice(&(!self.hit_rate_bps).to_be_bytes());
+        bytes.extend(bcs::to_bytes(&self.follower)?);
+        Ok(bytes)
+    }
+
+    fn from_custom_bytes(bytes: &[u8]) -> Result<Self, ViewError> {
+        if bytes.len() < 12 {
+            return Err(ViewError::InconsistentEntries);
+        }
+        let pnl = u64::from_be_bytes(bytes[0..8].try_into().expect("8 bytes"));
+        let hit_rate = u32::from_be_bytes(bytes[8..12].try_into().expect("4 bytes"));
+        Ok(CopierKey {
+            realized_pnl_units: (!pnl ^ (1 << 63)) as i64,
+            hit_rate_bps: !hit_rate,
+            follower: bcs::from_bytes(&bytes[12..])?,
+        })
+    }
+}
+
+/// Scheduled automatic resolution of an open signal. Its custom serialization sorts by expiry,
+/// then signal ID, so a range read yields the signals that are due first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResolutionKey {
+    pub expires_at: Timestamp,
+    pub signal_id: u64,
+}
+
+impl CustomSerialize for ResolutionKey {
+    fn to_custom_bytes(&self) -> Result<Vec<u8>, ViewError> {
+        let mut bytes = Vec::with_capacity(16);
+        bytes.extend_from_slice(&self.expires_at.micros().to_be_bytes());
+        bytes.extend_from_slice(&self.signal_id.to_be_bytes());
+        Ok(bytes)
+    }
+
+    fn from_custom_bytes(bytes: &[u8]) -> Result<Self, ViewError> {
+        let bytes: [u8; 16] = bytes.try_into().map_err(|_| ViewError::InconsistentEntries)?;
+        let expires_at = u64::from_be_bytes(bytes[0..8].try_into().expect("8 bytes"));
+        let signal_id = u64::from_be_bytes(bytes[8..16].try_into().expect("8 bytes"));
+        Ok(ResolutionKey {
+            expires_at: Timestamp::from(expires_at),
+            signal_id,
+        })
+    }
+}
+
+/// Position of a signal in a creation-time index. Its custom serialization sorts by creation
+/// time, then signal ID; revealed signals take their commit time, so this can differ from
+/// publish order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreationKey {
+    pub created_at: Timestamp,
+    pub signal_id: u64,
+}
+
+impl CustomSerialize for CreationKey {
+    fn to_custom_bytes(&self) -> Result<Vec<u8>, ViewError> {
+        let mut bytes = Vec::with_capacity(16);
+        bytes.extend_from_slice(&self.created_at.micros().to_be_bytes());
+        bytes.extend_from_slice(&self.signal_id.to_be_bytes());
+        Ok(bytes)
+    }
+
+    fn from_custom_bytes(bytes: &[u8]) -> Result<Self, ViewError> {
+        let bytes: [u8; 16] = bytes.try_into().map_err(|_| ViewError::InconsistentEntries)?;
+        let created_at = u64::from_be_bytes(bytes[0..8].try_into().expect("8 bytes"));
+        let signal_id = u64::from_be_bytes(bytes[8..16].try_into().expect("8 bytes"));
+        Ok(CreationKey {
+            created_at: Timestamp::from(created_at),
+            signal_id,
+        })
+    }
+}
+
+/// Position of a follower in a strategy's follower index. Its custom serialization sorts by
+/// follow time, then follower, so a range read yields followers in follow order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FollowOrderKey {
+    pub followed_at: Timestamp,
+    pub follower: AccountOwner,
+}
+
+impl CustomSerialize for FollowOrderKey {
+    fn to_custom_bytes(&self) -> Result<Vec<u8>, ViewError> {
+        let mut bytes = Vec::with_capacity(8);
+        bytes.extend_from_slice(&self.followed_at.micros().to_be_bytes());
+        bytes.extend(bcs::to_bytes(&self.follower)?);
+        Ok(bytes)
+    }
+
+    fn from_custom_bytes(bytes: &[u8]) -> Result<Self, ViewError> {
+        if bytes.len() < 8 {
+            return Err(ViewError::InconsistentEntries);
+        }
+        let followed_at = u64::from_be_bytes(bytes[0..8].try_into().expect("8 bytes"));
+        Ok(FollowOrderKey {
+            followed_at: Timestamp::from(followed_at),
+            follower: bcs::from_bytes(&bytes[8..])?,
+        })
+    }
+}
+
+/// Ranking of a strategy in a tournament. Its custom serialization sorts by score DESC, then
+/// strategy ID, so a range read yields the leaders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TournamentScoreKey {
+    pub score_bps: i64,
+    pub strategy_id: u64,
+}
+
+impl CustomSerialize for TournamentScoreKey {
+    fn to_custom_bytes(&self) -> Result<Vec<u8>, ViewError> {
+        let score = !((self.score_bps as u64) ^ (1 << 63));
+        let mut bytes = Vec::with_capacity(16);
+        bytes.extend_from_slice(&score.to_be_bytes());
+        bytes.extend_from_slice(&self.strategy_id.to_be_bytes());
+        Ok(bytes)
+    }
+
+    fn from_custom_bytes(bytes: &[u8]) -> Result<Self, ViewError> {
+        let bytes: [u8; 16] = bytes.try_into().map_err(|_| ViewError::InconsistentEntries)?;
+        let score = u64::from_be_bytes(bytes[0..8].try_into().expect("8 bytes"));
+        let strategy_id = u64::from_be_bytes(bytes[8..16].try_into().expect("8 bytes"));
+        Ok(TournamentScoreKey {
+            score_bps: (!score ^ (1 << 63)) as i64,
+            strategy_id,
+        })
+    }
+}
+
+/// Entry of a per-key ID index. Its custom serialization sorts by ID descending, so a range
+/// read yields the newest entries first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReverseId(pub u64);
+
+impl CustomSerialize for ReverseId {
+    fn to_custom_bytes(&self) -> Result<Vec<u8>, ViewError> {
+        Ok((!self.0).to_be_bytes().to_vec())
+    }
+
+    fn from_custom_bytes(bytes: &[u8]) -> Result<Self, ViewError> {
+        let bytes: [u8; 8] = bytes.try_into().map_err(|_| ViewError::InconsistentEntries)?;
+        Ok(ReverseId(!u64::from_be_bytes(bytes)))
+    }
+}
+
+/// Stage of the upgrade from the first release's layout, in the order the stages run: each
+/// needs the records converted by the ones before it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum MigrationPhase {
+    #[default]
+    Strategists,
+    Strategies,
+    Followers,
+    StrategyStats,
+    Signals,
+    SubscriptionOffers,
+    Subscriptions,
+}
+
+/// How far the upgrade from the first release's layout has got, kept across the blocks it is
+/// spread over
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MigrationProgress {
+    pub phase: MigrationPhase,
+    /// Whether the phase's keys have been queued, for phases over maps not keyed by ID
+    pub queued: bool,
+    /// IDs converted so far, for phases over maps keyed by ID
+    pub converted: u64,
+}
 
 /// The application state stored on each chain.
 #[derive(RootView)]
 #[view(context = ViewStorageContext)]
 pub struct AgentHubState {
+    // Fields up to `next_subscription_id` keep the order and view types of the first
+    // release, whose storage keys derive from field position; add new views at the end.
     /// Hub chain ID for cross-chain communication
     pub hub_chain_id: RegisterView<Option<ChainId>>,
     
@@ -25,8 +257,8 @@ pub struct AgentHubState {
     /// All signals (keyed by signal ID)
     pub signals: MapView<u64, Signal>,
     
-    /// Signals by strategy (strategy_id -> list of signal IDs)
-    pub signals_by_strategy: MapView<u64, Vec<u64>>,
+    /// Per-strategy signal lists written before `signals_by_strategy`; emptied by migration
+    pub legacy_signals_by_strategy: MapView<u64, Vec<u64>>,
     
     /// Strategy statistics
     pub strategy_stats: MapView<u64, StrategyStats>,
@@ -50,15 +282,382 @@ pub struct AgentHubState {
     /// Subscription offers by strategist (strategist -> SubscriptionOffer)
     pub subscription_offers: MapView<AccountOwner, SubscriptionOffer>,
     
-    /// Active subscriptions (subscription_id -> Subscription)
+    /// Subscriptions (subscription_id -> Subscription), one per (subscriber, strategist) pair
     pub subscriptions: MapView<String, Subscription>,
     
     /// Subscriptions by subscriber (subscriber -> list of subscription IDs)
     pub subscriptions_by_subscriber: MapView<AccountOwner, Vec<String>>,
     
-    /// Subscribers by strategist (strategist -> list of subscription IDs)
-    pub subscribers_by_strategist: MapView<AccountOwner, Vec<String>>,
+    /// Subscription ID lists per strategist written before `subscribers_by_strategist`;
+    /// emptied by migration
+    pub legacy_subscribers_by_strategist: MapView<AccountOwner, Vec<String>>,
     
-    /// Counter for subscription ID
+    /// Counter behind the sequential subscription IDs written before they were derived from
+    /// the (subscriber, strategist) pair; no longer advanced
     pub next_subscription_id: RegisterView<u64>,
+    
+    /// Layout version of the stored state (0 = written by the first release)
+    pub schema_version: RegisterView<u32>,
+    
+    /// Progress of the upgrade from the first release's layout while it runs
+    pub migration_progress: RegisterView<MigrationProgress>,
+    
+    /// BCS-encoded keys of the first-release map being upgraded that are still to convert
+    pub migration_queue: QueueView<Vec<u8>>,
+    
+    /// Platform-wide running totals
+    pub global_counters: RegisterView<GlobalCounters>,
+    
+    /// Privileged roles (owner -> role)
+    pub roles: MapView<AccountOwner, Role>,
+    
+    /// Signal publishing rate limits
+    pub rate_limits: RegisterView<RateLimitConfig>,
+    
+    /// Recent publish times per strategist (pruned to the last 24 hours)
+    pub publish_history: MapView<AccountOwner, Vec<Timestamp>>,
+    
+    /// Strategists by normalized display name (see `normalize_name`)
+    pub name_index: MapView<String, AccountOwner>,
+    
+    /// Append-only log of published signal IDs, in publish order
+    pub recent_signals_log: LogView<u64>,
+    
+    /// Append-only change feed for off-chain indexers
+    pub change_log: LogView<ChangeRecord>,
+    
+    /// Signals broadcast from strategies on other chains (keyed by global ID)
+    pub received_signals: MapView<GlobalId, Signal>,
+    
+    /// Received signals by strategist (strategist -> global signal IDs)
+    pub received_signals_by_strategist: CollectionView<AccountOwner, SetView<GlobalId>>,
+    
+    /// Strategist whose stream delivered each received signal
+    pub received_signal_strategists: MapView<GlobalId, AccountOwner>,
+    
+    /// Read-only copies of public strategies hosted on other chains
+    pub mirrored_strategies: MapView<GlobalId, MirroredStrategy>,
+    
+    /// Signals by strategy, in publish order (strategy_id -> log of signal IDs)
+    pub signals_by_strategy: CollectionView<u64, LogView<u64>>,
+    
+    /// Signal IDs per strategy ordered by creation time, for time-range queries
+    pub strategy_creation_index: CollectionView<u64, CustomSetView<CreationKey>>,
+    
+    /// All signal IDs on this chain ordered by creation time, for time-range queries
+    pub signal_creation_index: CustomSetView<CreationKey>,
+    
+    /// Followers with a bet open on the prediction app, per signal
+    pub prediction_bets: MapView<u64, Vec<AccountOwner>>,
+    
+    /// Resolved-signal leaf hashes per strategy, in resolution order
+    pub merkle_leaves: CollectionView<u64, LogView<CryptoHash>>,
+    
+    /// Position of each resolved signal in its strategy's accumulator
+    pub merkle_leaf_index: MapView<u64, u64>,
+    
+    /// Peaks of each strategy's accumulator (largest subtree first)
+    pub merkle_peaks: MapView<u64, Vec<CryptoHash>>,
+    
+    /// Signals by market and status, newest first ((base_market, status) -> signal IDs)
+    pub signals_by_market: CollectionView<(String, SignalStatus), CustomSetView<ReverseId>>,
+    
+    /// Outcomes of resolved signals per base market
+    pub market_stats: MapView<String, MarketStats>,
+    
+    /// Strategies forked from each strategy on this chain
+    pub forks_by_strategy: MapView<u64, Vec<u64>>,
+    
+    /// Published track-record blobs per strategy, oldest first
+    pub track_record_exports: MapView<u64, Vec<TrackRecordExport>>,
+    
+    /// Retired strategy versions with their frozen stats, keyed by (strategy, version)
+    pub strategy_versions: MapView<(u64, u32), StrategyVersion>,
+    
+    /// Committed signals awaiting reveal (signal ID -> commitment)
+    pub signal_commitments: MapView<u64, SignalCommitment>,
+    
+    /// A strategy's one outstanding commitment (strategy ID -> signal ID)
+    pub open_commitments: MapView<u64, u64>,
+    
+    /// Commitments in commit order with their reveal deadlines, checked for lapses
+    pub commitment_deadlines: QueueView<(Timestamp, u64)>,
+    
+    /// Commitments never revealed, by (strategy, version)
+    pub lapsed_commitments: MapView<(u64, u32), u64>,
+    
+    /// Calibration counters per (strategy, confidence bucket)
+    pub calibration: MapView<(u64, u8), CalibrationBucket>,
+    
+    /// Resolutions per (strategy, day since the epoch), for PnL charts
+    pub daily_pnl: MapView<(u64, u32), DailyPnl>,
+    
+    /// Follower counts per (strategy, day since the epoch), recorded on days they changed
+    pub follower_history: MapView<(u64, u32), FollowerSnapshot>,
+    
+    /// Public strategies with resolved signals, ordered best first
+    pub leaderboard: CustomSetView<LeaderboardKey>,
+    
+    /// Current leaderboard position of each indexed strategy
+    pub leaderboard_keys: MapView<u64, LeaderboardKey>,
+    
+    /// Public strategies carrying each tag (tag -> strategy_ids)
+    pub strategies_by_tag: MapView<String, Vec<u64>>,
+    
+    /// Open signals awaiting resolution, soonest-expiring first; dropped once resolved or
+    /// cancelled
+    pub pending_resolution: CustomSetView<ResolutionKey>,
+    
+    /// Open signals scheduled to resolve themselves from the oracle at expiry, soonest first
+    pub resolution_schedule: CustomSetView<ResolutionKey>,
+    
+    /// Open signals to auto-cancel once their resolution grace period passes, soonest first
+    pub expiry_sweep: CustomSetView<ResolutionKey>,
+    
+    /// Open delayed-public signals to broadcast once disclosed, keyed by disclosure time
+    pub disclosure_schedule: CustomSetView<ResolutionKey>,
+    
+    /// Price observations submitted for time-weighted resolution, by time (signal_id -> observations)
+    pub price_observations: MapView<u64, Vec<PriceObservation>>,
+    
+    /// Keys resolvers sign their resolutions with (resolver -> public key)
+    pub resolver_keys: MapView<AccountOwner, Ed25519PublicKey>,
+    
+    /// Number of currently open signals per strategy
+    pub open_signal_count: MapView<u64, u64>,
+    
+    /// Follower owners per strategy, in follow order (strategy_id -> followers)
+    pub followers_by_strategy: CollectionView<u64, CustomSetView<FollowOrderKey>>,
+    
+    /// Every account that has ever followed each strategy, including those since unfollowed
+    pub past_followers: SetView<FollowerKey>,
+    
+    /// Number of distinct accounts that have ever followed each strategy
+    pub lifetime_follower_count: MapView<u64, u64>,
+    
+    /// Unfollow reasons per strategy
+    pub churn_stats: MapView<u64, ChurnStats>,
+    
+    /// Notification preferences of accounts on this chain
+    pub notification_preferences: MapView<AccountOwner, NotificationPreferences>,
+    
+    /// All positions by ID
+    pub positions: MapView<u64, Position>,
+    
+    /// Positions on each signal, newest first (signal_id -> position_ids)
+    pub positions_by_signal: CollectionView<u64, CustomSetView<ReverseId>>,
+    
+    /// Positions held by each account, newest first (owner -> position_ids)
+    pub positions_by_owner: CollectionView<AccountOwner, CustomSetView<ReverseId>>,
+    
+    /// Counter for the last position ID
+    pub next_position_id: RegisterView<u64>,
+    
+    /// Units each account holds in open, non-simulated positions
+    pub open_exposure: MapView<AccountOwner, u64>,
+    
+    /// Most open units each account allows itself (absent = no cap)
+    pub exposure_caps: MapView<AccountOwner, u64>,
+    
+    /// Recent signals each auto-copy follower's risk limits skipped, oldest first
+    pub copy_skips: MapView<FollowerKey, Vec<CopySkip>>,
+    
+    /// Units lost on copied signals per (follower, UTC day)
+    pub copy_daily_loss: MapView<(FollowerKey, u32), u64>,
+    
+    /// Paper-trading ledger of followers in simulation mode
+    pub simulated_pnl: MapView<FollowerKey, SimulatedPnl>,
+    
+    /// Realized copy-trading results per follow
+    pub follower_stats: MapView<FollowerKey, FollowerStats>,
+    
+    /// Strategies each follower has copy-trading results on (follower -> strategy_ids)
+    pub copy_stats_by_follower: MapView<AccountOwner, Vec<u64>>,
+    
+    /// Copy-trading results per follower over all strategies
+    pub copier_stats: MapView<AccountOwner, CopierStats>,
+    
+    /// Followers with settled copies sorted by realized PnL, then hit rate
+    pub copier_board: CustomSetView<CopierKey>,
+    
+    /// Followers living on other chains, per strategy (strategy_id -> follower owners)
+    pub remote_followers: MapView<u64, Vec<AccountOwner>>,
+    
+    /// Follow requests waiting for a slot on a full strategy, oldest first
+    pub follower_waitlist: MapView<u64, Vec<Follower>>,
+    
+    /// Accounts invited to private strategies (strategy_id -> invitees)
+    pub strategy_invites: MapView<u64, Vec<AccountOwner>>,
+    
+    /// Accounts the strategist has blocked from following (strategy_id -> blocked accounts)
+    pub blocked_followers: MapView<u64, Vec<AccountOwner>>,
+    
+    /// Number of local follows/subscriptions listening to each strategist's signal stream
+    pub stream_listeners: MapView<(ChainId, AccountOwner), u64>,
+    
+    /// Subscriptions to each strategist (strategist -> subscription IDs)
+    pub subscribers_by_strategist: CollectionView<AccountOwner, SetView<String>>,
+    
+    /// Local strategies followed by each account (follower -> strategy IDs)
+    pub follows_by_follower: CollectionView<AccountOwner, SetView<u64>>,
+    
+    /// Reputation per strategist (number of winning signals)
+    pub reputation: MapView<AccountOwner, u64>,
+    
+    /// Strategists referred by each strategist (referrer -> referees)
+    pub referrals: MapView<AccountOwner, Vec<AccountOwner>>,
+    
+    /// Protocol fees paid out to each referrer
+    pub referral_earnings: MapView<AccountOwner, Amount>,
+    
+    /// Admin-curated market registry (normalized symbol -> market)
+    pub markets: MapView<String, Market>,
+    
+    /// Default resolution rules per market kind (markets may override)
+    pub kind_resolution_rules: MapView<MarketKind, ResolutionRules>,
+    
+    /// Badges earned by each strategist
+    pub badges: MapView<AccountOwner, Vec<Badge>>,
+    
+    /// Strategies on other chains followed from this chain (follower -> remote follows)
+    pub remote_follows: MapView<AccountOwner, Vec<RemoteFollow>>,
+    
+    /// Accounts on this chain following each strategy hosted elsewhere
+    /// (strategy global ID -> followers)
+    pub remote_follows_by_strategy: CollectionView<GlobalId, SetView<AccountOwner>>,
+    
+    /// Accounts on this chain that have subscribed to each strategist (strategist ->
+    /// subscribers); whether the subscription still grants access is checked on read
+    pub local_subscribers: CollectionView<AccountOwner, SetView<AccountOwner>>,
+    
+    /// Requests sent to other chains and their delivery status (request key -> request)
+    pub outbound_requests: MapView<String, OutboundRequest>,
+    
+    /// Pending requests in send order, checked for timeouts
+    pub outbound_timeouts: QueueView<(Timestamp, String)>,
+    
+    /// Incoming messages whose handlers failed, for admins to replay or refund (sequence ->
+    /// letter)
+    pub dead_letters: MapView<u64, DeadLetter>,
+    
+    /// Number of dead letters recorded; the last one's sequence
+    pub dead_letter_count: RegisterView<u64>,
+    
+    // =========================================================================
+    // Fee and Payment State
+    // =========================================================================
+    
+    /// Remaining performance-fee allowance of each follower, held in escrow
+    pub fee_allowances: MapView<FollowerKey, Amount>,
+    
+    /// Performance fees accrued to each strategist and not yet claimed
+    pub performance_fees: MapView<AccountOwner, Amount>,
+    
+    /// Tips received by each strategist on this chain, oldest first
+    pub tips_by_strategist: MapView<AccountOwner, Vec<Tip>>,
+    
+    /// Lifetime tip total per strategist
+    pub tips_received: MapView<AccountOwner, Amount>,
+    
+    /// Registration deposits of strategists on this chain
+    pub registration_deposits: MapView<AccountOwner, RegistrationDeposit>,
+    
+    /// Free trials already granted, so each subscriber gets one per strategist
+    /// (subscription_id -> when the trial started)
+    pub trials_claimed: MapView<String, Timestamp>,
+    
+    /// Escrowed subscription payments on the strategist's chain (subscription_id -> escrow)
+    pub escrows: MapView<String, SubscriptionEscrow>,
+    
+    /// Protocol fees collected on this chain, held in the application's account
+    pub treasury_balance: RegisterView<Amount>,
+    
+    // =========================================================================
+    // Governance State
+    // =========================================================================
+    
+    /// Protocol fee set by governance, overriding the application parameters
+    pub protocol_fee_bps: RegisterView<Option<u16>>,
+    
+    /// Proposals (proposal_id -> Proposal)
+    pub proposals: MapView<u64, Proposal>,
+    
+    /// Votes cast ((proposal_id, voter) -> support)
+    pub proposal_votes: MapView<(u64, AccountOwner), bool>,
+    
+    /// Proposals that are active or waiting on their timelock
+    pub pending_proposals: RegisterView<Vec<u64>>,
+    
+    /// Counter for next proposal ID
+    pub next_proposal_id: RegisterView<u64>,
+    
+    // =========================================================================
+    // Tournament State
+    // =========================================================================
+    
+    /// Tournaments (tournament_id -> Tournament)
+    pub tournaments: MapView<u64, Tournament>,
+    
+    /// Tournament scores ((tournament_id, strategy_id) -> entry)
+    pub tournament_entries: MapView<(u64, u64), TournamentEntry>,
+    
+    /// Strategies with an entry in each tournament, highest score first
+    pub tournament_participants: CollectionView<u64, CustomSetView<TournamentScoreKey>>,
+    
+    /// Tournaments not yet settled
+    pub open_tournaments: RegisterView<Vec<u64>>,
+    
+    /// Counter for next tournament ID
+    pub next_tournament_id: RegisterView<u64>,
+    
+    // =========================================================================
+    // Hub State (only populated on the hub chain)
+    // =========================================================================
+    
+    /// Global strategy catalog (strategy global ID -> summary)
+    pub hub_strategies: MapView<GlobalId, StrategySummary>,
+    
+    /// Chain hosting each strategist's strategies, learned from registrations and syncs
+    pub strategist_chains: MapView<AccountOwner, ChainId>,
+    
+    /// Latest synced stats of catalog strategies (strategy global ID -> stats)
+    pub hub_stats: MapView<GlobalId, StrategyStats>,
+    
+    /// Public strategies whose stats changed since the last sync to the hub
+    pub hub_sync_pending: SetView<u64>,
+    
+    /// When this chain last sent a stats batch to the hub
+    pub hub_synced_at: RegisterView<Timestamp>,
+    
+    /// Leaderboard snapshots ((epoch, market kind) -> leaderboard)
+    pub hub_leaderboards: MapView<(u64, MarketKind), HubLeaderboard>,
+    
+    /// Latest leaderboard epoch (0 = none taken yet)
+    pub hub_leaderboard_epoch: RegisterView<u64>,
+    
+    /// When the latest leaderboard snapshot was taken
+    pub hub_leaderboard_taken_at: RegisterView<Timestamp>,
+}
+
+impl AgentHubState {
+    /// IDs of a strategy's signals in `range` of publish order (clamped to the log length)
+    pub async fn strategy_signal_ids(
+        &self,
+        strategy_id: u64,
+        range: Range<usize>,
+    ) -> Result<Vec<u64>, ViewError> {
+        let Some(log) = self.signals_by_strategy.try_load_entry(&strategy_id).await? else {
+            return Ok(Vec::new());
+        };
+        let end = range.end.min(log.count());
+        log.read(range.start.min(end)..end).await
+    }
+
+    /// Number of signals a strategy has published
+    pub async fn strategy_signal_count(&self, strategy_id: u64) -> Result<usize, ViewError> {
+        Ok(match self.signals_by_strategy.try_load_entry(&strategy_id).await? {
+            Some(log) => log.count(),
+            None => 0,
+        })
+    }
 }
@@ -4,6 +4,11 @@
 
 import { chainQuery, chainMutate, chainManager, connectApp } from './connection';
 
+// The contract's `Price` scalar is a decimal string of the integer mantissa
+function toPrice(value: number): string {
+  return BigInt(Math.trunc(value)).toString();
+}
+
 // Helper to ensure app is connected before operations
 async function ensureAppConnected(): Promise<void> {
   if (!chainManager.isConnected()) {
@@ -209,7 +214,7 @@ export const GET_FOLLOWED_STRATEGIES = `
 
 /**
  * Register as a strategist (on-chain)
- * Contract: RegisterStrategist { display_name: String }
+ * Contract: RegisterStrategist { display_name: String, referrer: Option<AccountOwner> }
  */
 export const REGISTER_STRATEGIST = `
   mutation RegisterStrategist($displayName: String!) {
@@ -219,8 +224,9 @@ export const REGISTER_STRATEGIST = `
 
 /**
  * Create a new strategy (on-chain)
- * Contract: CreateAgentStrategy { name, description, market_kind, base_market, is_public, is_ai_controlled }
- * Note: MarketKind enum uses SCREAMING_CASE in GraphQL: CRYPTO, SPORTS, PREDICTION_APP
+ * Contract: CreateAgentStrategy { name, description, market_kind, base_market, is_public, is_ai_controlled, push_band_bps? }
+ * Note: MarketKind uses SCREAMING_CASE in GraphQL: CRYPTO, SPORTS, PREDICTION_APP, FOREX,
+ * EQUITIES, COMMODITIES, or "CUSTOM:<name>" for any other asset class
  */
 export const CREATE_STRATEGY = `
   mutation CreateAgentStrategy(
@@ -245,6 +251,7 @@ export const CREATE_STRATEGY = `
 /**
  * Publish a trading signal (on-chain)
  * Contract: PublishSignal { strategy_id, direction, horizon_secs, confidence_bps, entry_value }
+ * Note: entry_value is a `Price`, sent as a decimal string of its mantissa
  */
 export const PUBLISH_SIGNAL = `
   mutation PublishSignal(
@@ -252,7 +259,7 @@ export const PUBLISH_SIGNAL = `
     $direction: Direction!,
     $horizonSecs: Int!,
     $confidenceBps: Int!,
-    $entryValue: Int
+    $entryValue: Price
   ) {
     publishSignal(
       strategyId: $strategyId,
@@ -267,9 +274,10 @@ export const PUBLISH_SIGNAL = `
 /**
  * Resolve a signal with outcome (on-chain)
  * Contract: ResolveSignal { signal_id, resolved_value }
+ * Note: resolved_value is a `Price`, sent as a decimal string of its mantissa
  */
 export const RESOLVE_SIGNAL = `
-  mutation ResolveSignal($signalId: Int!, $resolvedValue: Int!) {
+  mutation ResolveSignal($signalId: Int!, $resolvedValue: Price!) {
     resolveSignal(signalId: $signalId, resolvedValue: $resolvedValue)
   }
 `;
@@ -601,7 +609,7 @@ export const onChainApi = {
   async createStrategy(data: { 
     name: string; 
     description: string; 
-    marketKind: string;  // "Crypto" | "Sports" | "PredictionApp"
+    marketKind: string;  // "Crypto" | "Sports" | "PredictionApp" | "Forex" | ... | "CUSTOM:<name>"
     baseMarket: string;  // e.g., "BTC/USD", "ETH/USD"
     isPublic: boolean;
     isAiControlled: boolean;
@@ -619,11 +627,19 @@ export const onChainApi = {
       'Crypto': 'CRYPTO',
       'Sports': 'SPORTS', 
       'PredictionApp': 'PREDICTION_APP',
+      'Forex': 'FOREX',
+      'Equities': 'EQUITIES',
+      'Commodities': 'COMMODITIES',
     };
     
+    // Custom kinds already use the contract's "CUSTOM:<name>" form and keep their name's case
+    const marketKind = data.marketKind.startsWith('CUSTOM:')
+      ? data.marketKind
+      : marketKindMap[data.marketKind] || data.marketKind.toUpperCase();
+
     const graphqlData = {
       ...data,
-      marketKind: marketKindMap[data.marketKind] || data.marketKind.toUpperCase(),
+      marketKind,
     };
     
     console.log('🔗 Calling ON-CHAIN createAgentStrategy mutation...', graphqlData);
@@ -733,6 +749,7 @@ export const onChainApi = {
     const graphqlData = {
       ...data,
       direction: directionMap[data.direction] || 'UP',
+      entryValue: data.entryValue === undefined ? undefined : toPrice(data.entryValue),
     };
     
     console.log('🔗 Calling ON-CHAIN publishSignal mutation...', graphqlData);
@@ -761,7 +778,10 @@ export const onChainApi = {
     console.log('🔗 Calling ON-CHAIN resolveSignal mutation...');
     
     try {
-      await chainMutate<{ resolveSignal: number[] }>(RESOLVE_SIGNAL, { signalId, resolvedValue });
+      await chainMutate<{ resolveSignal: number[] }>(RESOLVE_SIGNAL, {
+        signalId,
+        resolvedValue: toPrice(resolvedValue),
+      });
       await new Promise(resolve => setTimeout(resolve, 3000));
       console.log('✅ ON-CHAIN resolveSignal completed');
       return true;
@@ -2,8 +2,15 @@
 // Linera Types - TypeScript types matching the Rust contract
 // ============================================================================
 
-// Market kind enum
-export type MarketKind = 'Crypto' | 'Sports' | 'PredictionApp';
+// Market kind enum; custom kinds use the contract's GraphQL form "CUSTOM:<name>"
+export type MarketKind =
+  | 'Crypto'
+  | 'Sports'
+  | 'PredictionApp'
+  | 'Forex'
+  | 'Equities'
+  | 'Commodities'
+  | `CUSTOM:${string}`;
 
 // Direction enum
 export type Direction = 'Up' | 'Down' | 'Over' | 'Under' | 'Yes' | 'No';
@@ -7,14 +7,31 @@
 set -e
 
 # Check if chain ID is provided
-if [ -z "$1" ]; then
-  echo "Usage: ./scripts/deploy-contract.sh <HUB_CHAIN_ID>"
+if [ -z "$1" ] || [ -z "$2" ]; then
+  echo "Usage: ./scripts/deploy-contract.sh <HUB_CHAIN_ID> <ADMIN_OWNER> [PRICE_ORACLE_APP_ID] [PREDICTION_APP_ID]"
   echo ""
   echo "Get your chain ID from: linera wallet show"
   exit 1
 fi
 
 HUB_CHAIN_ID=$1
+ADMIN_OWNER=$2
+
+# Without an oracle, strategists supply their own entry values
+if [ -n "$3" ]; then
+  PRICE_ORACLE="\"$3\""
+else
+  PRICE_ORACLE="null"
+fi
+
+# Without a prediction app, PredictionApp signals are not mirrored as bets
+if [ -n "$4" ]; then
+  PREDICTION_APP="\"$4\""
+else
+  PREDICTION_APP="null"
+fi
+
+PARAMETERS="{\"min_horizon_secs\": 60, \"max_horizon_secs\": 2592000, \"max_open_signals_per_strategy\": 100, \"protocol_fee_bps\": 250, \"admin\": \"$ADMIN_OWNER\", \"price_oracle\": $PRICE_ORACLE, \"prediction_app\": $PREDICTION_APP}"
 
 echo "🚀 Deploying AgentHub Contract to Conway Testnet..."
 echo "   Hub Chain ID: $HUB_CHAIN_ID"
@@ -26,6 +43,7 @@ echo "   Hub Chain ID: $HUB_CHAIN_ID"
 linera publish-and-create \
   target/wasm32-unknown-unknown/release/agent_hub_contract.wasm \
   target/wasm32-unknown-unknown/release/agent_hub_service.wasm \
+  --json-parameters "$PARAMETERS" \
   --json-argument "{\"hub_chain_id\": \"$HUB_CHAIN_ID\"}"
 
 echo ""